@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+use linked_list_impls_fuzz::{check_against_model, Op};
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut list: DynamicLinkedList<i32> = DynamicLinkedList::new();
+    check_against_model(&mut list, None, &ops);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use linked_list_impls::static_linked_list::StaticLinkedList;
+use linked_list_impls_fuzz::{check_against_model, Op};
+
+/// Small enough that the fuzzer regularly fills the list and exercises slot reuse.
+const CAPACITY: usize = 16;
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut list: StaticLinkedList<i32, CAPACITY> = StaticLinkedList::new();
+    check_against_model(&mut list, Some(CAPACITY), &ops);
+});
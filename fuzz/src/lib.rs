@@ -0,0 +1,112 @@
+//! Shared helpers for the fuzz targets.
+//!
+//! Each target decodes the raw fuzzer input into a sequence of [`Op`]s, applies them to
+//! a list and to a `Vec<i32>` reference model, and panics as soon as the two disagree.
+
+use arbitrary::Arbitrary;
+use linked_list_impls::ListWrite;
+
+/// A single operation from the `ListWrite` interface.
+///
+/// Values and indices are kept to a single byte so that the fuzzer frequently produces
+/// duplicates and indices at (or just past) the end of the list.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum Op {
+    Insert(u8),
+    InsertAt(u8, u8),
+    DeleteElement(u8),
+    DeleteAt(u8),
+    UpdateElement(u8, u8),
+    UpdateAt(u8, u8),
+    Find(u8),
+    Get(u8),
+}
+
+/// Applies `ops` to both `list` and a `Vec` model, panicking on the first divergence.
+///
+/// # Arguments
+///
+/// * list - The (initially empty) list under test.
+/// * capacity - `Some(n)` for bounded lists, which drop tail inserts and reject
+///   `insert_at_index` once `n` elements are stored.
+/// * ops - The decoded operation sequence.
+pub fn check_against_model<L: ListWrite<i32>>(list: &mut L, capacity: Option<usize>, ops: &[Op]) {
+    let mut model: Vec<i32> = Vec::new();
+    let is_full = |model: &Vec<i32>| capacity.is_some_and(|c| model.len() >= c);
+
+    for op in ops {
+        match *op {
+            Op::Insert(v) => {
+                list.insert(v as i32);
+                if !is_full(&model) {
+                    model.push(v as i32);
+                }
+            }
+            Op::InsertAt(i, v) => {
+                let (i, v) = (i as usize, v as i32);
+                let result = list.insert_at_index(i, v);
+                if i <= model.len() && !is_full(&model) {
+                    assert!(result.is_ok(), "{:?} failed: {:?}", op, result);
+                    model.insert(i, v);
+                } else {
+                    assert!(result.is_err(), "{:?} should have failed", op);
+                }
+            }
+            Op::DeleteElement(v) => {
+                let v = v as i32;
+                let expected = match model.iter().position(|&x| x == v) {
+                    Some(pos) => {
+                        model.remove(pos);
+                        true
+                    }
+                    None => false,
+                };
+                assert_eq!(list.delete_element(&v), expected, "{:?}", op);
+            }
+            Op::DeleteAt(i) => {
+                let i = i as usize;
+                let result = list.delete_at_index(i);
+                if i < model.len() {
+                    assert!(result.is_ok(), "{:?} failed: {:?}", op, result);
+                    model.remove(i);
+                } else {
+                    assert!(result.is_err(), "{:?} should have failed", op);
+                }
+            }
+            Op::UpdateElement(old, new) => {
+                let (old, new) = (old as i32, new as i32);
+                let expected = match model.iter_mut().find(|x| **x == old) {
+                    Some(slot) => {
+                        *slot = new;
+                        true
+                    }
+                    None => false,
+                };
+                assert_eq!(list.update_element(&old, new), expected, "{:?}", op);
+            }
+            Op::UpdateAt(i, v) => {
+                let (i, v) = (i as usize, v as i32);
+                let result = list.update_element_at_index(i, v);
+                if i < model.len() {
+                    assert!(result.is_ok(), "{:?} failed: {:?}", op, result);
+                    model[i] = v;
+                } else {
+                    assert!(result.is_err(), "{:?} should have failed", op);
+                }
+            }
+            Op::Find(v) => {
+                let v = v as i32;
+                assert_eq!(list.find(&v), model.contains(&v), "{:?}", op);
+            }
+            Op::Get(i) => {
+                let i = i as usize;
+                assert_eq!(list.get(i), model.get(i), "{:?}", op);
+            }
+        }
+    }
+
+    for (i, expected) in model.iter().enumerate() {
+        assert_eq!(list.get(i), Some(expected));
+    }
+    assert_eq!(list.get(model.len()), None);
+}
@@ -0,0 +1,71 @@
+//! A minimal allocator interface for the node-based lists.
+//!
+//! The standard `Allocator` trait is still unstable, so lists that let the caller choose
+//! where their nodes live take an [`Alloc`] instead. [`Global`] forwards to the global
+//! allocator and is the default; implement `Alloc` for a pool or arena to place nodes in
+//! it. With the `bumpalo` feature, a `&Bump` is an allocator too.
+
+use alloc::alloc::{alloc, dealloc};
+use core::alloc::Layout;
+use core::ptr::NonNull;
+
+#[cfg(feature = "bumpalo")]
+use bumpalo::Bump;
+
+/// A source of memory for list nodes.
+///
+/// # Safety
+/// Implementations must return memory that is valid for `layout` and stays valid until it
+/// is passed to [`deallocate`](Self::deallocate). A list may free a node through a clone
+/// of the allocator that allocated it, so clones must accept each other's memory.
+pub unsafe trait Alloc: Clone {
+    /// Whether [`deallocate`](Self::deallocate) frees anything. Arenas that only release
+    /// their memory all at once set this to `false`, so a list whose elements need no
+    /// dropping can skip walking its nodes when it is dropped.
+    const FREES: bool = true;
+
+    /// Allocates a block of memory for `layout`, which never has a size of zero.
+    ///
+    /// # Returns
+    /// - `Some(NonNull<u8>)` with the start of the block.
+    /// - `None` if the memory could not be allocated.
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>>;
+
+    /// Frees a block of memory.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by `allocate` on this allocator (or a clone of it)
+    /// with the same `layout`, and not freed since.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+}
+
+/// The global allocator, as used by `Box`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+// SAFETY: the global allocator hands out memory that stays valid until it is freed, and
+// every `Global` frees through the same allocator.
+unsafe impl Alloc for Global {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        // SAFETY: callers never ask for a zero-sized block.
+        NonNull::new(unsafe { alloc(layout) })
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        dealloc(ptr.as_ptr(), layout);
+    }
+}
+
+// SAFETY: a bump arena keeps every block until the arena itself is dropped, which cannot
+// happen while a list borrows it, and all `&Bump`s to one arena share its memory.
+#[cfg(feature = "bumpalo")]
+unsafe impl Alloc for &Bump {
+    const FREES: bool = false;
+
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        self.try_alloc_layout(layout).ok()
+    }
+
+    /// Does nothing: the arena frees its memory when it is dropped or reset.
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+}
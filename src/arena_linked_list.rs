@@ -0,0 +1,433 @@
+use core::borrow::Borrow;
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ptr::NonNull;
+
+use bumpalo::Bump;
+
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// Shorthand for an optional pointer to a node in the arena.
+type Link<T> = Option<NonNull<Node<T>>>;
+
+/// `Node` represents a single element of the arena-backed list.
+struct Node<T> {
+    /// The data stored in the node. Dropped explicitly, since the arena never runs destructors.
+    data: ManuallyDrop<T>,
+    /// The next node in the list.
+    next: Link<T>,
+}
+
+/// `ArenaLinkedList` is a singly linked list whose nodes are allocated from a bump arena.
+///
+/// Allocating a node is a pointer bump inside the borrowed [`Bump`], and nodes are never
+/// freed individually: their memory is reclaimed all at once when the arena is reset or
+/// dropped. Building a large list therefore avoids a `malloc` per element, and dropping
+/// it is O(1) when `T` has no destructor (otherwise each element is still dropped, but
+/// no memory is freed). A tail pointer keeps appends O(1).
+///
+/// Memory of deleted nodes is not reused until the arena itself is reset.
+///
+/// The list is neither `Send` nor `Sync`, because it holds a `&Bump` and `Bump` is not
+/// `Sync`: two threads must never allocate from the same arena.
+pub struct ArenaLinkedList<'bump, T> {
+    /// The arena that owns the node memory.
+    bump: &'bump Bump,
+    /// The first node of the list.
+    head: Link<T>,
+    /// The last node of the list.
+    tail: Link<T>,
+    /// The number of elements in the list.
+    len: usize,
+    /// Ties the node pointers to the arena's lifetime and marks ownership of `T`.
+    _marker: PhantomData<(&'bump Bump, T)>,
+}
+
+impl<'bump, T> ArenaLinkedList<'bump, T> {
+    /// Creates a new, empty list that allocates its nodes in `bump`.
+    ///
+    /// # Parameters
+    /// - `bump`: The arena to allocate nodes from.
+    pub fn new_in(bump: &'bump Bump) -> Self {
+        ArenaLinkedList {
+            bump,
+            head: None,
+            tail: None,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts an element at the front of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        let node = self.alloc_node(data, self.head);
+        if self.tail.is_none() {
+            self.tail = Some(node);
+        }
+        self.head = Some(node);
+    }
+
+    /// Inserts an element at the back of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        let node = self.alloc_node(data, None);
+        match self.tail {
+            // SAFETY: `tail` is a live node in the arena, uniquely borrowed via `&mut self`.
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(node) },
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            current: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Allocates a node in the arena.
+    fn alloc_node(&mut self, data: T, next: Link<T>) -> NonNull<Node<T>> {
+        self.len += 1;
+        NonNull::from(self.bump.alloc(Node {
+            data: ManuallyDrop::new(data),
+            next,
+        }))
+    }
+
+    /// Returns the node at `index`, which must be less than `len`.
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        let mut current = self.head.expect("index must be in bounds");
+        for _ in 0..index {
+            // SAFETY: the first `len` nodes reachable from `head` are live.
+            current = unsafe { (*current.as_ptr()).next.expect("index must be in bounds") };
+        }
+        current
+    }
+
+    /// Returns `(prev, node)` for the first node whose data equals `data`.
+    fn locate_match<Q>(&self, data: &Q) -> Option<(Link<T>, NonNull<Node<T>>)>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut prev = None;
+        let mut current = self.head;
+        while let Some(node) = current {
+            // SAFETY: every node reachable from `head` is live.
+            unsafe {
+                if (*(*node.as_ptr()).data).borrow() == data {
+                    return Some((prev, node));
+                }
+                prev = current;
+                current = (*node.as_ptr()).next;
+            }
+        }
+        None
+    }
+
+    /// Unlinks `node` (whose predecessor is `prev`) and drops its data in place.
+    ///
+    /// The node's memory stays in the arena.
+    ///
+    /// # Safety
+    /// `node` must be a live node of this list and `prev` its actual predecessor.
+    unsafe fn unlink(&mut self, prev: Link<T>, node: NonNull<Node<T>>) {
+        let next = (*node.as_ptr()).next;
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = next,
+            None => self.head = next,
+        }
+        if self.tail == Some(node) {
+            self.tail = prev;
+        }
+        self.len -= 1;
+        ManuallyDrop::drop(&mut (*node.as_ptr()).data);
+    }
+}
+
+impl<T> Drop for ArenaLinkedList<'_, T> {
+    /// Drops the elements if `T` needs it; the node memory belongs to the arena.
+    fn drop(&mut self) {
+        if !mem::needs_drop::<T>() {
+            return;
+        }
+        let mut current = self.head;
+        while let Some(node) = current {
+            // SAFETY: each live node's data is dropped exactly once, here.
+            unsafe {
+                current = (*node.as_ptr()).next;
+                ManuallyDrop::drop(&mut (*node.as_ptr()).data);
+            }
+        }
+    }
+}
+
+impl<T: Debug> Debug for ArenaLinkedList<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> ListRead<T> for ArenaLinkedList<'_, T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the data at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+}
+
+impl<T> ListWrite<T> for ArenaLinkedList<'_, T> {
+    /// Inserts an element at the end (tail) of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        if index == 0 {
+            self.push_front(data);
+        } else if index == self.len {
+            self.push_back(data);
+        } else {
+            let prev = self.node_at(index - 1);
+            // SAFETY: `prev` is a live, non-tail node of this list.
+            unsafe {
+                let node = self.alloc_node(data, (*prev.as_ptr()).next);
+                (*prev.as_ptr()).next = Some(node);
+            }
+        }
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.locate_match(data) {
+            Some((prev, node)) => {
+                // SAFETY: `locate_match` returns a live node and its predecessor.
+                unsafe { self.unlink(prev, node) };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let prev = if index == 0 { None } else { Some(self.node_at(index - 1)) };
+        let node = match prev {
+            // SAFETY: `prev` is live and not the tail, since `index < len`.
+            Some(prev) => unsafe { (*prev.as_ptr()).next.unwrap() },
+            None => self.head.unwrap(),
+        };
+        // SAFETY: `node` is live and `prev` is its predecessor.
+        unsafe { self.unlink(prev, node) };
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let node = self.node_at(index);
+        // SAFETY: `node` is live and uniquely borrowed through `&mut self`.
+        unsafe { Some(&mut *(*node.as_ptr()).data) }
+    }
+
+    /// Updates the data of the node at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the node to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let node = self.node_at(index);
+        // SAFETY: `node` is live and uniquely borrowed through `&mut self`.
+        unsafe { *(*node.as_ptr()).data = data };
+        Ok(())
+    }
+}
+
+impl<T> LinkedListTrait<T> for ArenaLinkedList<'_, T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        ArenaLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        ArenaLinkedList::iter_mut(self)
+    }
+}
+
+/// An iterator over the elements of an `ArenaLinkedList`.
+pub struct Iter<'a, T> {
+    /// The next node to yield.
+    current: Link<T>,
+    /// Ties the iterator to the borrowed list.
+    _marker: PhantomData<&'a T>,
+}
+
+// SAFETY: the iterator only reads through its pointers, like the `&T`s it yields, and
+// never touches the arena.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: as above.
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.current?;
+        // SAFETY: the node is live for as long as the list is borrowed.
+        unsafe {
+            self.current = (*node.as_ptr()).next;
+            Some(&(*node.as_ptr()).data)
+        }
+    }
+}
+
+/// An iterator over mutable references to the elements of an `ArenaLinkedList`.
+pub struct IterMut<'a, T> {
+    /// The next node to yield.
+    current: Link<T>,
+    /// Ties the iterator to the mutably borrowed list.
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator hands out each element once, like the `&mut T`s it yields, and
+// never touches the arena.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared iterator gives no access to the elements.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let node = self.current?;
+        // SAFETY: the node is live and uniquely borrowed for as long as the list is, and
+        // each node is yielded once.
+        unsafe {
+            self.current = (*node.as_ptr()).next;
+            Some(&mut (*node.as_ptr()).data)
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! The `bench` subcommand of the binary: timed workloads printed as CSV.
+//!
+//! ```text
+//! linked_list_impls bench --size 1000 --ops 10000 --mix insert=30,get=50,delete=20 \
+//!     --impl dynamic,static --runs 3
+//! ```
+//!
+//! Each implementation is filled with `size` elements and then runs the same `ops`
+//! operations, drawn from the mix with a fixed seed, at random positions. One CSV row is
+//! printed per implementation and run. Build with `--release` for meaningful numbers.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+use linked_list_impls::skip_list::IndexedSkipList;
+use linked_list_impls::static_linked_list::StaticLinkedList;
+use linked_list_impls::ListWrite;
+
+/// The capacity of the static list under test; `size` plus the inserts must fit in it.
+const STATIC_CAPACITY: usize = 1 << 14;
+
+/// The implementations `--impl` accepts.
+const IMPLEMENTATIONS: [&str; 4] = ["dynamic", "static", "doubly", "skip"];
+
+/// The CSV header.
+const HEADER: &str = "implementation,run,size,ops,insert,get,delete,total_ns,ns_per_op";
+
+/// One operation of a workload, with the position it applies to.
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Insert(usize),
+    Get(usize),
+    Delete(usize),
+}
+
+/// The parameters of a benchmark.
+#[derive(Debug)]
+struct Config {
+    /// The number of elements to start with.
+    size: usize,
+    /// The number of timed operations.
+    ops: usize,
+    /// The relative weights of inserts, gets and deletes.
+    mix: [u32; 3],
+    /// The implementations to run, by name.
+    implementations: Vec<String>,
+    /// How many times to run each implementation.
+    runs: usize,
+}
+
+impl Config {
+    /// Parses the arguments after `bench`.
+    ///
+    /// # Returns
+    /// - `Err(String)` with a message for an unknown option or a bad value.
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut config = Config {
+            size: 1000,
+            ops: 10_000,
+            mix: [30, 50, 20],
+            implementations: vec!["dynamic".to_string(), "static".to_string()],
+            runs: 1,
+        };
+        let mut args = args.iter();
+        while let Some(option) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {option}"))?;
+            match option.as_str() {
+                "--size" => config.size = number(option, value)?,
+                "--ops" => config.ops = number(option, value)?,
+                "--runs" => config.runs = number(option, value)?,
+                "--mix" => config.mix = parse_mix(value)?,
+                "--impl" => {
+                    config.implementations = value.split(',').map(str::to_string).collect();
+                    if let Some(unknown) = config
+                        .implementations
+                        .iter()
+                        .find(|name| !IMPLEMENTATIONS.contains(&name.as_str()))
+                    {
+                        return Err(format!(
+                            "unknown implementation `{unknown}`; use {}",
+                            IMPLEMENTATIONS.join(", ")
+                        ));
+                    }
+                }
+                _ => return Err(format!("unknown option `{option}`")),
+            }
+        }
+        if config.mix.iter().all(|&weight| weight == 0) {
+            return Err("the mix needs at least one nonzero weight".to_string());
+        }
+        Ok(config)
+    }
+}
+
+/// Parses the value of a numeric option.
+fn number(option: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for {option}"))
+}
+
+/// Parses a mix such as `insert=30,get=50,delete=20`; missing operations get weight 0.
+fn parse_mix(value: &str) -> Result<[u32; 3], String> {
+    let mut mix = [0; 3];
+    for part in value.split(',') {
+        let (name, weight) = part
+            .split_once('=')
+            .ok_or_else(|| format!("invalid mix entry `{part}`; use name=weight"))?;
+        let slot = match name {
+            "insert" => 0,
+            "get" => 1,
+            "delete" => 2,
+            _ => return Err(format!("unknown operation `{name}` in the mix")),
+        };
+        mix[slot] = weight
+            .parse()
+            .map_err(|_| format!("invalid weight `{weight}` for {name}"))?;
+    }
+    // The workload draws from the sum of the weights, so it has to fit in a `u32`.
+    mix.iter()
+        .try_fold(0u32, |total, &weight| total.checked_add(weight))
+        .ok_or_else(|| format!("the mix weights add up to more than {}", u32::MAX))?;
+    Ok(mix)
+}
+
+/// A xorshift generator, so every implementation sees the same operations without a
+/// dependency on `rand`.
+struct XorShift(u64);
+
+impl XorShift {
+    /// Returns a number below `bound`, which must not be zero.
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+/// Draws `config.ops` operations from the mix, with positions that are valid for the
+/// list at that point. A delete from an empty list becomes an insert.
+fn workload(config: &Config) -> Vec<Op> {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    let total: u32 = config.mix.iter().sum();
+    let mut len = config.size;
+    (0..config.ops)
+        .map(|_| {
+            let roll = rng.below(total as usize) as u32;
+            if roll < config.mix[0] || (len == 0 && roll >= total - config.mix[2]) {
+                len += 1;
+                Op::Insert(rng.below(len))
+            } else if roll < config.mix[0] + config.mix[1] {
+                Op::Get(rng.below(len.max(1)))
+            } else {
+                len -= 1;
+                Op::Delete(rng.below(len + 1))
+            }
+        })
+        .collect()
+}
+
+/// Fills `list` with `size` elements, then times the operations.
+fn time<L: ListWrite<i64>>(mut list: L, size: usize, ops: &[Op]) -> Duration {
+    for value in 0..size {
+        list.insert(value as i64);
+    }
+    let start = Instant::now();
+    for &op in ops {
+        match op {
+            Op::Insert(index) => {
+                black_box(list.insert_at_index(index, index as i64)).unwrap();
+            }
+            Op::Get(index) => {
+                black_box(list.get(index));
+            }
+            Op::Delete(index) => {
+                black_box(list.delete_at_index(index)).unwrap();
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+    black_box(list.len());
+    elapsed
+}
+
+/// Runs the `bench` subcommand and prints the CSV to stdout.
+///
+/// # Returns
+/// - `Err(String)` with a message if the arguments are invalid or the workload does not
+///   fit in the static list.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let config = Config::parse(args)?;
+    let ops = workload(&config);
+    let inserts = ops.iter().filter(|op| matches!(op, Op::Insert(_))).count();
+    if config.implementations.iter().any(|name| name == "static")
+        && config.size + inserts > STATIC_CAPACITY
+    {
+        return Err(format!(
+            "size plus inserts ({}) exceeds the static list's capacity of {STATIC_CAPACITY}",
+            config.size + inserts
+        ));
+    }
+    println!("{HEADER}");
+    for name in &config.implementations {
+        for run in 1..=config.runs {
+            let elapsed = match name.as_str() {
+                "dynamic" => time(DynamicLinkedList::new(), config.size, &ops),
+                "static" => time(
+                    Box::new(StaticLinkedList::<i64, STATIC_CAPACITY>::new()),
+                    config.size,
+                    &ops,
+                ),
+                "doubly" => time(DoublyLinkedList::new(), config.size, &ops),
+                "skip" => time(IndexedSkipList::new(), config.size, &ops),
+                _ => unreachable!("checked when parsing"),
+            };
+            let total_ns = elapsed.as_nanos();
+            println!(
+                "{name},{run},{},{},{},{},{},{total_ns},{:.1}",
+                config.size,
+                config.ops,
+                config.mix[0],
+                config.mix[1],
+                config.mix[2],
+                total_ns as f64 / config.ops.max(1) as f64
+            );
+        }
+    }
+    Ok(())
+}
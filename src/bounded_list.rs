@@ -0,0 +1,232 @@
+use core::marker::PhantomData;
+
+use crate::counted::Counted;
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// What a [`BoundedList`] does with an insertion once it holds its maximum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Refuse the new element with a `CapacityExceeded` error.
+    #[default]
+    Reject,
+    /// Delete the first (oldest) element to make room for the new one.
+    EvictOldest,
+}
+
+/// `BoundedList` caps a list at a maximum length, applying an [`OverflowPolicy`] when an
+/// insertion would exceed it. Build one with [`ListBuilder`](crate::list_builder::ListBuilder).
+///
+/// The policy only governs the configured maximum length. A backing store with a fixed
+/// capacity of its own, such as a `StaticLinkedList<T, N>`, still rejects insertions past
+/// `N`, so set the maximum length to at most `N` to have the policy apply.
+#[derive(Debug)]
+pub struct BoundedList<T, L> {
+    /// The backing list, counted so the bound can be checked in O(1).
+    list: Counted<L>,
+    /// The maximum number of elements, or `None` for no bound.
+    max_len: Option<usize>,
+    /// What to do with an insertion past `max_len`.
+    policy: OverflowPolicy,
+    _marker: PhantomData<T>,
+}
+
+impl<T, L: ListWrite<T>> BoundedList<T, L> {
+    /// Wraps an empty backing list with the given bound and policy.
+    pub(crate) fn new(list: L, max_len: Option<usize>, policy: OverflowPolicy) -> Self {
+        BoundedList {
+            list: Counted::new(list),
+            max_len,
+            policy,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L> BoundedList<T, L> {
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the maximum number of elements, or `None` if the list is unbounded.
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Returns the policy applied to insertions past the maximum length.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Returns a reference to the backing list.
+    pub fn inner(&self) -> &L {
+        self.list.inner()
+    }
+
+    /// Consumes the wrapper and returns the backing list.
+    pub fn into_inner(self) -> L {
+        self.list.into_inner()
+    }
+}
+
+impl<T, L: ListRead<T>> ListRead<T> for BoundedList<T, L> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.list.position_by(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+}
+
+impl<T, L: ListWrite<T>> ListWrite<T> for BoundedList<T, L> {
+    /// Inserts an element at the end (tail) of the list, applying the overflow policy if
+    /// the list is at its maximum length.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        let _ = self.try_insert(data);
+    }
+
+    /// Inserts an element at the end (tail) of the list, applying the overflow policy if
+    /// the list is at its maximum length.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::CapacityExceeded)` if the list is at its maximum length under
+    ///   `Reject`.
+    /// - `Err(LinkedListError)` with the backing list's error otherwise.
+    fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.insert_at_index(self.len(), data)
+    }
+
+    /// Inserts an element at a specific index in the list, applying the overflow policy if
+    /// the list is at its maximum length.
+    ///
+    /// With `EvictOldest`, the first element is deleted before inserting, so `index` refers
+    /// to the list as it was before the eviction.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    /// - `Err(LinkedListError::CapacityExceeded)` if the list is at its maximum length under
+    ///   `Reject`.
+    /// - `Err(LinkedListError)` with the backing list's error otherwise.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len() {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        if let Some(max_len) = self.max_len.filter(|&max_len| self.len() >= max_len) {
+            match self.policy {
+                OverflowPolicy::Reject => {
+                    return Err(LinkedListError::CapacityExceeded { capacity: max_len });
+                }
+                OverflowPolicy::EvictOldest if self.is_empty() => {
+                    return Err(LinkedListError::CapacityExceeded { capacity: max_len });
+                }
+                OverflowPolicy::EvictOldest => {
+                    self.list.delete_at_index(0)?;
+                    return self.list.insert_at_index(index.saturating_sub(1), data);
+                }
+            }
+        }
+        self.list.insert_at_index(index, data)
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the backing list's error otherwise.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        self.list.delete_at_index(index)
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    /// Updates the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the backing list's error otherwise.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list.update_element_at_index(index, data)
+    }
+}
+
+impl<T, L: LinkedListTrait<T>> LinkedListTrait<T> for BoundedList<T, L> {
+    type Iter<'a>
+        = L::Iter<'a>
+    where
+        Self: 'a,
+        T: 'a;
+    type IterMut<'a>
+        = L::IterMut<'a>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns an iterator over the elements of the backing list, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator that allows modifying each element of the backing list.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.list.iter_mut()
+    }
+}
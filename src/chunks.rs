@@ -0,0 +1,182 @@
+//! Iterators that walk a list once and yield its elements in groups of adjacent
+//! elements, made with the methods of [`LinkedListTrait`](crate::LinkedListTrait).
+//!
+//! A linked list has no slices to lend, so each group is collected into a `Vec` of
+//! references to the elements; the elements themselves are never copied.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// An iterator over the elements of a list in groups of `size`, made with
+/// [`LinkedListTrait::chunks`](crate::LinkedListTrait::chunks). The last group is
+/// shorter if the length does not divide evenly.
+#[derive(Debug, Clone)]
+pub struct Chunks<I> {
+    /// The iterator over the elements not yet grouped.
+    iter: I,
+    /// The number of elements in each group.
+    size: usize,
+}
+
+impl<I> Chunks<I> {
+    /// Groups the items of `iter` by `size`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks { iter, size }
+    }
+}
+
+impl<I: Iterator> Iterator for Chunks<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        (
+            low.div_ceil(self.size),
+            high.map(|high| high.div_ceil(self.size)),
+        )
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Chunks<I> {}
+
+/// An iterator over every run of `size` adjacent elements of a list, overlapping and
+/// front to back, made with [`LinkedListTrait::windows`](crate::LinkedListTrait::windows).
+/// A list shorter than `size` has no windows.
+///
+/// The current window is kept in a ring buffer, so each step reads one new element.
+#[derive(Debug, Clone)]
+pub struct Windows<I: Iterator> {
+    /// The iterator over the elements after the current window.
+    iter: I,
+    /// The elements of the current window, empty before the first one.
+    window: VecDeque<I::Item>,
+    /// The number of elements in each window.
+    size: usize,
+}
+
+impl<I: Iterator> Windows<I> {
+    /// Slides a window of `size` over the items of `iter`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Windows {
+            iter,
+            window: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+}
+
+impl<I> Iterator for Windows<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+        while self.window.len() < self.size {
+            match self.iter.next() {
+                Some(item) => self.window.push_back(item),
+                None => {
+                    self.window.clear();
+                    return None;
+                }
+            }
+        }
+        Some(self.window.iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        // Each window after the first needs one more element.
+        let missing = self.size - self.window.len().min(self.size - 1);
+        let windows = |remaining: usize| (remaining + 1).saturating_sub(missing);
+        (windows(low), high.map(windows))
+    }
+}
+
+impl<I> FusedIterator for Windows<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
+/// An iterator over the maximal runs of adjacent elements of a list for which
+/// `predicate` holds between each element and the next, made with
+/// [`LinkedListTrait::chunk_by`](crate::LinkedListTrait::chunk_by). It mirrors
+/// `slice::chunk_by`.
+#[derive(Debug, Clone)]
+pub struct ChunkBy<'a, T, I, F> {
+    /// The iterator over the elements after `next`.
+    iter: I,
+    /// The first element of the next run, read while ending the previous one.
+    next: Option<&'a T>,
+    /// The test between two adjacent elements that keeps them in the same run.
+    predicate: F,
+}
+
+impl<'a, T, I, F> ChunkBy<'a, T, I, F> {
+    /// Groups the items of `iter` into runs joined by `predicate`.
+    pub(crate) fn new(iter: I, predicate: F) -> Self {
+        ChunkBy {
+            iter,
+            next: None,
+            predicate,
+        }
+    }
+}
+
+impl<'a, T, I, F> Iterator for ChunkBy<'a, T, I, F>
+where
+    I: Iterator<Item = &'a T>,
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Vec<&'a T>> {
+        let mut last = self.next.take().or_else(|| self.iter.next())?;
+        let mut run = Vec::from([last]);
+        for item in self.iter.by_ref() {
+            if !(self.predicate)(last, item) {
+                self.next = Some(item);
+                break;
+            }
+            run.push(item);
+            last = item;
+        }
+        Some(run)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let pending = usize::from(self.next.is_some());
+        (
+            low.saturating_add(pending).min(1),
+            high.and_then(|high| high.checked_add(pending)),
+        )
+    }
+}
+
+impl<'a, T, I, F> FusedIterator for ChunkBy<'a, T, I, F>
+where
+    I: FusedIterator<Item = &'a T>,
+    F: FnMut(&T, &T) -> bool,
+{
+}
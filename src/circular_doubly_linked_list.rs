@@ -0,0 +1,626 @@
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// `Node` represents a single element (or the sentinel) in the circular doubly linked list.
+struct Node<T> {
+    /// The previous node. The first element's `prev` is the sentinel.
+    prev: NonNull<Node<T>>,
+    /// The next node. The last element's `next` is the sentinel.
+    next: NonNull<Node<T>>,
+    /// The data stored in the node. Always initialized, except in the sentinel.
+    data: MaybeUninit<T>,
+}
+
+/// `CircularDoublyLinkedList` is a circular doubly linked list built around a sentinel node.
+///
+/// The sentinel sits between the last and the first element, so every real node always
+/// has both neighbours and insertion/removal never special-cases the ends. Pushing and
+/// popping at either end is O(1), and whole lists can be spliced into each other in O(1)
+/// by relinking their boundary nodes.
+pub struct CircularDoublyLinkedList<T> {
+    /// The heap-allocated sentinel node. Its data is never initialized.
+    sentinel: NonNull<Node<T>>,
+    /// The number of elements in the list (excluding the sentinel).
+    len: usize,
+    /// Tells the compiler this type owns boxed nodes containing `T`.
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: the list uniquely owns its nodes, like a `Box` chain; the raw pointers are never
+// shared with another list, so moving it moves ownership of every `T`.
+unsafe impl<T: Send> Send for CircularDoublyLinkedList<T> {}
+// SAFETY: `&CircularDoublyLinkedList` only gives out `&T` and never mutates nodes.
+unsafe impl<T: Sync> Sync for CircularDoublyLinkedList<T> {}
+
+impl<T> CircularDoublyLinkedList<T> {
+    /// Creates a new, empty `CircularDoublyLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty list whose sentinel links to itself.
+    pub fn new() -> Self {
+        let sentinel = Box::new(Node {
+            prev: NonNull::dangling(),
+            next: NonNull::dangling(),
+            data: MaybeUninit::uninit(),
+        });
+        let sentinel = NonNull::from(Box::leak(sentinel));
+        // SAFETY: `sentinel` was just allocated and is uniquely owned here.
+        unsafe {
+            (*sentinel.as_ptr()).prev = sentinel;
+            (*sentinel.as_ptr()).next = sentinel;
+        }
+
+        CircularDoublyLinkedList {
+            sentinel,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: the sentinel is always valid, and a non-sentinel node's data is initialized.
+        unsafe { self.data_ref((*self.sentinel.as_ptr()).next) }
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: see `front`.
+        unsafe { self.data_ref((*self.sentinel.as_ptr()).prev) }
+    }
+
+    /// Inserts an element at the front of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        // SAFETY: the sentinel and its `next` are valid nodes of this list.
+        unsafe {
+            let next = (*self.sentinel.as_ptr()).next;
+            self.link_between(data, self.sentinel, next);
+        }
+    }
+
+    /// Inserts an element at the back of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        // SAFETY: the sentinel and its `prev` are valid nodes of this list.
+        unsafe {
+            let prev = (*self.sentinel.as_ptr()).prev;
+            self.link_between(data, prev, self.sentinel);
+        }
+    }
+
+    /// Removes and returns the first element in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: the list is non-empty, so the sentinel's `next` is a real node.
+        unsafe { Some(self.unlink((*self.sentinel.as_ptr()).next)) }
+    }
+
+    /// Removes and returns the last element in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: the list is non-empty, so the sentinel's `prev` is a real node.
+        unsafe { Some(self.unlink((*self.sentinel.as_ptr()).prev)) }
+    }
+
+    /// Moves all elements of `other` to the back of this list in O(1).
+    ///
+    /// `other` is left empty.
+    ///
+    /// # Parameters
+    /// - `other`: The list whose nodes are spliced in.
+    pub fn append(&mut self, other: &mut Self) {
+        let len = self.len;
+        // `len` is always a valid splice position.
+        let _ = self.splice_at(len, other);
+    }
+
+    /// Moves all elements of `other` to the front of this list in O(1).
+    ///
+    /// `other` is left empty.
+    ///
+    /// # Parameters
+    /// - `other`: The list whose nodes are spliced in.
+    pub fn prepend(&mut self, other: &mut Self) {
+        let _ = self.splice_at(0, other);
+    }
+
+    /// Moves all elements of `other` into this list so the first of them ends up at `index`.
+    ///
+    /// Finding the position walks from the closer end; the splice itself only relinks
+    /// four pointers, regardless of the length of `other`. `other` is left empty.
+    ///
+    /// # Parameters
+    /// - `index`: The position at which to splice (0-based, may equal `len()`).
+    /// - `other`: The list whose nodes are spliced in.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if `index > len()`.
+    pub fn splice_at(&mut self, index: usize, other: &mut Self) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        if other.is_empty() {
+            return Ok(());
+        }
+
+        // SAFETY: `next` is a node of `self` (possibly the sentinel), `prev` is its
+        // predecessor, and `first..=last` is the non-empty chain owned by `other`,
+        // which is reset to empty so that the nodes have exactly one owner.
+        unsafe {
+            let next = self.node_at(index);
+            let prev = (*next.as_ptr()).prev;
+            let first = (*other.sentinel.as_ptr()).next;
+            let last = (*other.sentinel.as_ptr()).prev;
+
+            (*prev.as_ptr()).next = first;
+            (*first.as_ptr()).prev = prev;
+            (*last.as_ptr()).next = next;
+            (*next.as_ptr()).prev = last;
+
+            (*other.sentinel.as_ptr()).next = other.sentinel;
+            (*other.sentinel.as_ptr()).prev = other.sentinel;
+        }
+
+        self.len += other.len;
+        other.len = 0;
+        Ok(())
+    }
+
+    /// Returns a double-ended iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        // SAFETY: the sentinel is always valid.
+        unsafe {
+            Iter {
+                front: (*self.sentinel.as_ptr()).next,
+                back: (*self.sentinel.as_ptr()).prev,
+                remaining: self.len,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Returns a double-ended iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        // SAFETY: the sentinel is always valid.
+        unsafe {
+            IterMut {
+                front: (*self.sentinel.as_ptr()).next,
+                back: (*self.sentinel.as_ptr()).prev,
+                remaining: self.len,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Returns the node at logical position `index`, or the sentinel when `index == len`.
+    ///
+    /// Walks from whichever end is closer.
+    ///
+    /// # Safety
+    /// `index` must be at most `self.len`.
+    unsafe fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        let mut current = self.sentinel;
+        if index < self.len / 2 {
+            for _ in 0..=index {
+                current = (*current.as_ptr()).next;
+            }
+        } else {
+            for _ in index..self.len {
+                current = (*current.as_ptr()).prev;
+            }
+        }
+        current
+    }
+
+    /// Returns the first node whose data equals `data`.
+    fn node_of<Q>(&self, data: &Q) -> Option<NonNull<Node<T>>>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        // SAFETY: every node between the sentinel's `next` and the sentinel is a real,
+        // initialized node of this list.
+        unsafe {
+            let mut current = (*self.sentinel.as_ptr()).next;
+            while current != self.sentinel {
+                if (*current.as_ptr()).data.assume_init_ref().borrow() == data {
+                    return Some(current);
+                }
+                current = (*current.as_ptr()).next;
+            }
+        }
+        None
+    }
+
+    /// Returns a reference to the data in `node`, or `None` if `node` is the sentinel.
+    ///
+    /// # Safety
+    /// `node` must be a node of this list.
+    unsafe fn data_ref(&self, node: NonNull<Node<T>>) -> Option<&T> {
+        if node == self.sentinel {
+            None
+        } else {
+            Some((*node.as_ptr()).data.assume_init_ref())
+        }
+    }
+
+    /// Allocates a node holding `data` and links it between `prev` and `next`.
+    ///
+    /// # Safety
+    /// `prev` and `next` must be adjacent nodes of this list (`prev.next == next`).
+    unsafe fn link_between(&mut self, data: T, prev: NonNull<Node<T>>, next: NonNull<Node<T>>) {
+        let node = Box::new(Node {
+            prev,
+            next,
+            data: MaybeUninit::new(data),
+        });
+        let node = NonNull::from(Box::leak(node));
+        (*prev.as_ptr()).next = node;
+        (*next.as_ptr()).prev = node;
+        self.len += 1;
+    }
+
+    /// Unlinks `node` from its neighbours, frees it, and returns its data.
+    ///
+    /// # Safety
+    /// `node` must be a real (non-sentinel) node of this list.
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        let boxed = Box::from_raw(node.as_ptr());
+        (*boxed.prev.as_ptr()).next = boxed.next;
+        (*boxed.next.as_ptr()).prev = boxed.prev;
+        self.len -= 1;
+        boxed.data.assume_init()
+    }
+}
+
+impl<T> Drop for CircularDoublyLinkedList<T> {
+    /// Frees every node iteratively, then the sentinel.
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+        // SAFETY: the sentinel was allocated with `Box` in `new()` and is freed only here.
+        // Its data is `MaybeUninit`, so nothing is dropped for it.
+        unsafe {
+            drop(Box::from_raw(self.sentinel.as_ptr()));
+        }
+    }
+}
+
+impl<T> Default for CircularDoublyLinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for CircularDoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> ListRead<T> for CircularDoublyLinkedList<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the data at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: `index < len`, so `node_at` returns a real node of this list.
+        unsafe { self.data_ref(self.node_at(index)) }
+    }
+}
+
+impl<T> ListWrite<T> for CircularDoublyLinkedList<T> {
+    /// Inserts an element at the end (tail) of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        // SAFETY: `index <= len`, so `next` is a node of this list and `prev` its predecessor.
+        unsafe {
+            let next = self.node_at(index);
+            let prev = (*next.as_ptr()).prev;
+            self.link_between(data, prev, next);
+        }
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.node_of(data) {
+            Some(node) => {
+                // SAFETY: `node_of` only returns real nodes of this list.
+                unsafe { self.unlink(node) };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        // SAFETY: `index < len`, so `node_at` returns a real node.
+        unsafe {
+            let node = self.node_at(index);
+            self.unlink(node);
+        }
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: `index < len`, so `node_at` returns a real, initialized node.
+        unsafe { Some((*self.node_at(index).as_ptr()).data.assume_init_mut()) }
+    }
+
+    /// Updates the data of the node at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the node to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        // SAFETY: `index < len`, so `node_at` returns a real, initialized node.
+        unsafe {
+            let node = self.node_at(index);
+            *(*node.as_ptr()).data.assume_init_mut() = data;
+        }
+        Ok(())
+    }
+}
+
+impl<T> LinkedListTrait<T> for CircularDoublyLinkedList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        CircularDoublyLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        CircularDoublyLinkedList::iter_mut(self)
+    }
+}
+
+/// A double-ended iterator over the elements of a `CircularDoublyLinkedList`.
+pub struct Iter<'a, T> {
+    /// The next node to yield from the front.
+    front: NonNull<Node<T>>,
+    /// The next node to yield from the back.
+    back: NonNull<Node<T>>,
+    /// The number of elements not yet yielded from either end.
+    remaining: usize,
+    /// Ties the iterator to the borrowed list.
+    _marker: PhantomData<&'a T>,
+}
+
+// SAFETY: the iterator only reads through its pointers, like the `&T`s it yields.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: as above.
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `front` is a real node that outlives `'a`.
+        unsafe {
+            let node = &*self.front.as_ptr();
+            self.front = node.next;
+            self.remaining -= 1;
+            Some(node.data.assume_init_ref())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `back` is a real node that outlives `'a`.
+        unsafe {
+            let node = &*self.back.as_ptr();
+            self.back = node.prev;
+            self.remaining -= 1;
+            Some(node.data.assume_init_ref())
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// A double-ended iterator over mutable references to the elements of a
+/// `CircularDoublyLinkedList`.
+pub struct IterMut<'a, T> {
+    /// The next node to yield from the front.
+    front: NonNull<Node<T>>,
+    /// The next node to yield from the back.
+    back: NonNull<Node<T>>,
+    /// The number of elements not yet yielded from either end.
+    remaining: usize,
+    /// Ties the iterator to the mutably borrowed list.
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator hands out each element once, like the `&mut T`s it yields.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared iterator gives no access to the elements.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `front` is a real node that outlives `'a` and has
+        // not been yielded from either end.
+        unsafe {
+            let node = &mut *self.front.as_ptr();
+            self.front = node.next;
+            self.remaining -= 1;
+            Some(node.data.assume_init_mut())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `back` is a real node that outlives `'a` and has
+        // not been yielded from either end.
+        unsafe {
+            let node = &mut *self.back.as_ptr();
+            self.back = node.prev;
+            self.remaining -= 1;
+            Some(node.data.assume_init_mut())
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
@@ -0,0 +1,478 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+use crate::slots::SlotNode;
+
+pub use crate::slots::IterMut;
+
+/// `Node` represents a single element in the circular linked list.
+#[derive(Debug)]
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The index of the next node. The tail's `next` is the head.
+    next: usize,
+}
+
+/// `CircularLinkedList` is a singly linked list whose tail links back to its head.
+///
+/// Only the tail index is stored: the head is always `tail.next`, which gives O(1)
+/// insertion at both ends and O(1) rotation. Nodes live in a growable slot vector and
+/// refer to each other by index, with freed slots reused by later insertions.
+#[derive(Debug)]
+pub struct CircularLinkedList<T> {
+    /// Slot storage for the nodes. `None` marks a free slot.
+    nodes: Vec<Option<Node<T>>>,
+    /// Indices of free slots in `nodes`.
+    free: Vec<usize>,
+    /// The index of the last node in the list.
+    tail: Option<usize>,
+    /// The number of elements in the list.
+    len: usize,
+}
+
+impl<T> CircularLinkedList<T> {
+    /// Creates a new, empty `CircularLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `CircularLinkedList` instance.
+    pub fn new() -> Self {
+        CircularLinkedList {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            tail: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.head().map(|i| &self.node(i).data)
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|i| &self.node(i).data)
+    }
+
+    /// Inserts an element at the front of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        let index = self.link_after_tail(data);
+        if self.tail.is_none() {
+            self.tail = Some(index);
+        }
+    }
+
+    /// Inserts an element at the back of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        let index = self.link_after_tail(data);
+        self.tail = Some(index);
+    }
+
+    /// Removes and returns the first element in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        Some(self.unlink_after(tail))
+    }
+
+    /// Rotates the list by one position, so the current head becomes the tail.
+    ///
+    /// This is O(1) and is the basic step of round-robin scheduling: the element
+    /// returned by `front()` advances to the next one on every call.
+    pub fn rotate(&mut self) {
+        if let Some(head) = self.head() {
+            self.tail = Some(head);
+        }
+    }
+
+    /// Returns an iterator over the elements, going once around the list from the head.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            current: self.head(),
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    ///
+    /// Nodes only link forwards, so iterating it from the back walks from the front for
+    /// every element after the last.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let head = self.head();
+        IterMut::new(&mut self.nodes, head, self.tail, self.len)
+    }
+
+    /// Returns an iterator that cycles through the elements endlessly, starting at the head.
+    ///
+    /// The iterator yields nothing if the list is empty.
+    pub fn cycle(&self) -> Cycle<'_, T> {
+        Cycle {
+            list: self,
+            current: self.head(),
+        }
+    }
+
+    /// Returns the index of the head node, which is the node after the tail.
+    fn head(&self) -> Option<usize> {
+        self.tail.map(|tail| self.node(tail).next)
+    }
+
+    /// Returns a shared reference to the node stored in an occupied slot.
+    fn node(&self, index: usize) -> &Node<T> {
+        self.nodes[index].as_ref().expect("linked slot must be occupied")
+    }
+
+    /// Returns a mutable reference to the node stored in an occupied slot.
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        self.nodes[index].as_mut().expect("linked slot must be occupied")
+    }
+
+    /// Links a new node directly after the tail (i.e. as the new head) without
+    /// moving `tail`, and returns its slot index.
+    ///
+    /// On an empty list the new node links to itself; callers set `tail` as needed.
+    fn link_after_tail(&mut self, data: T) -> usize {
+        match self.tail {
+            Some(tail) => self.link_after(tail, data),
+            None => {
+                let index = self.allocate_node(data, 0);
+                self.node_mut(index).next = index;
+                index
+            }
+        }
+    }
+
+    /// Links a new node directly after the node at slot `prev` and returns its slot index.
+    fn link_after(&mut self, prev: usize, data: T) -> usize {
+        let next = self.node(prev).next;
+        let index = self.allocate_node(data, next);
+        self.node_mut(prev).next = index;
+        index
+    }
+
+    /// Unlinks the node following slot `prev`, frees its slot, and returns its data.
+    ///
+    /// Keeps `tail` valid when the removed node was the tail or the only node.
+    fn unlink_after(&mut self, prev: usize) -> T {
+        let index = self.node(prev).next;
+        let node = self.nodes[index].take().expect("linked slot must be occupied");
+        self.free.push(index);
+        self.len -= 1;
+
+        if self.len == 0 {
+            self.tail = None;
+        } else {
+            self.node_mut(prev).next = node.next;
+            if self.tail == Some(index) {
+                self.tail = Some(prev);
+            }
+        }
+        node.data
+    }
+
+    /// Stores a new node in a free slot (growing the storage if needed).
+    fn allocate_node(&mut self, data: T, next: usize) -> usize {
+        let node = Some(Node { data, next });
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Returns the slot of the node *before* logical position `index`.
+    ///
+    /// Position 0's predecessor is the tail, so this is valid for `index <= len`
+    /// on a non-empty list.
+    fn slot_before(&self, index: usize) -> Option<usize> {
+        let mut current = self.tail?;
+        for _ in 0..index {
+            current = self.node(current).next;
+        }
+        Some(current)
+    }
+
+    /// Returns the slot of the node before the first element equal to `data`.
+    fn slot_before_match<Q>(&self, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut prev = self.tail?;
+        for _ in 0..self.len {
+            let next = self.node(prev).next;
+            if self.node(next).data.borrow() == data {
+                return Some(prev);
+            }
+            prev = next;
+        }
+        None
+    }
+}
+
+impl<T> Default for CircularLinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlotNode for Option<Node<T>> {
+    type Item = T;
+
+    fn data(&mut self) -> &mut T {
+        &mut self.as_mut().expect("linked slot must be occupied").data
+    }
+
+    fn next(&self) -> Option<usize> {
+        self.as_ref().map(|node| node.next)
+    }
+}
+
+impl<T> ListRead<T> for CircularLinkedList<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the data at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        let prev = self.slot_before(index)?;
+        Some(&self.node(self.node(prev).next).data)
+    }
+}
+
+impl<T> ListWrite<T> for CircularLinkedList<T> {
+    /// Inserts an element at the end (tail) of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        if index == self.len {
+            self.push_back(data);
+        } else if let Some(prev) = self.slot_before(index) {
+            self.link_after(prev, data);
+        }
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.slot_before_match(data) {
+            Some(prev) => {
+                self.unlink_after(prev);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let prev = self.slot_before(index).unwrap();
+        self.unlink_after(prev);
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let prev = self.slot_before(index).unwrap();
+        let index = self.node(prev).next;
+        Some(&mut self.node_mut(index).data)
+    }
+
+    /// Updates the data of the node at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the node to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let prev = self.slot_before(index).unwrap();
+        let index = self.node(prev).next;
+        self.node_mut(index).data = data;
+        Ok(())
+    }
+}
+
+impl<T> LinkedListTrait<T> for CircularLinkedList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        CircularLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        CircularLinkedList::iter_mut(self)
+    }
+}
+
+/// An iterator that goes once around a `CircularLinkedList`, starting at the head.
+pub struct Iter<'a, T> {
+    /// The list being iterated.
+    list: &'a CircularLinkedList<T>,
+    /// The next slot to yield.
+    current: Option<usize>,
+    /// The number of elements not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.list.node(self.current?);
+        self.current = Some(node.next);
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An endless iterator that keeps following the circular links of a `CircularLinkedList`.
+pub struct Cycle<'a, T> {
+    /// The list being iterated.
+    list: &'a CircularLinkedList<T>,
+    /// The next slot to yield, or `None` if the list is empty.
+    current: Option<usize>,
+}
+
+impl<'a, T> Iterator for Cycle<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.list.node(self.current?);
+        self.current = Some(node.next);
+        Some(&node.data)
+    }
+}
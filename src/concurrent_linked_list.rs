@@ -0,0 +1,432 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{LinkedListError, ListRead, ListWrite};
+
+/// `ConcurrentLinkedList` is a thread-safe list that guards a whole backing list with one
+/// `RwLock`.
+///
+/// Every operation takes `&self`, so the list can be shared through an `Arc` and used from
+/// several threads at once. Lookups take the read lock and run in parallel; changes take
+/// the write lock and run one at a time. It is the simple, obviously correct baseline to
+/// compare the lock-free and fine-grained lists against.
+///
+/// Elements are stored as `Arc<T>`, and [`get`](Self::get) returns a clone of the `Arc`,
+/// so an element read by one thread stays alive while another removes it, and is freed
+/// when the last clone is dropped.
+///
+/// The list implements `ListRead` and `ListWrite` itself, so it can be passed to code
+/// generic over them; each trait method takes the lock for its own duration. The trait's
+/// `get` returns a plain `&T`, which must stay valid after the lock is released, so it
+/// keeps a clone of the element's `Arc` inside the list until the next call through
+/// `&mut self`. Shared code doing many lookups should use the inherent `get` instead.
+/// [`write`](Self::write) returns a [`WriteGuard`] that implements the same traits while
+/// holding the lock throughout, for running several operations atomically.
+///
+/// `LinkedListTrait` is not implemented: its iterators borrow the elements for as long as
+/// they live, which would mean holding the lock across them, and `iter_mut` could not
+/// reach an element whose `Arc` has been handed out by `get`. Iterate under
+/// [`read`](Self::read) or over [`to_vec`](Self::to_vec) instead.
+pub struct ConcurrentLinkedList<T, L = DynamicLinkedList<Arc<T>>> {
+    /// The backing list, holding shared pointers to the elements.
+    list: RwLock<L>,
+    /// The elements handed out by `ListRead::get`, kept alive until `&mut self` shows the
+    /// references to them are gone.
+    pinned: Mutex<Vec<Arc<T>>>,
+    /// Marks the element type, which `L` only mentions through its trait impls.
+    _marker: PhantomData<T>,
+}
+
+impl<T> ConcurrentLinkedList<T> {
+    /// Creates a new, empty `ConcurrentLinkedList` backed by a `DynamicLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `ConcurrentLinkedList` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Default> ConcurrentLinkedList<T, L> {
+    /// Creates a new, empty `ConcurrentLinkedList` using any backing list of `Arc<T>`,
+    /// e.g. `ConcurrentLinkedList::<i32, StaticLinkedList<Arc<i32>, 8>>::empty()`.
+    ///
+    /// # Returns
+    /// - A new empty `ConcurrentLinkedList` instance.
+    pub fn empty() -> Self {
+        ConcurrentLinkedList {
+            list: RwLock::new(L::default()),
+            pinned: Mutex::new(Vec::new()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L> ConcurrentLinkedList<T, L> {
+    /// Locks the backing list for reading, for running several lookups atomically.
+    ///
+    /// A panic in another thread while it held the lock does not poison the list: every
+    /// operation leaves the backing list consistent before it can panic.
+    pub fn read(&self) -> RwLockReadGuard<'_, L> {
+        self.list.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the list for writing, for running several changes atomically. The guard
+    /// implements `ListRead` and `ListWrite` as well, without locking for each call.
+    pub fn write(&self) -> WriteGuard<'_, T, L> {
+        WriteGuard {
+            list: self.lock(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Locks the backing list for writing.
+    fn lock(&self) -> RwLockWriteGuard<'_, L> {
+        self.list.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the backing list through exclusive access, which needs no lock, and drops
+    /// the elements pinned by `ListRead::get`, since no reference to them can remain.
+    fn list_mut(&mut self) -> &mut L {
+        self.pinned
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        self.list.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consumes the list and returns the backing list.
+    pub fn into_inner(self) -> L {
+        self.list.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T, L> ConcurrentLinkedList<T, L>
+where
+    T: PartialEq,
+    L: ListWrite<Arc<T>>,
+    for<'a> &'a L: IntoIterator<Item = &'a Arc<T>>,
+{
+    /// Inserts an element at the end (tail) of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn insert(&self, data: T) {
+        self.lock().insert(Arc::new(data));
+    }
+
+    /// Inserts an element at the end, reporting whether the backing list accepted it.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` if the backing list rejected the element (e.g. it is full).
+    pub fn try_insert(&self, data: T) -> Result<(), LinkedListError> {
+        self.lock().try_insert(Arc::new(data))
+    }
+
+    /// Inserts an element at a specific index.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` if the backing list rejected the element.
+    pub fn insert_at_index(&self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.lock().insert_at_index(index, Arc::new(data))
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    pub fn delete_element<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut list = self.lock();
+        let Some(index) = Self::position_in(&list, data) else {
+            return false;
+        };
+        list.delete_at_index(index).is_ok()
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn delete_at_index(&self, index: usize) -> Result<(), LinkedListError> {
+        self.lock().delete_at_index(index)
+    }
+
+    /// Updates the first element that matches `old_data` with `new_data`.
+    ///
+    /// # Parameters
+    /// - `old_data`: A reference to the value to replace.
+    /// - `new_data`: The new value.
+    ///
+    /// # Returns
+    /// - `true` if an update occurred.
+    /// - `false` if the old value was not found.
+    pub fn update_element<Q>(&self, old_data: &Q, new_data: T) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut list = self.lock();
+        let Some(index) = Self::position_in(&list, old_data) else {
+            return false;
+        };
+        list.update_element_at_index(index, Arc::new(new_data))
+            .is_ok()
+    }
+
+    /// Updates the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn update_element_at_index(&self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.lock().update_element_at_index(index, Arc::new(data))
+    }
+
+    /// Checks whether a given value exists in the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to search for.
+    ///
+    /// # Returns
+    /// - `true` if the value exists in the list.
+    /// - `false` otherwise.
+    pub fn find<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        Self::position_in(&self.read(), data).is_some()
+    }
+
+    /// Returns a shared pointer to the element at the specified index.
+    ///
+    /// The element stays alive while the pointer does, even if another thread removes it
+    /// from the list meanwhile.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(Arc<T>)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn get(&self, index: usize) -> Option<Arc<T>> {
+        self.read().get(index).cloned()
+    }
+
+    /// Returns a copy of every element, front to back, taken under one read lock.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.read().into_iter().map(|element| T::clone(element)).collect()
+    }
+
+    /// Returns the index of the first element equal to `data`.
+    fn position_in<Q>(list: &L, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        list.into_iter()
+            .position(|element| (**element).borrow() == data)
+    }
+}
+
+impl<T> Default for ConcurrentLinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, L> ListRead<T> for ConcurrentLinkedList<T, L>
+where
+    T: PartialEq,
+    L: ListWrite<Arc<T>>,
+    for<'a> &'a L: IntoIterator<Item = &'a Arc<T>>,
+{
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.read()
+            .into_iter()
+            .position(|element| predicate(element))
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// The element is kept alive by the list until its next call through `&mut self`,
+    /// even if another thread removes it meanwhile.
+    fn get(&self, index: usize) -> Option<&T> {
+        let element = Arc::clone(self.read().get(index)?);
+        let data = Arc::as_ptr(&element);
+        self.pinned
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(element);
+        // SAFETY: the pinned clone keeps the element alive until `pinned` is cleared, which
+        // takes `&mut self` and so cannot happen while the returned borrow of `self` lives.
+        Some(unsafe { &*data })
+    }
+}
+
+impl<T, L> ListWrite<T> for ConcurrentLinkedList<T, L>
+where
+    T: PartialEq,
+    L: ListWrite<Arc<T>>,
+    for<'a> &'a L: IntoIterator<Item = &'a Arc<T>>,
+{
+    /// Inserts an element at the end (tail) of the list.
+    fn insert(&mut self, data: T) {
+        self.list_mut().insert(Arc::new(data));
+    }
+
+    /// Inserts an element at the end, reporting whether the backing list accepted it.
+    fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.list_mut().try_insert(Arc::new(data))
+    }
+
+    /// Inserts an element at a specific index.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list_mut().insert_at_index(index, Arc::new(data))
+    }
+
+    /// Deletes the element at the specified index.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        self.list_mut().delete_at_index(index)
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid and no `Arc` from
+    ///   [`get`](ConcurrentLinkedList::get) still points to the element.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        Arc::get_mut(self.list_mut().get_mut(index)?)
+    }
+
+    /// Updates the element at the specified index.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list_mut()
+            .update_element_at_index(index, Arc::new(data))
+    }
+}
+
+/// Exclusive access to a [`ConcurrentLinkedList`], made with
+/// [`write`](ConcurrentLinkedList::write). The list stays locked until the guard is
+/// dropped, so the guard can hand out plain references and implements `ListRead` and
+/// `ListWrite`.
+pub struct WriteGuard<'a, T, L> {
+    /// The locked backing list.
+    list: RwLockWriteGuard<'a, L>,
+    /// Marks the element type, which `L` only mentions through its trait impls.
+    _marker: PhantomData<T>,
+}
+
+impl<T, L> ListRead<T> for WriteGuard<'_, T, L>
+where
+    T: PartialEq,
+    L: ListWrite<Arc<T>>,
+    for<'a> &'a L: IntoIterator<Item = &'a Arc<T>>,
+{
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        (&*self.list)
+            .into_iter()
+            .position(|element| predicate(element))
+    }
+
+    /// Returns a reference to the element at the specified index.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index).map(|element| &**element)
+    }
+}
+
+impl<T, L> ListWrite<T> for WriteGuard<'_, T, L>
+where
+    T: PartialEq,
+    L: ListWrite<Arc<T>>,
+    for<'a> &'a L: IntoIterator<Item = &'a Arc<T>>,
+{
+    /// Inserts an element at the end (tail) of the list.
+    fn insert(&mut self, data: T) {
+        self.list.insert(Arc::new(data));
+    }
+
+    /// Inserts an element at the end, reporting whether the backing list accepted it.
+    fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.list.try_insert(Arc::new(data))
+    }
+
+    /// Inserts an element at a specific index.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list.insert_at_index(index, Arc::new(data))
+    }
+
+    /// Deletes the element at the specified index.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        self.list.delete_at_index(index)
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid and no `Arc` from
+    ///   [`get`](ConcurrentLinkedList::get) still points to the element.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        Arc::get_mut(self.list.get_mut(index)?)
+    }
+
+    /// Updates the element at the specified index.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list.update_element_at_index(index, Arc::new(data))
+    }
+}
+
+impl<T: Debug, L> Debug for ConcurrentLinkedList<T, L>
+where
+    for<'a> &'a L: IntoIterator<Item = &'a Arc<T>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&*self.read()).finish()
+    }
+}
@@ -0,0 +1,190 @@
+//! A battery of behavioral checks that any [`LinkedListTrait`] implementation should pass.
+//!
+//! Each function builds fresh lists of `i32` with `new` and panics on the first check
+//! that fails, so it can be called from a `#[test]`. The
+//! [`linked_list_trait_tests!`](crate::linked_list_trait_tests) macro writes one test per
+//! function for a list type, which is the easy way to cover a new implementation, in
+//! this crate or outside it:
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod my_list_conformance {
+//!     use my_crate::MyList;
+//!
+//!     linked_list_impls::linked_list_trait_tests!(MyList<i32>);
+//! }
+//! ```
+//!
+//! A list with a fixed capacity needs room for at least [`REQUIRED_CAPACITY`] elements.
+
+use crate::{LinkedListError, LinkedListTrait};
+
+/// The most elements any check holds in a list at once.
+pub const REQUIRED_CAPACITY: usize = 6;
+
+/// Asserts that the elements of `list`, front to back, are `expected`, through `len`,
+/// `get` and `iter`.
+fn assert_elements<L: LinkedListTrait<i32>>(list: &L, expected: &[i32]) {
+    assert_eq!(list.len(), expected.len(), "len");
+    assert_eq!(list.is_empty(), expected.is_empty(), "is_empty");
+    for (index, value) in expected.iter().enumerate() {
+        assert_eq!(list.get(index), Some(value), "get({index})");
+    }
+    assert_eq!(list.get(expected.len()), None, "get past the end");
+    assert!(list.iter().eq(expected), "iter");
+}
+
+/// Asserts that `result` is an `IndexOutOfBounds` error for `index` in a list of `len`.
+fn assert_out_of_bounds(result: Result<(), LinkedListError>, index: usize, len: usize) {
+    assert_eq!(
+        result,
+        Err(LinkedListError::IndexOutOfBounds { index, len }),
+        "index {index} in a list of length {len}"
+    );
+}
+
+/// Builds a list holding `values`, front to back.
+fn filled<L: LinkedListTrait<i32>>(new: &impl Fn() -> L, values: &[i32]) -> L {
+    let mut list = new();
+    for &value in values {
+        list.insert(value);
+    }
+    list
+}
+
+/// Checks that a new list is empty and that every index is out of bounds.
+pub fn empty_list<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = new();
+    assert_elements(&list, &[]);
+    assert_eq!(list.get_mut(0), None, "get_mut on an empty list");
+    assert_eq!(list.position(&1), None, "position on an empty list");
+    assert_out_of_bounds(list.delete_at_index(0), 0, 0);
+    assert_out_of_bounds(list.update_element_at_index(0, 1), 0, 0);
+    assert!(!list.delete_element(&1), "delete_element on an empty list");
+}
+
+/// Checks that `insert` appends and that the elements read back in order.
+pub fn insert_and_get<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = new();
+    for value in 1..=3 {
+        list.insert(value);
+        assert_eq!(list.len(), value as usize, "len after insert");
+    }
+    assert_elements(&list, &[1, 2, 3]);
+    assert_eq!(list.try_insert(4), Ok(()), "try_insert");
+    assert_elements(&list, &[1, 2, 3, 4]);
+}
+
+/// Checks `insert_at_index` at the front, in the middle and at the end, and that an
+/// index past the end is rejected without changing the list.
+pub fn insert_at_index<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = new();
+    assert_eq!(
+        list.insert_at_index(0, 3),
+        Ok(()),
+        "insert into an empty list"
+    );
+    assert_eq!(list.insert_at_index(0, 1), Ok(()), "insert at the front");
+    assert_eq!(list.insert_at_index(1, 2), Ok(()), "insert in the middle");
+    assert_eq!(list.insert_at_index(3, 4), Ok(()), "insert at the end");
+    assert_elements(&list, &[1, 2, 3, 4]);
+    assert_out_of_bounds(list.insert_at_index(5, 9), 5, 4);
+    assert_elements(&list, &[1, 2, 3, 4]);
+    list.insert(5);
+    assert_elements(&list, &[1, 2, 3, 4, 5]);
+}
+
+/// Checks `delete_at_index` at the front, in the middle and at the end, and that the list
+/// still appends correctly after its last element was removed.
+pub fn delete_at_index<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = filled(&new, &[1, 2, 3, 4, 5]);
+    assert_eq!(list.delete_at_index(0), Ok(()), "delete the front");
+    assert_eq!(list.delete_at_index(1), Ok(()), "delete in the middle");
+    assert_eq!(list.delete_at_index(2), Ok(()), "delete the end");
+    assert_elements(&list, &[2, 4]);
+    assert_out_of_bounds(list.delete_at_index(2), 2, 2);
+    list.insert(6);
+    assert_elements(&list, &[2, 4, 6]);
+}
+
+/// Checks that `delete_element` removes only the first equal element.
+pub fn delete_element<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = filled(&new, &[1, 2, 1, 3]);
+    assert!(list.delete_element(&1), "delete a present element");
+    assert_elements(&list, &[2, 1, 3]);
+    assert!(!list.delete_element(&9), "delete a missing element");
+    assert!(list.delete_element(&3), "delete the last element");
+    assert_elements(&list, &[2, 1]);
+}
+
+/// Checks `update_element_at_index`, `update_element` and `get_mut`.
+pub fn update<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = filled(&new, &[1, 2, 1]);
+    assert_eq!(
+        list.update_element_at_index(1, 5),
+        Ok(()),
+        "update by index"
+    );
+    assert!(list.update_element(&1, 7), "update by value");
+    assert!(!list.update_element(&9, 0), "update a missing value");
+    assert_elements(&list, &[7, 5, 1]);
+    *list.get_mut(2).expect("get_mut in bounds") = 8;
+    assert_eq!(list.get_mut(3), None, "get_mut past the end");
+    assert_out_of_bounds(list.update_element_at_index(3, 0), 3, 3);
+    assert_elements(&list, &[7, 5, 8]);
+}
+
+/// Checks `position` and `find`, which report the first equal element.
+pub fn position_and_find<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let list = filled(&new, &[4, 5, 4]);
+    assert_eq!(list.position(&4), Some(0), "position of a repeated element");
+    assert_eq!(list.position(&5), Some(1), "position");
+    assert_eq!(list.position(&6), None, "position of a missing element");
+    assert!(list.find(&5), "find");
+    assert!(!list.find(&6), "find a missing element");
+}
+
+/// Checks that `iter_mut` visits every element once, front to back.
+pub fn iter_mut<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = filled(&new, &[1, 2, 3]);
+    for (offset, value) in list.iter_mut().enumerate() {
+        *value = *value * 10 + offset as i32;
+    }
+    assert_elements(&list, &[10, 21, 32]);
+}
+
+/// Checks that a list emptied one element at a time can be filled again.
+pub fn drain_and_refill<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    let mut list = filled(&new, &[1, 2, 3, 4, 5, 6]);
+    while !list.is_empty() {
+        let last = list.len() - 1;
+        assert_eq!(
+            list.delete_at_index(last),
+            Ok(()),
+            "delete the last element"
+        );
+    }
+    assert_elements(&list, &[]);
+    for value in [7, 8] {
+        list.insert(value);
+    }
+    assert_eq!(
+        list.insert_at_index(0, 6),
+        Ok(()),
+        "insert at the front after draining"
+    );
+    assert_elements(&list, &[6, 7, 8]);
+}
+
+/// Runs every check in this module.
+pub fn run_all<L: LinkedListTrait<i32>>(new: impl Fn() -> L) {
+    empty_list(&new);
+    insert_and_get(&new);
+    insert_at_index(&new);
+    delete_at_index(&new);
+    delete_element(&new);
+    update(&new);
+    position_and_find(&new);
+    iter_mut(&new);
+    drain_and_refill(&new);
+}
@@ -0,0 +1,189 @@
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// `Counted` decorates any `ListRead` implementation with an O(1) `len()`.
+///
+/// Every mutating call is forwarded to the wrapped list and the counter is adjusted
+/// according to the result, so an implementation whose `len` walks every node (such as
+/// `DynamicLinkedList`) gets an O(1) `len()` and `is_empty()` without changes of its own.
+///
+/// `insert` is forwarded as an insertion at index `len`, so a bounded list that rejects
+/// the element is never counted.
+#[derive(Debug, Clone, Default)]
+pub struct Counted<L> {
+    /// The wrapped list.
+    list: L,
+    /// The number of elements in `list`.
+    len: usize,
+}
+
+impl<L> Counted<L> {
+    /// Wraps `list`, counting the elements it already holds.
+    ///
+    /// The count comes from the list's own `len`, which walks lists that do not store
+    /// their length; wrap an empty list to avoid the cost.
+    ///
+    /// # Parameters
+    /// - `list`: The list to wrap.
+    pub fn new<T>(list: L) -> Self
+    where
+        L: ListRead<T>,
+    {
+        let len = list.len();
+        Counted { list, len }
+    }
+
+    /// Returns the number of elements in the list in O(1).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the wrapped list.
+    pub fn inner(&self) -> &L {
+        &self.list
+    }
+
+    /// Consumes the wrapper and returns the wrapped list.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<T, L: ListRead<T>> ListRead<T> for Counted<L> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.list.position_by(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+}
+
+impl<T, L: ListWrite<T>> ListWrite<T> for Counted<L> {
+    /// Inserts an element at the end (tail) of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        if self.list.insert_at_index(self.len, data).is_ok() {
+            self.len += 1;
+        }
+    }
+
+    /// Inserts an element at the end (tail) of the list, counting it only if the wrapped
+    /// list accepted it.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.list.try_insert(data)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list.insert_at_index(index, data)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        self.list.delete_at_index(index)?;
+        self.len -= 1;
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    /// Updates the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list.update_element_at_index(index, data)
+    }
+}
+
+impl<T, L: LinkedListTrait<T>> LinkedListTrait<T> for Counted<L> {
+    type Iter<'a>
+        = L::Iter<'a>
+    where
+        Self: 'a,
+        T: 'a;
+    type IterMut<'a>
+        = L::IterMut<'a>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns an iterator over the elements of the wrapped list, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator that allows modifying each element of the wrapped list.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.list.iter_mut()
+    }
+}
@@ -0,0 +1,72 @@
+//! `defmt::Format` implementations, enabled by the `defmt` feature.
+//!
+//! Embedded targets that log over RTT with `defmt` cannot afford `core::fmt`, so the
+//! lists below implement `Format` as well as `Debug`. A list is written like a slice,
+//! `[1, 2, 3]`, front to back; the error types derive `Format` where they are declared.
+
+use defmt::{Format, Formatter};
+
+use crate::counted::Counted;
+use crate::heapless_compat;
+use crate::static_linked_list::StaticLinkedList;
+#[cfg(feature = "alloc")]
+use crate::{
+    allocator::Alloc, circular_doubly_linked_list::CircularDoublyLinkedList,
+    circular_linked_list::CircularLinkedList, doubly_linked_list::DoublyLinkedList,
+    dynamic_linked_list::DynamicLinkedList, skip_list::IndexedSkipList,
+    unrolled_linked_list::UnrolledLinkedList, xor_linked_list::XorLinkedList,
+};
+
+/// Writes `items` as a comma-separated list in brackets.
+fn format_list<'a, T: Format + 'a>(f: Formatter<'_>, items: impl Iterator<Item = &'a T>) {
+    defmt::write!(f, "[");
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            defmt::write!(f, ", ");
+        }
+        defmt::write!(f, "{}", item);
+    }
+    defmt::write!(f, "]");
+}
+
+/// Implements `Format` for a list with an `iter()` method yielding `&T`. The brackets
+/// hold any generic parameters besides `T`.
+macro_rules! impl_format {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $list:ty;)*) => {
+        $(
+            $(#[$attr])*
+            impl<T: Format, $($generics)*> Format for $list {
+                fn format(&self, f: Formatter<'_>) {
+                    format_list(f, self.iter());
+                }
+            }
+        )*
+    };
+}
+
+impl_format! {
+    [const N: usize] StaticLinkedList<T, N>;
+    [const N: usize] heapless_compat::Vec<T, N>;
+    [const N: usize] heapless_compat::Deque<T, N>;
+    #[cfg(feature = "alloc")]
+    [] CircularDoublyLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [] CircularLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [] DoublyLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [A: Alloc] DynamicLinkedList<T, A>;
+    #[cfg(feature = "alloc")]
+    [] IndexedSkipList<T>;
+    #[cfg(feature = "alloc")]
+    [const B: usize] UnrolledLinkedList<T, B>;
+    #[cfg(feature = "alloc")]
+    [] XorLinkedList<T>;
+}
+
+impl<L: Format> Format for Counted<L> {
+    /// Writes the wrapped list; the count is not shown.
+    fn format(&self, f: Formatter<'_>) {
+        self.inner().format(f);
+    }
+}
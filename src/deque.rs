@@ -0,0 +1,251 @@
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+use crate::circular_doubly_linked_list::{self, CircularDoublyLinkedList};
+use crate::doubly_linked_list::{self, DoublyLinkedList};
+use crate::xor_linked_list::{self, XorLinkedList};
+
+/// `DequeBackend` is implemented by the lists that support O(1) operations at both ends.
+///
+/// It lets [`Deque`] switch between [`DoublyLinkedList`], [`CircularDoublyLinkedList`]
+/// and [`XorLinkedList`] without changing any calling code.
+pub trait DequeBackend<T>: Default {
+    /// The double-ended iterator over the backend's elements.
+    type Iter<'a>: DoubleEndedIterator<Item = &'a T> + ExactSizeIterator
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns the number of elements.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the first element.
+    fn front(&self) -> Option<&T>;
+
+    /// Returns a reference to the last element.
+    fn back(&self) -> Option<&T>;
+
+    /// Inserts an element at the front.
+    fn push_front(&mut self, data: T);
+
+    /// Inserts an element at the back.
+    fn push_back(&mut self, data: T);
+
+    /// Removes and returns the first element.
+    fn pop_front(&mut self) -> Option<T>;
+
+    /// Removes and returns the last element.
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+/// Implements `DequeBackend` by forwarding to the list's inherent methods of the same name.
+macro_rules! forward_deque_backend {
+    ($list:ident, $module:ident) => {
+        impl<T> DequeBackend<T> for $list<T> {
+            type Iter<'a>
+                = $module::Iter<'a, T>
+            where
+                T: 'a;
+
+            fn len(&self) -> usize {
+                $list::len(self)
+            }
+
+            fn front(&self) -> Option<&T> {
+                $list::front(self)
+            }
+
+            fn back(&self) -> Option<&T> {
+                $list::back(self)
+            }
+
+            fn push_front(&mut self, data: T) {
+                $list::push_front(self, data)
+            }
+
+            fn push_back(&mut self, data: T) {
+                $list::push_back(self, data)
+            }
+
+            fn pop_front(&mut self) -> Option<T> {
+                $list::pop_front(self)
+            }
+
+            fn pop_back(&mut self) -> Option<T> {
+                $list::pop_back(self)
+            }
+
+            fn iter(&self) -> $module::Iter<'_, T> {
+                $list::iter(self)
+            }
+        }
+    };
+}
+
+forward_deque_backend!(DoublyLinkedList, doubly_linked_list);
+forward_deque_backend!(CircularDoublyLinkedList, circular_doubly_linked_list);
+forward_deque_backend!(XorLinkedList, xor_linked_list);
+
+/// `Deque` is a double-ended queue with O(1) pushes and pops at both ends.
+///
+/// Its API mirrors `std::collections::VecDeque`, so it can stand in for one. The
+/// elements live in a [`DequeBackend`], which defaults to `DoublyLinkedList`; pick
+/// another, e.g. `Deque<T, XorLinkedList<T>>`, to benchmark the implementations
+/// against each other.
+pub struct Deque<T, B = DoublyLinkedList<T>> {
+    /// The backing list.
+    list: B,
+    /// Marks the element type, which `B` only mentions through its trait impls.
+    _marker: PhantomData<T>,
+}
+
+impl<T> Deque<T> {
+    /// Creates a new, empty `Deque` backed by a `DoublyLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `Deque` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, B: DequeBackend<T>> Deque<T, B> {
+    /// Creates a new, empty `Deque` using any backend, e.g.
+    /// `Deque::<i32, XorLinkedList<i32>>::empty()`.
+    ///
+    /// # Returns
+    /// - A new empty `Deque` instance.
+    pub fn empty() -> Self {
+        Deque {
+            list: B::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    /// Prepends an element to the deque in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        self.list.push_front(data);
+    }
+
+    /// Appends an element to the back of the deque in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        self.list.push_back(data);
+    }
+
+    /// Removes and returns the front element, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Removes and returns the back element, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve, counted from the front.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.list.iter().nth(index)
+    }
+
+    /// Returns `true` if the deque contains an element equal to `data`.
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.list.iter().any(|item| item == data)
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.list = B::default();
+    }
+
+    /// Returns a double-ended iterator over the elements, front to back.
+    pub fn iter(&self) -> B::Iter<'_> {
+        self.list.iter()
+    }
+
+    /// Consumes the deque and returns the backing list.
+    pub fn into_inner(self) -> B {
+        self.list
+    }
+}
+
+impl<T, B: DequeBackend<T>> Default for Deque<T, B> {
+    /// Provides a default (empty) instance of the deque using `empty()`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, B: DequeBackend<T>> Extend<T> for Deque<T, B> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+impl<T, B: DequeBackend<T>> FromIterator<T> for Deque<T, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::empty();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<'a, T, B: DequeBackend<T>> IntoIterator for &'a Deque<T, B> {
+    type Item = &'a T;
+    type IntoIter = B::Iter<'a>;
+
+    fn into_iter(self) -> B::Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<T: Debug, B: DequeBackend<T>> Debug for Deque<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
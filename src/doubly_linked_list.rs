@@ -0,0 +1,589 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::mem;
+
+use crate::footprint::{self, MemoryFootprint};
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+use crate::slots::SlotNode;
+
+pub use crate::slots::IterMut;
+
+/// `Node` represents a single element in the doubly linked list.
+///
+/// Links are stored as indices into the list's node storage rather than pointers,
+/// so the whole structure is built from safe code.
+#[derive(Debug)]
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The index of the previous node, if any.
+    prev: Option<usize>,
+    /// The index of the next node, if any.
+    next: Option<usize>,
+}
+
+/// `DoublyLinkedList` is a linked list where every node links to both of its neighbours.
+///
+/// Nodes live in a growable slot vector and refer to each other by index; slots freed
+/// by deletions are reused by later insertions. Keeping both `head` and `tail` makes
+/// operations at either end O(1), and positional operations walk from whichever end
+/// is closer.
+#[derive(Debug)]
+pub struct DoublyLinkedList<T> {
+    /// Slot storage for the nodes. `None` marks a free slot.
+    nodes: Vec<Option<Node<T>>>,
+    /// Indices of free slots in `nodes`.
+    free: Vec<usize>,
+    /// The index of the first node in the list.
+    head: Option<usize>,
+    /// The index of the last node in the list.
+    tail: Option<usize>,
+    /// The number of elements in the list.
+    len: usize,
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Creates a new, empty `DoublyLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `DoublyLinkedList` instance.
+    pub fn new() -> Self {
+        DoublyLinkedList {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns how much memory the list takes up: the slot vector, including free and
+    /// spare slots, and the free-slot indices.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        MemoryFootprint {
+            nodes: self.len,
+            node_overhead: footprint::node_overhead::<Option<Node<T>>, T>(),
+            total_bytes: mem::size_of::<Self>()
+                + self.nodes.capacity() * mem::size_of::<Option<Node<T>>>()
+                + self.free.capacity() * mem::size_of::<usize>(),
+        }
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|i| &self.node(i).data)
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|i| &self.node(i).data)
+    }
+
+    /// Inserts an element at the front of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        let index = self.allocate_node(data, None, self.head);
+        match self.head {
+            Some(head) => self.node_mut(head).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+
+    /// Inserts an element at the back of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        let index = self.allocate_node(data, self.tail, None);
+        match self.tail {
+            Some(tail) => self.node_mut(tail).next = Some(index),
+            None => self.head = Some(index),
+        }
+        self.tail = Some(index);
+    }
+
+    /// Removes and returns the first element in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|i| self.unlink(i))
+    }
+
+    /// Removes and returns the last element in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|i| self.unlink(i))
+    }
+
+    /// Returns a double-ended iterator over the elements, front to back.
+    ///
+    /// Calling `.rev()` on the iterator traverses the list back to front.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&mut self.nodes, self.head, self.tail, self.len)
+    }
+
+    /// Returns a mutable cursor positioned at the first element, for O(1) insertions and
+    /// removals at the cursor.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            list: self,
+            index: 0,
+        }
+    }
+
+    /// Returns a shared reference to the node stored in an occupied slot.
+    fn node(&self, index: usize) -> &Node<T> {
+        self.nodes[index].as_ref().expect("linked slot must be occupied")
+    }
+
+    /// Returns a mutable reference to the node stored in an occupied slot.
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        self.nodes[index].as_mut().expect("linked slot must be occupied")
+    }
+
+    /// Stores a new node in a free slot (growing the storage if needed).
+    ///
+    /// # Returns
+    /// - The index of the slot holding the new node.
+    fn allocate_node(&mut self, data: T, prev: Option<usize>, next: Option<usize>) -> usize {
+        let node = Some(Node { data, prev, next });
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Stores a new node between `prev` and `next`, which must be adjacent, and links
+    /// them to it.
+    ///
+    /// # Returns
+    /// - The index of the slot holding the new node.
+    fn link_between(&mut self, data: T, prev: Option<usize>, next: Option<usize>) -> usize {
+        let index = self.allocate_node(data, prev, next);
+        match prev {
+            Some(prev) => self.node_mut(prev).next = Some(index),
+            None => self.head = Some(index),
+        }
+        match next {
+            Some(next) => self.node_mut(next).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        index
+    }
+
+    /// Unlinks the node at slot `index` from its neighbours and frees the slot.
+    ///
+    /// # Returns
+    /// - The data stored in the removed node.
+    fn unlink(&mut self, index: usize) -> T {
+        let node = self.nodes[index].take().expect("linked slot must be occupied");
+        match node.prev {
+            Some(prev) => self.node_mut(prev).next = node.next,
+            None => self.head = node.next,
+        }
+        match node.next {
+            Some(next) => self.node_mut(next).prev = node.prev,
+            None => self.tail = node.prev,
+        }
+        self.free.push(index);
+        self.len -= 1;
+        node.data
+    }
+
+    /// Finds the slot of the element at logical position `index`, walking from
+    /// whichever end of the list is closer.
+    fn slot_at(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+
+        if index <= self.len / 2 {
+            let mut current = self.head;
+            for _ in 0..index {
+                current = self.node(current?).next;
+            }
+            current
+        } else {
+            let mut current = self.tail;
+            for _ in 0..(self.len - 1 - index) {
+                current = self.node(current?).prev;
+            }
+            current
+        }
+    }
+
+    /// Finds the slot of the first element equal to `data`.
+    fn slot_of<Q>(&self, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut current = self.head;
+        while let Some(i) = current {
+            let node = self.node(i);
+            if node.data.borrow() == data {
+                return Some(i);
+            }
+            current = node.next;
+        }
+        None
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlotNode for Option<Node<T>> {
+    type Item = T;
+
+    fn data(&mut self) -> &mut T {
+        &mut self.as_mut().expect("linked slot must be occupied").data
+    }
+
+    fn next(&self) -> Option<usize> {
+        self.as_ref().and_then(|node| node.next)
+    }
+
+    fn prev(&self) -> Option<usize> {
+        self.as_ref().and_then(|node| node.prev)
+    }
+}
+
+impl<T> ListRead<T> for DoublyLinkedList<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the data at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.slot_at(index).map(|i| &self.node(i).data)
+    }
+}
+
+impl<T> ListWrite<T> for DoublyLinkedList<T> {
+    /// Inserts an element at the end (tail) of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        if index == self.len {
+            self.push_back(data);
+            return Ok(());
+        }
+
+        let next = self.slot_at(index).unwrap();
+        let prev = self.node(next).prev;
+        self.link_between(data, prev, Some(next));
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.slot_of(data) {
+            Some(i) => {
+                self.unlink(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        match self.slot_at(index) {
+            Some(i) => {
+                self.unlink(i);
+                Ok(())
+            }
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let slot = self.slot_at(index)?;
+        Some(&mut self.node_mut(slot).data)
+    }
+
+    /// Updates the data of the node at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the node to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        match self.slot_at(index) {
+            Some(i) => {
+                self.node_mut(i).data = data;
+                Ok(())
+            }
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+}
+
+impl<T> LinkedListTrait<T> for DoublyLinkedList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        DoublyLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        DoublyLinkedList::iter_mut(self)
+    }
+}
+
+/// A double-ended iterator over the elements of a `DoublyLinkedList`.
+pub struct Iter<'a, T> {
+    /// The list being iterated.
+    list: &'a DoublyLinkedList<T>,
+    /// The next slot to yield from the front.
+    front: Option<usize>,
+    /// The next slot to yield from the back.
+    back: Option<usize>,
+    /// The number of elements not yet yielded from either end.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.list.node(self.front?);
+        self.front = node.next;
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.list.node(self.back?);
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// A mutable cursor over a `DoublyLinkedList`, editing the list in place at its position.
+///
+/// Past the last element the cursor sits at the end, where insertions append to the list.
+pub struct CursorMut<'a, T> {
+    /// The list the cursor edits.
+    list: &'a mut DoublyLinkedList<T>,
+    /// The slot of the node at the cursor, or `None` past the end.
+    current: Option<usize>,
+    /// The position of the cursor.
+    index: usize,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Returns the position of the cursor. Past the last element this is the length of
+    /// the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the element at the cursor, or `None` past the end.
+    pub fn current(&mut self) -> Option<&mut T> {
+        let slot = self.current?;
+        Some(&mut self.list.node_mut(slot).data)
+    }
+
+    /// Moves the cursor to the next element. Does nothing once past the end.
+    pub fn move_next(&mut self) {
+        if let Some(slot) = self.current {
+            self.current = self.list.node(slot).next;
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous element; from past the end, onto the last one.
+    /// Does nothing at the front.
+    pub fn move_prev(&mut self) {
+        let prev = match self.current {
+            Some(slot) => self.list.node(slot).prev,
+            None => self.list.tail,
+        };
+        if prev.is_some() {
+            self.current = prev;
+            self.index -= 1;
+        }
+    }
+
+    /// Inserts an element before the cursor. The cursor stays on the same element, so its
+    /// index grows by one.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn insert_before(&mut self, data: T) {
+        let prev = match self.current {
+            Some(slot) => self.list.node(slot).prev,
+            None => self.list.tail,
+        };
+        self.list.link_between(data, prev, self.current);
+        self.index += 1;
+    }
+
+    /// Inserts an element after the cursor. Past the end, the element is appended like
+    /// with `insert_before`.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn insert_after(&mut self, data: T) {
+        match self.current {
+            Some(slot) => {
+                let next = self.list.node(slot).next;
+                self.list.link_between(data, Some(slot), next);
+            }
+            None => self.insert_before(data),
+        }
+    }
+
+    /// Removes the element at the cursor, moving the cursor onto the element after it.
+    ///
+    /// # Returns
+    /// - `Some(T)` with the removed element.
+    /// - `None` if the cursor is past the end.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let slot = self.current?;
+        self.current = self.list.node(slot).next;
+        Some(self.list.unlink(slot))
+    }
+}
@@ -1,26 +1,206 @@
-use std::fmt::Debug;
+use alloc::alloc::handle_alloc_error;
+use alloc::collections::{LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::borrow::Borrow;
+use core::cell::UnsafeCell;
+use core::fmt::{self, Debug, Display};
+use core::iter;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Bound, Deref, DerefMut, RangeBounds};
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
-use crate::LinkedListTrait;
+use crate::allocator::{Alloc, Global};
+use crate::footprint::{self, LocalityStats, MemoryFootprint};
+use crate::instrument;
+use crate::render;
+use crate::static_linked_list::StaticLinkedList;
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// Shorthand for an optional pointer to a heap-allocated node.
+type Link<T> = Option<NonNull<Node<T>>>;
+
+/// A cached `(index, node)` pair from the last lookup by position.
+type Finger<T> = Option<(usize, NonNull<Node<T>>)>;
+
+/// The source of the lists' handle ids, so no two lists ever share one. Zero is skipped.
+static HANDLE_IDS: AtomicUsize = AtomicUsize::new(1);
+
+/// `FingerLock` guards the cached finger, which lookups update through `&self`.
+///
+/// It only supports `try_lock`: a lookup that finds the finger in use by another thread
+/// walks from the head instead of waiting, so a spin lock without blocking is enough and
+/// works without `std`.
+struct FingerLock<T> {
+    /// Whether a lookup currently holds the finger.
+    locked: AtomicBool,
+    /// The cached finger.
+    finger: UnsafeCell<Finger<T>>,
+}
+
+impl<T> FingerLock<T> {
+    /// Creates an unlocked, empty finger.
+    fn new() -> Self {
+        FingerLock {
+            locked: AtomicBool::new(false),
+            finger: UnsafeCell::new(None),
+        }
+    }
+
+    /// Takes the finger, or returns `None` if another lookup holds it.
+    fn try_lock(&self) -> Option<FingerGuard<'_, T>> {
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()?;
+        Some(FingerGuard { lock: self })
+    }
+
+    /// Returns the finger through an exclusive borrow, without locking.
+    fn get_mut(&mut self) -> &mut Finger<T> {
+        self.finger.get_mut()
+    }
+}
+
+/// Access to the finger, released when dropped.
+struct FingerGuard<'a, T> {
+    /// The lock this guard holds.
+    lock: &'a FingerLock<T>,
+}
+
+impl<T> Deref for FingerGuard<'_, T> {
+    type Target = Finger<T>;
+
+    fn deref(&self) -> &Finger<T> {
+        // SAFETY: the guard holds the lock, so no other reference to the finger exists.
+        unsafe { &*self.lock.finger.get() }
+    }
+}
+
+impl<T> DerefMut for FingerGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Finger<T> {
+        // SAFETY: as in `deref`.
+        unsafe { &mut *self.lock.finger.get() }
+    }
+}
+
+impl<T> Drop for FingerGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
 
 /// `Node` represents a single element in the dynamic linked list.
 /// 
 /// Each node stores data of type `T` and a pointer to the next node.
-#[derive(Debug)]
 struct Node<T> {
     /// The data stored in the node.
     data: T,
     /// A pointer to the next node in the list.
-    next: Option<Box<Node<T>>>,
+    next: Link<T>,
+    /// The key of the handle given out for this node, or 0 if there is none.
+    key: u64,
 }
 
 /// `DynamicLinkedList` is a singly linked list that uses dynamic memory allocation.
 ///
 /// It supports common linked list operations such as insertion, deletion, update,
-/// retrieval, and search.
-#[derive(Debug)]
-pub struct DynamicLinkedList<T> {
+/// retrieval, and search. A tail pointer keeps appends O(1).
+///
+/// Nodes are allocated from `A`, the global allocator by default, and linked through raw
+/// pointers, so the list can point at its last node while the node before it owns it. Use
+/// [`new_in`](Self::new_in) to place the nodes in a pool or arena instead; with the
+/// `bumpalo` feature, `DynamicLinkedList::new_in(&bump)` allocates them in a `Bump`.
+///
+/// Removed nodes are kept in an internal pool and reused by later insertions, so churn
+/// does not go to the allocator for every operation. [`shrink_pool`](Self::shrink_pool)
+/// releases them.
+///
+/// Every node carries a `u64` key next to its data and link, so that a
+/// [`NodeHandle`] can tell whether its node still holds the element it was given out
+/// for. That is 8 bytes per node, plus any padding it brings, whether or not handles
+/// are used; [`memory_footprint`](Self::memory_footprint) reports it as part of the
+/// node overhead.
+pub struct DynamicLinkedList<T, A: Alloc = Global> {
     /// A pointer to the head (first element) of the linked list.
-    head: Option<Box<Node<T>>>,
+    head: Link<T>,
+    /// A pointer to the tail (last element) of the linked list.
+    tail: Link<T>,
+    /// The node found by the last lookup by position, so sequential lookups walk on from
+    /// it instead of from the head. It is cleared whenever the links change. The lock
+    /// keeps the list `Sync`; lookups walk from the head if it is busy.
+    finger: FingerLock<T>,
+    /// Freed node blocks kept for reuse, chained through their `next` fields. Their `data`
+    /// is uninitialized and their `key` is 0.
+    pool: Link<T>,
+    /// The number of blocks in `pool`.
+    pooled: usize,
+    /// The allocator the nodes come from.
+    alloc: A,
+    /// Tells this list's handles apart from those of other lists, or 0 before the first
+    /// handle is given out. It is reset whenever nodes may leave the list, which makes
+    /// every outstanding handle stale, so a handle never points at a freed node.
+    handle_id: usize,
+    /// The key for the next handle.
+    next_key: u64,
+    /// Tells the compiler this type owns nodes containing `T`.
+    _marker: PhantomData<Node<T>>,
+}
+
+// SAFETY: the list uniquely owns its nodes, like a `Box` chain; the raw pointers are never
+// shared with another list, so moving it moves ownership of every `T`.
+unsafe impl<T: Send, A: Alloc + Send> Send for DynamicLinkedList<T, A> {}
+// SAFETY: `&DynamicLinkedList` only gives out `&T` and never mutates nodes; the cached
+// finger is only touched under its lock.
+unsafe impl<T: Sync, A: Alloc + Sync> Sync for DynamicLinkedList<T, A> {}
+
+/// `NodeHandle` is a stable handle to an element of a `DynamicLinkedList`, returned by
+/// [`insert_with_handle`](DynamicLinkedList::insert_with_handle).
+///
+/// A handle stays valid across any number of unrelated insertions and removals. Once its
+/// element is removed the handle is stale for good, even if the node is reused. Operations
+/// that may free nodes or move them out of the list (`shrink_pool`, `splice`,
+/// `optimize_layout` and `CursorMut::split_after`) make every handle of the list stale.
+pub struct NodeHandle<T> {
+    /// The id of the list that gave out the handle.
+    list: usize,
+    /// The node holding the element. It is only read once `list` has been matched.
+    node: NonNull<Node<T>>,
+    /// The key written into the node when the handle was given out.
+    key: u64,
+}
+
+// SAFETY: a handle gives no access to the element on its own; it is only dereferenced by
+// the list that gave it out, through a borrow of that list.
+unsafe impl<T> Send for NodeHandle<T> {}
+// SAFETY: as above.
+unsafe impl<T> Sync for NodeHandle<T> {}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeHandle<T> {}
+
+impl<T> PartialEq for NodeHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.list, self.node, self.key) == (other.list, other.node, other.key)
+    }
+}
+
+impl<T> Eq for NodeHandle<T> {}
+
+impl<T> Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeHandle")
+            .field("list", &self.list)
+            .field("key", &self.key)
+            .finish()
+    }
 }
 
 impl<T> DynamicLinkedList<T> {
@@ -29,242 +209,1278 @@ impl<T> DynamicLinkedList<T> {
     /// # Returns
     /// - A new empty `DynamicLinkedList` instance.
     pub fn new() -> Self {
-        DynamicLinkedList { head: None }
+        DynamicLinkedList::new_in(Global)
     }
-}
 
-impl<T: PartialEq + Clone + Debug> LinkedListTrait<T> for DynamicLinkedList<T> {
-    /// Inserts an element at the end (tail) of the list.
+    /// Replaces the elements in `range` with the elements of `other`, returning the
+    /// replaced elements as a list.
+    ///
+    /// The nodes are relinked in place: no element is moved or cloned. Only lists on the
+    /// global allocator can be spliced, since the nodes of `other` must be freed by this
+    /// list.
     ///
     /// # Parameters
-    /// - `data`: The value to insert.
-    fn insert(&mut self, data: T) {
-        let new_node = Box::new(Node { data, next: None });
+    /// - `range`: The positions to replace, e.g. `1..3` or `2..`.
+    /// - `other`: The list to link in place of the range.
+    ///
+    /// # Returns
+    /// - `Ok(DynamicLinkedList<T>)` with the elements that were in `range`.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the range is invalid, leaving the list
+    ///   unchanged.
+    pub fn splice<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        mut other: DynamicLinkedList<T>,
+    ) -> Result<DynamicLinkedList<T>, LinkedListError> {
+        // A bound of `usize::MAX` has no position after it.
+        let overflow = || LinkedListError::IndexOutOfBounds {
+            index: usize::MAX,
+            len: self.len(),
+        };
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).ok_or_else(overflow)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(end.checked_add(1).ok_or_else(overflow)?),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        if end.is_some_and(|end| end < start) {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index: start,
+                len: self.len(),
+            });
+        }
 
-        match self.head.as_mut() {
+        let prev = match start {
+            0 => None,
+            _ => match self.node_at(start - 1) {
+                Some(node) => Some(node),
+                None => {
+                    return Err(LinkedListError::IndexOutOfBounds {
+                        index: start,
+                        len: self.len(),
+                    });
+                }
+            },
+        };
+
+        // Find the last removed node and the first node kept after the range.
+        let mut last = prev;
+        let mut rest = self.next_of(prev);
+        match end {
+            Some(end) => {
+                for _ in start..end {
+                    match rest {
+                        Some(node) => {
+                            last = Some(node);
+                            // SAFETY: every node reachable from `head` is live.
+                            rest = unsafe { (*node.as_ptr()).next };
+                        }
+                        None => {
+                            return Err(LinkedListError::IndexOutOfBounds {
+                                index: end,
+                                len: self.len(),
+                            });
+                        }
+                    }
+                }
+            }
             None => {
-                self.head = Some(new_node);
+                if rest.is_some() {
+                    last = self.tail;
+                }
+                rest = None;
+            }
+        }
+
+        let mut removed = DynamicLinkedList::new();
+        if last != prev {
+            removed.head = self.next_of(prev);
+            removed.tail = last;
+            self.forget_handles();
+            if let Some(last) = last {
+                // SAFETY: `last` is a live node of this list; it now ends `removed`.
+                unsafe { (*last.as_ptr()).next = None };
             }
-            Some(mut current) => {
-                while current.next.is_some() {
-                    let next = current.next.as_mut().unwrap();
-                    current = next;
+        }
+
+        // Link the nodes of `other`, which now belong to this list, in place of the range.
+        let other_head = other.head.take();
+        match other.tail.take() {
+            Some(other_tail) => {
+                // SAFETY: `other_tail` is the live last node of `other`.
+                unsafe { (*other_tail.as_ptr()).next = rest };
+                self.set_next(prev, other_head);
+                if rest.is_none() {
+                    self.tail = Some(other_tail);
+                }
+            }
+            None => {
+                self.set_next(prev, rest);
+                if rest.is_none() {
+                    self.tail = prev;
                 }
-                current.next = Some(new_node);
             }
         }
+        Ok(removed)
     }
 
-    /// Inserts an element at a specific index in the list.
+    /// Joins `lists` into one list, in order, by linking each list's nodes after the
+    /// last node of the lists before it. No element is moved or cloned, and each list
+    /// costs O(1) to join. Like `splice`, it takes lists on the global allocator, whose
+    /// nodes the joined list frees.
     ///
     /// # Parameters
-    /// - `index`: The position to insert at (0-based).
-    /// - `data`: The value to insert.
+    /// - `lists`: The lists to join, front to back.
     ///
     /// # Returns
-    /// - `Ok(())` on success.
-    /// - `Err("Index out of bounds")` if the index is invalid.
-    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), String> {
-        if index == 0 {
-            let new_node = Box::new(Node {
-                data,
-                next: self.head.take(),
-            });
-            self.head = Some(new_node);
-            return Ok(());
+    /// - A list holding the elements of every list in `lists`, in order.
+    pub fn concat<I>(lists: I) -> Self
+    where
+        I: IntoIterator<Item = DynamicLinkedList<T>>,
+    {
+        let mut joined = DynamicLinkedList::new();
+        for mut list in lists {
+            let Some(head) = list.head.take() else {
+                continue;
+            };
+            match joined.tail {
+                // SAFETY: `tail` is the live last node of `joined`.
+                Some(tail) => unsafe { (*tail.as_ptr()).next = Some(head) },
+                None => joined.head = Some(head),
+            }
+            joined.tail = list.tail.take();
         }
+        joined
+    }
+}
 
-        let mut current = &mut self.head;
-        for _ in 0..(index - 1) {
-            match current {
-                Some(node) => {
-                    current = &mut node.next;
-                }
-                None => {
-                    return Err("Index out of bounds".to_string());
-                }
+impl<T, A: Alloc> DynamicLinkedList<T, A> {
+    /// Creates a new, empty `DynamicLinkedList` whose nodes are allocated from `alloc`.
+    ///
+    /// # Parameters
+    /// - `alloc`: The allocator for the nodes.
+    ///
+    /// # Returns
+    /// - A new empty `DynamicLinkedList` instance.
+    pub fn new_in(alloc: A) -> Self {
+        DynamicLinkedList {
+            head: None,
+            tail: None,
+            finger: FingerLock::new(),
+            pool: None,
+            pooled: 0,
+            alloc,
+            handle_id: 0,
+            next_key: 1,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns how much memory the list takes up: one allocation per element and per
+    /// pooled node, on top of the list itself.
+    ///
+    /// The node overhead is the `next` pointer plus the 8-byte handle key, so it is 16
+    /// bytes on 64-bit targets before padding.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        let nodes = self.len();
+        MemoryFootprint {
+            nodes,
+            node_overhead: footprint::node_overhead::<Node<T>, T>(),
+            total_bytes: mem::size_of::<Self>() + (nodes + self.pooled) * mem::size_of::<Node<T>>(),
+        }
+    }
+
+    /// Returns how closely the addresses of the nodes follow the list order.
+    pub fn locality(&self) -> LocalityStats {
+        let size = mem::size_of::<Node<T>>();
+        footprint::locality(self.nodes().map(|node| node.as_ptr() as usize / size))
+    }
+
+    /// Moves the elements between the nodes so that walking the list visits the nodes in
+    /// address order. With an arena allocator, whose nodes sit side by side, iterating
+    /// then reads memory front to back. No node is allocated or freed.
+    ///
+    /// # Returns
+    /// - The locality before and after.
+    pub fn optimize_layout(&mut self) -> (LocalityStats, LocalityStats) {
+        let before = self.locality();
+        let mut nodes: Vec<NonNull<Node<T>>> = self.nodes().collect();
+        instrument::span!(DEBUG, "optimize_layout", len = nodes.len());
+        // SAFETY: every node is live; each element is read out once and written back once,
+        // into the node at its position in address order.
+        let data: Vec<T> = nodes
+            .iter()
+            .map(|node| unsafe { ptr::addr_of!((*node.as_ptr()).data).read() })
+            .collect();
+        nodes.sort_unstable();
+        for (index, (node, data)) in nodes.iter().zip(data).enumerate() {
+            // SAFETY: as above.
+            unsafe {
+                ptr::addr_of_mut!((*node.as_ptr()).data).write(data);
+                (*node.as_ptr()).next = nodes.get(index + 1).copied();
             }
         }
+        self.head = nodes.first().copied();
+        self.tail = nodes.last().copied();
+        self.set_finger(None);
+        self.forget_handles();
+        (before, self.locality())
+    }
 
-        match current {
-            Some(node) => {
-                let new_node = Box::new(Node {
-                    data,
-                    next: node.next.take(),
-                });
-                node.next = Some(new_node);
-                Ok(())
+    /// Allocates nodes into the pool until it holds at least `additional`, so the next
+    /// `additional` insertions do not allocate.
+    ///
+    /// # Parameters
+    /// - `additional`: The number of insertions to prepare for.
+    pub fn reserve(&mut self, additional: usize) {
+        instrument::span!(DEBUG, "reserve", additional, pooled = self.pooled);
+        let layout = Layout::new::<Node<T>>();
+        while self.pooled < additional {
+            match self.alloc.allocate(layout) {
+                // SAFETY: the block is fresh and fits a `Node<T>`.
+                Some(block) => unsafe { self.recycle(block.cast()) },
+                None => handle_alloc_error(layout),
             }
-            None => Err("Index out of bounds".to_string()),
         }
     }
 
-    /// Deletes the first occurrence of the given value from the list.
+    /// Frees the nodes kept for reuse by earlier removals.
+    pub fn shrink_pool(&mut self) {
+        instrument::event!(DEBUG, freed = self.pooled, "shrink_pool");
+        if self.pool.is_some() {
+            self.forget_handles();
+        }
+        while let Some(block) = self.pool {
+            // SAFETY: pooled blocks came from `alloc` and only their `next` and `key` are
+            // initialized.
+            unsafe {
+                self.pool = ptr::addr_of!((*block.as_ptr()).next).read();
+                self.alloc
+                    .deallocate(block.cast(), Layout::new::<Node<T>>());
+            }
+        }
+        self.pooled = 0;
+    }
+
+    /// Returns the allocator the nodes are allocated from.
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Returns the number of elements. The list does not store its length, so this walks
+    /// every node.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            current: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns mutable references to the elements at several indices at once, found in a
+    /// single walk, e.g. to swap two elements in place.
     ///
     /// # Parameters
-    /// - `data`: The value to delete.
+    /// - `indices`: The indices of the elements, in any order.
     ///
     /// # Returns
-    /// - `true` if the value was found and removed.
-    /// - `false` if the value was not found.
-    fn delete_element(&mut self, data: T) -> bool {
-        if self.head.is_none() {
-            return false;
+    /// - `Some([&mut T; K])` with the elements, in the order of `indices`.
+    /// - `None` if an index is out of bounds or two indices are equal.
+    pub fn get_many_mut<const K: usize>(&mut self, indices: [usize; K]) -> Option<[&mut T; K]> {
+        let mut order: [usize; K] = core::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| indices[i]);
+        if order
+            .windows(2)
+            .any(|pair| indices[pair[0]] == indices[pair[1]])
+        {
+            return None;
         }
-
-        if self.head.as_ref().unwrap().data == data {
-            self.head = self.head.take().unwrap().next;
-            return true;
+        let mut nodes = [None; K];
+        let mut current = self.head;
+        let mut position = 0;
+        for &i in &order {
+            while position < indices[i] {
+                // SAFETY: every node reachable from `head` is live.
+                current = unsafe { (*current?.as_ptr()).next };
+                position += 1;
+            }
+            nodes[i] = Some(current?);
         }
+        // SAFETY: the indices are distinct, so the nodes are too, and they are uniquely
+        // borrowed via `&mut self`.
+        Some(nodes.map(|node| unsafe { &mut (*node.unwrap().as_ptr()).data }))
+    }
 
-        let mut current = &mut self.head;
-        while let Some(node) = current {
-            if node.next.is_some() && node.next.as_ref().unwrap().data == data {
-                node.next = node.next.take().unwrap().next;
-                return true;
+    /// Removes and returns the element at `index`, moving the first element into its
+    /// place, like `Vec::swap_remove` does with the last one.
+    ///
+    /// Only the head node is unlinked, so no other link changes; finding `index` still
+    /// walks the list. Use it when the order of the elements does not matter. A handle to
+    /// the first element goes stale, since the element moves to another node.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to remove.
+    ///
+    /// # Returns
+    /// - `Ok(T)` with the removed element.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn swap_remove_at(&mut self, index: usize) -> Result<T, LinkedListError> {
+        instrument::event!(TRACE, index, "swap_remove_at");
+        let (Some(node), Some(head)) = (self.node_at(index), self.head) else {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        };
+        // SAFETY: `node` and `head` are live nodes of this list, uniquely borrowed via
+        // `&mut self`, and `head` has no predecessor.
+        unsafe {
+            if node != head {
+                ptr::swap(&mut (*node.as_ptr()).data, &mut (*head.as_ptr()).data);
+                (*node.as_ptr()).key = 0; // Its handle is for the removed element.
             }
-            current = &mut node.next;
+            Ok(self.unlink(None, head))
         }
+    }
+
+    /// Inserts an element at the end (tail) of the list and returns a handle to it, for
+    /// finding it again in O(1) later.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - A `NodeHandle` to the new element.
+    pub fn insert_with_handle(&mut self, data: T) -> NodeHandle<T> {
+        self.link_with_handle(self.tail, data)
+    }
 
-        false
+    /// Returns `true` if `handle` was given out by this list and its element is still in
+    /// the list.
+    ///
+    /// # Parameters
+    /// - `handle`: The handle to check.
+    pub fn contains_handle(&self, handle: NodeHandle<T>) -> bool {
+        self.node_of(handle).is_some()
     }
 
-    /// Deletes the element at the specified index.
+    /// Returns a reference to the element behind a handle in O(1).
     ///
     /// # Parameters
-    /// - `index`: The index of the element to delete.
+    /// - `handle`: The handle of the element.
     ///
     /// # Returns
-    /// - `Ok(())` on success.
-    /// - `Err("Index out of bounds")` if the index is invalid.
-    fn delete_at_index(&mut self, index: usize) -> Result<(), String> {
+    /// - `Some(&T)` if the handle is live.
+    /// - `None` if it is stale.
+    pub fn get_by_handle(&self, handle: NodeHandle<T>) -> Option<&T> {
+        let node = self.node_of(handle)?;
+        // SAFETY: `node_of` only returns live nodes.
+        Some(unsafe { &(*node.as_ptr()).data })
+    }
+
+    /// Returns a mutable reference to the element behind a handle in O(1).
+    ///
+    /// # Parameters
+    /// - `handle`: The handle of the element.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the handle is live.
+    /// - `None` if it is stale.
+    pub fn get_mut_by_handle(&mut self, handle: NodeHandle<T>) -> Option<&mut T> {
+        let node = self.node_of(handle)?;
+        // SAFETY: `node_of` only returns live nodes, uniquely borrowed via `&mut self`.
+        Some(unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Inserts an element directly after the element behind a handle in O(1).
+    ///
+    /// # Parameters
+    /// - `handle`: The handle of the element to insert after.
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(NodeHandle<T>)` with a handle to the new element.
+    /// - `Err(LinkedListError::StaleKey)` if the handle is stale.
+    pub fn insert_after_handle(
+        &mut self,
+        handle: NodeHandle<T>,
+        data: T,
+    ) -> Result<NodeHandle<T>, LinkedListError> {
+        let node = self.node_of(handle).ok_or(LinkedListError::StaleKey)?;
+        Ok(self.link_with_handle(Some(node), data))
+    }
+
+    /// Inserts an element directly before the element behind a handle. The list is singly
+    /// linked, so finding the node before it walks from the head.
+    ///
+    /// # Parameters
+    /// - `handle`: The handle of the element to insert before.
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(NodeHandle<T>)` with a handle to the new element.
+    /// - `Err(LinkedListError::StaleKey)` if the handle is stale.
+    pub fn insert_before_handle(
+        &mut self,
+        handle: NodeHandle<T>,
+        data: T,
+    ) -> Result<NodeHandle<T>, LinkedListError> {
+        let node = self.node_of(handle).ok_or(LinkedListError::StaleKey)?;
+        let prev = self.prev_of(node);
+        Ok(self.link_with_handle(prev, data))
+    }
+
+    /// Removes the element behind a handle. The list is singly linked, so finding the node
+    /// before it walks from the head.
+    ///
+    /// # Parameters
+    /// - `handle`: The handle of the element to remove.
+    ///
+    /// # Returns
+    /// - `Some(T)` with the removed element if the handle was live.
+    /// - `None` if it is stale.
+    pub fn remove_by_handle(&mut self, handle: NodeHandle<T>) -> Option<T> {
+        let node = self.node_of(handle)?;
+        let prev = self.prev_of(node);
+        // SAFETY: `node` is a live node and `prev` its predecessor.
+        Some(unsafe { self.unlink(prev, node) })
+    }
+
+    /// Returns a cursor positioned at the first element.
+    ///
+    /// Walking with a cursor costs O(1) per step, where calling `get(i)` for each `i`
+    /// walks from the head every time.
+    pub fn cursor(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            list: self,
+            current: self.head,
+            index: 0,
+        }
+    }
+
+    /// Returns a mutable cursor positioned at the first element, for O(1) insertions and
+    /// removals at the cursor.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut {
+            prev: None,
+            current: self.head,
+            list: self,
+            index: 0,
+        }
+    }
+
+    /// Moves the elements, in order, into a `StaticLinkedList` with capacity `N`.
+    ///
+    /// # Returns
+    /// - `Ok(StaticLinkedList<T, N>)` holding the same elements.
+    /// - `Err(DynamicLinkedList<T>)` with this list, unchanged, if it has more than `N`
+    ///   elements.
+    pub fn try_into_static<const N: usize>(mut self) -> Result<StaticLinkedList<T, N>, Self> {
+        if self.len() > N {
+            return Err(self);
+        }
+        let mut list = StaticLinkedList::new();
+        while let Some(node) = self.head {
+            // SAFETY: `head` is a live node with no predecessor.
+            list.insert(unsafe { self.unlink(None, node) });
+        }
+        Ok(list)
+    }
+
+    /// Moves the elements out front to back, handing each to `push`.
+    fn move_each(mut self, mut push: impl FnMut(T)) {
+        while let Some(node) = self.head {
+            // SAFETY: `head` is a live node with no predecessor.
+            push(unsafe { self.unlink(None, node) });
+        }
+    }
+
+    /// Splits the list in two at `index` by severing a single link: the front holds the
+    /// elements before `index`, the back the rest. Unlike `CursorMut::split_after`, both
+    /// halves are returned by value.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the first element of the back half.
+    ///
+    /// # Returns
+    /// - `Ok((front, back))` with the two halves. The back half allocates from a clone of
+    ///   this list's allocator, and the pooled nodes stay with the front half.
+    /// - `Err(DynamicLinkedList<T, A>)` with this list, unchanged, if `index` is past the
+    ///   end.
+    pub fn split_at(mut self, index: usize) -> Result<(Self, Self), Self> {
+        let mut back = DynamicLinkedList::new_in(self.alloc.clone());
         if index == 0 {
-            if self.head.is_none() {
-                return Err("Index out of bounds".to_string());
+            return Ok((back, self));
+        }
+        let Some(last) = self.node_at(index - 1) else {
+            return Err(self);
+        };
+        // SAFETY: `last` is a live node; the nodes after it move to `back`.
+        back.head = unsafe { (*last.as_ptr()).next.take() };
+        self.set_finger(None);
+        if back.head.is_some() {
+            self.forget_handles();
+            back.tail = self.tail.replace(last);
+        }
+        Ok((self, back))
+    }
+
+    /// Inserts an element at the front of the list in O(1).
+    #[cfg(feature = "std")]
+    pub(crate) fn push_front(&mut self, data: T) {
+        self.link_after(None, data);
+    }
+
+    /// Reverses the order of the elements in place, relinking nodes without moving data.
+    #[cfg(feature = "std")]
+    pub(crate) fn reverse(&mut self) {
+        let mut reversed = None;
+        let mut current = self.head;
+        while let Some(node) = current {
+            // SAFETY: every node reachable from `head` is live, and each is relinked once.
+            unsafe {
+                current = (*node.as_ptr()).next;
+                (*node.as_ptr()).next = reversed;
             }
-            self.head = self.head.take().unwrap().next;
-            return Ok(());
+            reversed = Some(node);
         }
+        self.tail = self.head;
+        self.head = reversed;
+        self.set_finger(None);
+    }
 
-        let mut current = &mut self.head;
-        for _ in 0..(index - 1) {
-            match current {
-                Some(node) => {
-                    current = &mut node.next;
-                }
-                None => {
-                    return Err("Index out of bounds".to_string());
-                }
+    /// Returns an iterator over the nodes, front to back.
+    fn nodes(&self) -> impl Iterator<Item = NonNull<Node<T>>> + '_ {
+        // SAFETY: every node reachable from `head` is live.
+        iter::successors(self.head, |node| unsafe { (*node.as_ptr()).next })
+    }
+
+    /// Returns the node at `index`, or `None` if the index is out of bounds.
+    ///
+    /// The walk starts from the cached finger when it is at or before `index`, so reading
+    /// the elements in order costs O(1) each. The node found becomes the new finger.
+    fn node_at(&self, index: usize) -> Link<T> {
+        let mut finger = self.finger.try_lock();
+        let (mut position, mut current) = match finger.as_deref() {
+            Some(&Some((position, node))) if position <= index => (position, Some(node)),
+            _ => (0, self.head),
+        };
+        instrument::event!(TRACE, index, walked = index - position, "node_at");
+        while position < index {
+            // SAFETY: every node reachable from `head` is live, and the finger is cleared
+            // before any node is unlinked.
+            current = unsafe { (*current?.as_ptr()).next };
+            position += 1;
+        }
+        if let (Some(finger), Some(node)) = (finger.as_deref_mut(), current) {
+            *finger = Some((index, node));
+        }
+        current
+    }
+
+    /// Replaces the cached finger, e.g. with `None` after the links have changed.
+    fn set_finger(&mut self, finger: Finger<T>) {
+        *self.finger.get_mut() = finger;
+    }
+
+    /// Makes every handle given out so far stale, before nodes may be freed or moved out
+    /// of the list.
+    fn forget_handles(&mut self) {
+        self.handle_id = 0;
+    }
+
+    /// Allocates a node holding `data`, links it after `prev` and returns a handle to it.
+    fn link_with_handle(&mut self, prev: Link<T>, data: T) -> NodeHandle<T> {
+        if self.handle_id == 0 {
+            self.handle_id = HANDLE_IDS.fetch_add(1, Ordering::Relaxed);
+        }
+        let key = self.next_key;
+        self.next_key += 1;
+        let node = self.link_after(prev, data);
+        // SAFETY: `node` was just linked, and is uniquely borrowed via `&mut self`.
+        unsafe { (*node.as_ptr()).key = key };
+        NodeHandle {
+            list: self.handle_id,
+            node,
+            key,
+        }
+    }
+
+    /// Returns the node behind `handle`, or `None` if the handle is stale.
+    fn node_of(&self, handle: NodeHandle<T>) -> Link<T> {
+        // SAFETY: no node has been freed or moved out of the list since it gave out a handle
+        // with its current id, so the node is live or in the pool, and either way its `key`
+        // is initialized. Pooled blocks have key 0, which no handle has.
+        let live = handle.list == self.handle_id
+            && unsafe { ptr::addr_of!((*handle.node.as_ptr()).key).read() } == handle.key;
+        live.then_some(handle.node)
+    }
+
+    /// Returns the node before `node`, or `None` if it is the head.
+    fn prev_of(&self, node: NonNull<Node<T>>) -> Link<T> {
+        let mut prev = None;
+        for current in self.nodes() {
+            if current == node {
+                break;
             }
+            prev = Some(current);
         }
+        prev
+    }
 
-        match current {
-            Some(node) => {
-                if node.next.is_none() {
-                    return Err("Index out of bounds".to_string());
+    /// Returns the node after `prev`, or the head if `prev` is `None`.
+    fn next_of(&self, prev: Link<T>) -> Link<T> {
+        match prev {
+            // SAFETY: callers only pass live nodes of this list.
+            Some(prev) => unsafe { (*prev.as_ptr()).next },
+            None => self.head,
+        }
+    }
+
+    /// Points the link after `prev` (or the head if `prev` is `None`) at `next`, forgetting
+    /// the cached finger.
+    fn set_next(&mut self, prev: Link<T>, next: Link<T>) {
+        self.set_finger(None);
+        match prev {
+            // SAFETY: callers only pass live nodes of this list, uniquely borrowed via
+            // `&mut self`.
+            Some(prev) => unsafe { (*prev.as_ptr()).next = next },
+            None => self.head = next,
+        }
+    }
+
+    /// Allocates a node holding `data` and links it after `prev`, or at the front if
+    /// `prev` is `None`. Returns the new node.
+    fn link_after(&mut self, prev: Link<T>, data: T) -> NonNull<Node<T>> {
+        let next = self.next_of(prev);
+        let node = match self.pool {
+            Some(block) => {
+                // SAFETY: pooled blocks came from `alloc` and only their `next` and `key`
+                // are initialized.
+                self.pool = unsafe { ptr::addr_of!((*block.as_ptr()).next).read() };
+                self.pooled -= 1;
+                block
+            }
+            None => {
+                instrument::event!(TRACE, "allocate node");
+                let layout = Layout::new::<Node<T>>();
+                match self.alloc.allocate(layout) {
+                    Some(block) => block.cast::<Node<T>>(),
+                    None => handle_alloc_error(layout),
                 }
-                node.next = node.next.take().unwrap().next;
-                Ok(())
             }
-            None => Err("Index out of bounds".to_string()),
+        };
+        // SAFETY: `node` is an unused block that fits a `Node<T>`.
+        unsafe { node.as_ptr().write(Node { data, next, key: 0 }) };
+        self.set_next(prev, Some(node));
+        if next.is_none() {
+            self.tail = Some(node);
         }
+        node
     }
 
-    /// Updates the first node that matches `old_data` with `new_data`.
+    /// Unlinks `node` (whose predecessor is `prev`), puts its block in the pool and returns
+    /// its data.
     ///
-    /// # Parameters
-    /// - `old_data`: The value to replace.
-    /// - `new_data`: The new value.
+    /// # Safety
+    /// `node` must be a live node of this list and `prev` its actual predecessor.
+    unsafe fn unlink(&mut self, prev: Link<T>, node: NonNull<Node<T>>) -> T {
+        let block = node;
+        let node = ptr::read(block.as_ptr());
+        self.recycle(block);
+        self.set_next(prev, node.next);
+        if node.next.is_none() {
+            self.tail = prev;
+        }
+        node.data
+    }
+
+    /// Puts an unused block in the pool.
     ///
-    /// # Returns
-    /// - `true` if an update occurred.
-    /// - `false` if the old value was not found.
-    fn update_element(&mut self, old_data: T, new_data: T) -> bool {
-        let mut current = &mut self.head;
+    /// # Safety
+    /// `block` must come from `alloc`, fit a `Node<T>` and hold no live data.
+    unsafe fn recycle(&mut self, block: NonNull<Node<T>>) {
+        ptr::addr_of_mut!((*block.as_ptr()).next).write(self.pool);
+        ptr::addr_of_mut!((*block.as_ptr()).key).write(0);
+        self.pool = Some(block);
+        self.pooled += 1;
+    }
+
+    /// Returns `(prev, node)` for the first node whose data equals `data`.
+    fn locate_match<Q>(&self, data: &Q) -> Option<(Link<T>, NonNull<Node<T>>)>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut prev = None;
+        let mut current = self.head;
         while let Some(node) = current {
-            if node.data == old_data {
-                node.data = new_data;
-                return true;
+            // SAFETY: every node reachable from `head` is live.
+            unsafe {
+                if (*node.as_ptr()).data.borrow() == data {
+                    return Some((prev, node));
+                }
+                prev = current;
+                current = (*node.as_ptr()).next;
             }
-            current = &mut node.next;
         }
-        false
+        None
     }
+}
 
-    /// Updates the data of the node at the specified index.
+impl<T, A: Alloc> Drop for DynamicLinkedList<T, A> {
+    /// Drops the elements one at a time, front to back, then frees the pooled nodes. With
+    /// an arena allocator and elements that need no dropping there is nothing to do.
+    fn drop(&mut self) {
+        if !A::FREES && !mem::needs_drop::<T>() {
+            return;
+        }
+        while let Some(node) = self.head {
+            // SAFETY: `head` is a live node with no predecessor.
+            unsafe { self.unlink(None, node) };
+        }
+        self.shrink_pool();
+    }
+}
+
+impl<T, A: Alloc + Default> Default for DynamicLinkedList<T, A> {
+    /// Provides a default (empty) instance of the list using `new_in()`.
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<T: Display, A: Alloc> DynamicLinkedList<T, A> {
+    /// Draws the chain of nodes from the head, e.g. `[head] -> 3 -> 7 -> X`.
+    ///
+    /// # Returns
+    /// - The drawing, on one line.
+    pub fn render_ascii(&self) -> String {
+        render::chain(self.iter())
+    }
+}
+
+impl<T: Debug, A: Alloc> Debug for DynamicLinkedList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> From<LinkedList<T>> for DynamicLinkedList<T> {
+    /// Moves the elements of a standard `LinkedList` into a new list, in order.
+    fn from(elements: LinkedList<T>) -> Self {
+        let mut list = DynamicLinkedList::new();
+        for data in elements {
+            list.insert(data);
+        }
+        list
+    }
+}
+
+impl<T> From<VecDeque<T>> for DynamicLinkedList<T> {
+    /// Moves the elements of a `VecDeque` into a new list, front to back.
+    fn from(elements: VecDeque<T>) -> Self {
+        let mut list = DynamicLinkedList::new();
+        for data in elements {
+            list.insert(data);
+        }
+        list
+    }
+}
+
+impl<T, A: Alloc> From<DynamicLinkedList<T, A>> for LinkedList<T> {
+    /// Moves the elements into a standard `LinkedList`, in order.
+    fn from(list: DynamicLinkedList<T, A>) -> Self {
+        let mut elements = LinkedList::new();
+        list.move_each(|data| elements.push_back(data));
+        elements
+    }
+}
+
+impl<T, A: Alloc> From<DynamicLinkedList<T, A>> for VecDeque<T> {
+    /// Moves the elements into a `VecDeque`, front to back.
+    fn from(list: DynamicLinkedList<T, A>) -> Self {
+        let mut elements = VecDeque::with_capacity(list.len());
+        list.move_each(|data| elements.push_back(data));
+        elements
+    }
+}
+
+impl<T, A: Alloc> ListRead<T> for DynamicLinkedList<T, A> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        DynamicLinkedList::len(self)
+    }
+
+    /// Returns the index of the first element matching `predicate`.
     ///
     /// # Parameters
-    /// - `index`: The index of the node to update.
-    /// - `data`: The new value to set.
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the data at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        let node = self.node_at(index)?;
+        // SAFETY: `node_at` only returns live nodes, borrowed here through `&self`.
+        Some(unsafe { &(*node.as_ptr()).data })
+    }
+}
+
+impl<T, A: Alloc> ListWrite<T> for DynamicLinkedList<T, A> {
+    /// Inserts an element at the end (tail) of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        instrument::event!(TRACE, "insert");
+        self.link_after(self.tail, data);
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
     ///
     /// # Returns
     /// - `Ok(())` on success.
-    /// - `Err("Index out of bounds")` if the index is invalid.
-    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), String> {
-        let mut current = &mut self.head;
-        for _ in 0..index {
-            match current {
-                Some(node) => {
-                    current = &mut node.next;
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        instrument::event!(TRACE, index, "insert_at_index");
+        let prev = match index {
+            0 => None,
+            _ => match self.node_at(index - 1) {
+                Some(node) => Some(node),
+                None => {
+                    return Err(LinkedListError::IndexOutOfBounds {
+                        index,
+                        len: self.len(),
+                    });
                 }
+            },
+        };
+
+        let node = self.link_after(prev, data);
+        self.set_finger(Some((index, node)));
+        Ok(())
+    }
+
+    /// Inserts the elements of `items` in order, the first one at `index`, walking to
+    /// `index` only once.
+    ///
+    /// # Parameters
+    /// - `index`: The position of the first inserted element (0-based).
+    /// - `items`: The values to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid, in which case
+    ///   nothing is inserted.
+    fn insert_many_at<I>(&mut self, index: usize, items: I) -> Result<(), LinkedListError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        instrument::event!(TRACE, index, "insert_many_at");
+        let mut prev = match index {
+            0 => None,
+            _ => match self.node_at(index - 1) {
+                Some(node) => Some(node),
                 None => {
-                    return Err("Index out of bounds".to_string());
+                    return Err(LinkedListError::IndexOutOfBounds {
+                        index,
+                        len: self.len(),
+                    });
                 }
+            },
+        };
+
+        for item in items {
+            prev = Some(self.link_after(prev, item));
+        }
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        instrument::event!(TRACE, "delete_element");
+        match self.locate_match(data) {
+            Some((prev, node)) => {
+                // SAFETY: `locate_match` returns a live node and its predecessor.
+                unsafe { self.unlink(prev, node) };
+                true
             }
+            None => false,
         }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        instrument::event!(TRACE, index, "delete_at_index");
+        let prev = match index {
+            0 => None,
+            _ => match self.node_at(index - 1) {
+                Some(node) => Some(node),
+                None => {
+                    return Err(LinkedListError::IndexOutOfBounds {
+                        index,
+                        len: self.len(),
+                    });
+                }
+            },
+        };
 
-        match current {
+        match self.next_of(prev) {
             Some(node) => {
-                node.data = data;
+                // SAFETY: `node` is a live node and `prev` its predecessor.
+                unsafe { self.unlink(prev, node) };
                 Ok(())
             }
-            None => Err("Index out of bounds".to_string()),
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
         }
     }
 
-    /// Checks whether a given value exists in the list.
+    /// Returns a mutable reference to the element at the specified index.
     ///
     /// # Parameters
-    /// - `data`: A reference to the value to search for.
+    /// - `index`: The index of the element to retrieve.
     ///
     /// # Returns
-    /// - `true` if the value exists in the list.
-    /// - `false` otherwise.
-    fn find(&self, data: &T) -> bool {
-        let mut current = &self.head;
-        while let Some(node) = current {
-            if &node.data == data {
-                return true;
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let node = self.node_at(index)?;
+        // SAFETY: `node_at` only returns live nodes, uniquely borrowed via `&mut self`.
+        Some(unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Updates the data of the node at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the node to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        match self.get_mut(index) {
+            Some(slot) => {
+                *slot = data;
+                Ok(())
             }
-            current = &node.next;
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
         }
-        false
     }
+}
 
-    /// Returns a reference to the data at the specified index.
+impl<T, A: Alloc> LinkedListTrait<T> for DynamicLinkedList<T, A> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        DynamicLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        DynamicLinkedList::iter_mut(self)
+    }
+}
+
+impl<'a, T, A: Alloc> IntoIterator for &'a DynamicLinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a `DynamicLinkedList`.
+pub struct Iter<'a, T> {
+    /// The next node to yield.
+    current: Link<T>,
+    /// Ties the iterator to the borrow of the list.
+    _marker: PhantomData<&'a T>,
+}
+
+// SAFETY: the iterator only reads through its pointers, like the `&T`s it yields.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: as above.
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.current?;
+        // SAFETY: the list is borrowed for `'a`, so every node reachable from it is live.
+        unsafe {
+            self.current = (*node.as_ptr()).next;
+            Some(&(*node.as_ptr()).data)
+        }
+    }
+}
+
+/// An iterator over mutable references to the elements of a `DynamicLinkedList`.
+pub struct IterMut<'a, T> {
+    /// The next node to yield.
+    current: Link<T>,
+    /// Ties the iterator to the exclusive borrow of the list.
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator hands out each element once, like the `&mut T`s it yields.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared iterator gives no access to the elements.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let node = self.current?;
+        // SAFETY: the list is exclusively borrowed for `'a`, and each node is yielded once.
+        unsafe {
+            self.current = (*node.as_ptr()).next;
+            Some(&mut (*node.as_ptr()).data)
+        }
+    }
+}
+
+/// A read-only cursor over a `DynamicLinkedList`, remembering its position between steps.
+pub struct Cursor<'a, T, A: Alloc = Global> {
+    /// The list the cursor walks.
+    list: &'a DynamicLinkedList<T, A>,
+    /// The node at the cursor, or `None` past the end.
+    current: Link<T>,
+    /// The position of the cursor.
+    index: usize,
+}
+
+// SAFETY: the cursor only reads through its pointers, like the `&T`s it yields.
+unsafe impl<T: Sync, A: Alloc + Sync> Send for Cursor<'_, T, A> {}
+// SAFETY: as above.
+unsafe impl<T: Sync, A: Alloc + Sync> Sync for Cursor<'_, T, A> {}
+
+impl<'a, T, A: Alloc> Cursor<'a, T, A> {
+    /// Returns the position of the cursor. Past the last element this is the length of
+    /// the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the element at the cursor, or `None` past the end.
+    pub fn peek(&self) -> Option<&'a T> {
+        // SAFETY: the list is borrowed for `'a`, so the node at the cursor is live.
+        self.current.map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+
+    /// Moves the cursor to the next element. Does nothing once past the end.
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current {
+            // SAFETY: as in `peek`.
+            self.current = unsafe { (*node.as_ptr()).next };
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to `index`, walking forward from the current position, or from
+    /// the front if `index` is behind it. An index past the end stops at the end.
     ///
     /// # Parameters
-    /// - `index`: The index of the element to retrieve.
+    /// - `index`: The position to move to.
     ///
     /// # Returns
-    /// - `Some(&T)` if the index is valid.
+    /// - `Some(&T)` with the element at `index` if it exists.
     /// - `None` otherwise.
-    fn get(&self, index: usize) -> Option<&T> {
-        let mut current = &self.head;
-        for _ in 0..index {
-            match current {
-                Some(node) => {
-                    current = &node.next;
-                }
-                None => {
-                    return None;
-                }
+    pub fn seek(&mut self, index: usize) -> Option<&'a T> {
+        if index < self.index {
+            *self = self.list.cursor();
+        }
+        while self.index < index && self.current.is_some() {
+            self.move_next();
+        }
+        self.peek()
+    }
+}
+
+impl<T, A: Alloc> Clone for Cursor<'_, T, A> {
+    fn clone(&self) -> Self {
+        Cursor {
+            list: self.list,
+            current: self.current,
+            index: self.index,
+        }
+    }
+}
+
+/// A mutable cursor over a `DynamicLinkedList`, editing the list in place at its position.
+///
+/// The list is singly linked, so the cursor only moves forward. Past the last element the
+/// cursor sits at the end, where insertions append to the list.
+pub struct CursorMut<'a, T, A: Alloc = Global> {
+    /// The list the cursor edits.
+    list: &'a mut DynamicLinkedList<T, A>,
+    /// The node before the cursor, or `None` at the front.
+    prev: Link<T>,
+    /// The node at the cursor, or `None` past the end.
+    current: Link<T>,
+    /// The position of the cursor.
+    index: usize,
+}
+
+// SAFETY: the cursor hands out `&mut T`s only while it is exclusively borrowed, like the
+// `&mut DynamicLinkedList` it holds.
+unsafe impl<T: Send, A: Alloc + Send> Send for CursorMut<'_, T, A> {}
+// SAFETY: a shared cursor gives no access to the elements.
+unsafe impl<T: Sync, A: Alloc + Sync> Sync for CursorMut<'_, T, A> {}
+
+impl<'a, T, A: Alloc> CursorMut<'a, T, A> {
+    /// Returns the position of the cursor. Past the last element this is the length of
+    /// the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the element at the cursor, or `None` past the end.
+    pub fn current(&mut self) -> Option<&mut T> {
+        // SAFETY: the node at the cursor is live, and the list is exclusively borrowed.
+        self.current
+            .map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Moves the cursor to the next element. Does nothing once past the end.
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current {
+            self.prev = self.current;
+            // SAFETY: the node at the cursor is live.
+            self.current = unsafe { (*node.as_ptr()).next };
+            self.index += 1;
+        }
+    }
+
+    /// Inserts an element before the cursor. The cursor stays on the same element, so its
+    /// index grows by one.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn insert_before(&mut self, data: T) {
+        self.prev = Some(self.list.link_after(self.prev, data));
+        self.index += 1;
+    }
+
+    /// Inserts an element after the cursor. Past the end, the element is appended like
+    /// with `insert_before`.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn insert_after(&mut self, data: T) {
+        match self.current {
+            Some(_) => {
+                self.list.link_after(self.current, data);
             }
+            None => self.insert_before(data),
         }
+    }
 
-        match current {
-            Some(node) => Some(&node.data),
-            None => None,
+    /// Removes the element at the cursor, moving the cursor onto the element after it.
+    ///
+    /// # Returns
+    /// - `Some(T)` with the removed element.
+    /// - `None` if the cursor is past the end.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        // SAFETY: the node at the cursor is live and `prev` is its predecessor.
+        unsafe {
+            self.current = (*node.as_ptr()).next;
+            Some(self.list.unlink(self.prev, node))
+        }
+    }
+
+    /// Splits the list after the cursor, leaving the cursor at the end of this list.
+    ///
+    /// # Returns
+    /// - A list of the elements after the cursor, empty if the cursor is past the end. It
+    ///   allocates from a clone of this list's allocator.
+    pub fn split_after(&mut self) -> DynamicLinkedList<T, A> {
+        let mut rest = DynamicLinkedList::new_in(self.list.alloc.clone());
+        if let Some(node) = self.current {
+            // SAFETY: the node at the cursor is live; the nodes after it move to `rest`.
+            rest.head = unsafe { (*node.as_ptr()).next.take() };
+            self.list.set_finger(None);
+            if rest.head.is_some() {
+                self.list.forget_handles();
+                rest.tail = self.list.tail.replace(node);
+            }
         }
+        rest
     }
 }
@@ -0,0 +1,39 @@
+use core::error::Error;
+use core::fmt;
+
+/// The error returned by the fallible list operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum LinkedListError {
+    /// `index` does not refer to a position in a list of `len` elements.
+    IndexOutOfBounds {
+        /// The index that was passed.
+        index: usize,
+        /// The length of the list at the time of the call.
+        len: usize,
+    },
+    /// The list already holds `capacity` elements and cannot grow.
+    CapacityExceeded {
+        /// The maximum number of elements the list can hold.
+        capacity: usize,
+    },
+    /// A key no longer refers to an element, because the element it named was removed.
+    StaleKey,
+}
+
+impl fmt::Display for LinkedListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkedListError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} is out of bounds for a list of length {len}")
+            }
+            LinkedListError::CapacityExceeded { capacity } => {
+                write!(f, "the list is full (capacity {capacity})")
+            }
+            LinkedListError::StaleKey => write!(f, "the key no longer refers to an element"),
+        }
+    }
+}
+
+impl Error for LinkedListError {}
@@ -0,0 +1,61 @@
+//! `Extend` implementations for the lists.
+//!
+//! Every list below appends the items to its tail with [`ListWrite::insert`], both by
+//! value and, for `Copy` elements, by reference, so `list.extend(slice.iter())` works
+//! like it does for `Vec` without a `.copied()` at the call site. A
+//! `StaticLinkedList` drops the items that do not fit, as its `insert` does; use
+//! `insert_many_at` to find out.
+
+use crate::static_linked_list::StaticLinkedList;
+use crate::ListWrite;
+#[cfg(feature = "alloc")]
+use crate::{
+    allocator::Alloc, circular_doubly_linked_list::CircularDoublyLinkedList,
+    circular_linked_list::CircularLinkedList, doubly_linked_list::DoublyLinkedList,
+    dynamic_linked_list::DynamicLinkedList, skip_list::IndexedSkipList,
+    unrolled_linked_list::UnrolledLinkedList, xor_linked_list::XorLinkedList,
+};
+
+/// Implements `Extend<T>` and `Extend<&T>` for a list that implements `ListWrite<T>`.
+/// The brackets hold any generic parameters besides `T`.
+macro_rules! impl_extend {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $list:ty;)*) => {
+        $(
+            $(#[$attr])*
+            impl<T, $($generics)*> Extend<T> for $list {
+                fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                    for data in iter {
+                        self.insert(data);
+                    }
+                }
+            }
+
+            $(#[$attr])*
+            impl<'a, T: Copy + 'a, $($generics)*> Extend<&'a T> for $list {
+                fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                    for &data in iter {
+                        self.insert(data);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_extend! {
+    [const N: usize] StaticLinkedList<T, N>;
+    #[cfg(feature = "alloc")]
+    [] CircularDoublyLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [] CircularLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [] DoublyLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [A: Alloc] DynamicLinkedList<T, A>;
+    #[cfg(feature = "alloc")]
+    [] IndexedSkipList<T>;
+    #[cfg(feature = "alloc")]
+    [const B: usize] UnrolledLinkedList<T, B>;
+    #[cfg(feature = "alloc")]
+    [] XorLinkedList<T>;
+}
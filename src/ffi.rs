@@ -0,0 +1,155 @@
+//! A C interface to `DynamicLinkedList`, enabled by the `ffi` feature.
+//!
+//! C and C++ code sees the list as an opaque [`LlList`] handle holding `int64_t`s, created
+//! with [`ll_new`] and released with [`ll_free`]. Every fallible call returns an
+//! [`LlStatus`] and writes any result through an out-pointer, so no Rust error or panic
+//! crosses the boundary. The items are plain `extern "C"` functions and `#[repr(C)]`
+//! types, so `cbindgen` can generate the header:
+//!
+//! ```text
+//! cbindgen --lang c --crate linked_list_impls --output linked_list.h
+//! ```
+//!
+//! Build the library for linking with
+//! `cargo rustc --release --features ffi --crate-type staticlib` (or `cdylib`).
+
+use alloc::boxed::Box;
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{LinkedListError, ListRead, ListWrite};
+
+/// An opaque handle to a list of `int64_t`s.
+pub struct LlList {
+    /// The wrapped list.
+    list: DynamicLinkedList<i64>,
+}
+
+/// The result of a fallible call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A pointer argument was null.
+    NullPointer = 1,
+    /// The index was past the end of the list.
+    IndexOutOfBounds = 2,
+    /// The list could not grow.
+    CapacityExceeded = 3,
+    /// A key no longer referred to an element.
+    StaleKey = 4,
+}
+
+impl From<LinkedListError> for LlStatus {
+    fn from(error: LinkedListError) -> Self {
+        match error {
+            LinkedListError::IndexOutOfBounds { .. } => LlStatus::IndexOutOfBounds,
+            LinkedListError::CapacityExceeded { .. } => LlStatus::CapacityExceeded,
+            LinkedListError::StaleKey => LlStatus::StaleKey,
+        }
+    }
+}
+
+/// Creates an empty list.
+///
+/// # Returns
+/// - A handle to pass to the other functions, and finally to [`ll_free`]. It is never
+///   null.
+#[no_mangle]
+pub extern "C" fn ll_new() -> *mut LlList {
+    Box::into_raw(Box::new(LlList {
+        list: DynamicLinkedList::new(),
+    }))
+}
+
+/// Frees a list and its elements. Does nothing for a null handle.
+///
+/// # Safety
+/// `list` must be null or a handle from [`ll_new`] that has not been freed, and must not
+/// be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ll_free(list: *mut LlList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// Appends a value to the end of the list.
+///
+/// # Parameters
+/// - `list`: The list to append to.
+/// - `value`: The value to append.
+///
+/// # Returns
+/// - `LlStatus::Ok` on success, or `LlStatus::NullPointer` for a null handle.
+///
+/// # Safety
+/// `list` must be null or a live handle from [`ll_new`].
+#[no_mangle]
+pub unsafe extern "C" fn ll_insert(list: *mut LlList, value: i64) -> LlStatus {
+    let Some(list) = list.as_mut() else {
+        return LlStatus::NullPointer;
+    };
+    list.list.insert(value);
+    LlStatus::Ok
+}
+
+/// Reads the value at `index`.
+///
+/// # Parameters
+/// - `list`: The list to read from.
+/// - `index`: The position of the value, from 0.
+/// - `out`: Where to write the value. It is left untouched on failure.
+///
+/// # Returns
+/// - `LlStatus::Ok` on success, `LlStatus::NullPointer` if `list` or `out` is null, or
+///   `LlStatus::IndexOutOfBounds` if `index` is not less than the length.
+///
+/// # Safety
+/// `list` must be null or a live handle from [`ll_new`], and `out` must be null or
+/// valid for writing an `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_get(list: *const LlList, index: usize, out: *mut i64) -> LlStatus {
+    let (Some(list), false) = (list.as_ref(), out.is_null()) else {
+        return LlStatus::NullPointer;
+    };
+    match list.list.get(index) {
+        Some(&value) => {
+            out.write(value);
+            LlStatus::Ok
+        }
+        None => LlStatus::IndexOutOfBounds,
+    }
+}
+
+/// Removes the value at `index`.
+///
+/// # Parameters
+/// - `list`: The list to remove from.
+/// - `index`: The position of the value, from 0.
+///
+/// # Returns
+/// - `LlStatus::Ok` on success, `LlStatus::NullPointer` for a null handle, or
+///   `LlStatus::IndexOutOfBounds` if `index` is not less than the length.
+///
+/// # Safety
+/// `list` must be null or a live handle from [`ll_new`].
+#[no_mangle]
+pub unsafe extern "C" fn ll_delete_at(list: *mut LlList, index: usize) -> LlStatus {
+    let Some(list) = list.as_mut() else {
+        return LlStatus::NullPointer;
+    };
+    match list.list.delete_at_index(index) {
+        Ok(()) => LlStatus::Ok,
+        Err(error) => error.into(),
+    }
+}
+
+/// Returns the number of values in the list, or 0 for a null handle.
+///
+/// # Safety
+/// `list` must be null or a live handle from [`ll_new`].
+#[no_mangle]
+pub unsafe extern "C" fn ll_len(list: *const LlList) -> usize {
+    list.as_ref().map_or(0, |list| list.list.len())
+}
@@ -0,0 +1,91 @@
+//! Memory accounting for the lists.
+//!
+//! Each list's `memory_footprint()` reports a [`MemoryFootprint`], so implementations can
+//! be compared by what they cost in memory as well as in time. Lists that can rearrange
+//! their nodes also report how well the node placement matches the traversal order as
+//! [`LocalityStats`]; a `StaticLinkedList` adds how its slots are spread out as
+//! [`FragmentationStats`].
+
+use core::mem;
+
+/// How much memory a list takes up.
+///
+/// Heap memory owned by the elements themselves (the buffer of a `String`, say) is not
+/// counted, since the list cannot see it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryFootprint {
+    /// The number of nodes holding an element.
+    pub nodes: usize,
+    /// The bytes each node spends beyond the element itself: links, slot tags and padding.
+    pub node_overhead: usize,
+    /// The bytes of the list value plus everything it has allocated, including unused
+    /// slots and free lists.
+    pub total_bytes: usize,
+}
+
+/// How closely the placement of a list's nodes in memory follows the list order.
+///
+/// A walk over a list whose links all go to the adjacent node reads memory front to back,
+/// which the cache and prefetcher handle best.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalityStats {
+    /// The number of links followed by a walk over the whole list.
+    pub links: usize,
+    /// The links that lead forward in memory.
+    pub forward: usize,
+    /// The links that lead to the node right after the current one.
+    pub adjacent: usize,
+}
+
+/// How the occupied and free slots of a `StaticLinkedList` are spread over its array, and
+/// how far the list order has drifted from the slot order.
+///
+/// After `compact()` the elements fill a prefix of the array in list order: there is one
+/// run of free slots at the end, no element is displaced and every link is adjacent. The
+/// further a list is from that, the more a compaction gains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FragmentationStats {
+    /// The number of slots holding an element.
+    pub occupied: usize,
+    /// The number of free slots.
+    pub free: usize,
+    /// The number of runs of consecutive free slots.
+    pub free_runs: usize,
+    /// The length of the longest run of consecutive free slots.
+    pub longest_free_run: usize,
+    /// The number of elements whose slot differs from their index in the list, which is
+    /// how many `compact()` would move.
+    pub displaced: usize,
+    /// How closely the traversal order follows the slot order.
+    pub locality: LocalityStats,
+}
+
+/// Computes the locality of a walk visiting nodes at `positions`, measured in nodes from
+/// some common origin.
+pub(crate) fn locality(positions: impl IntoIterator<Item = usize>) -> LocalityStats {
+    let mut stats = LocalityStats {
+        links: 0,
+        forward: 0,
+        adjacent: 0,
+    };
+    let mut positions = positions.into_iter();
+    let Some(mut previous) = positions.next() else {
+        return stats;
+    };
+    for position in positions {
+        stats.links += 1;
+        if position > previous {
+            stats.forward += 1;
+        }
+        if position == previous + 1 {
+            stats.adjacent += 1;
+        }
+        previous = position;
+    }
+    stats
+}
+
+/// Returns the bytes a node of type `N` spends beyond its element of type `T`.
+pub(crate) fn node_overhead<N, T>() -> usize {
+    mem::size_of::<N>().saturating_sub(mem::size_of::<T>())
+}
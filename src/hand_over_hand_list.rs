@@ -0,0 +1,312 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Debug};
+use std::ptr;
+use std::sync::PoisonError;
+
+use crate::sync::{Mutex, MutexGuard};
+use crate::LinkedListError;
+
+/// A locked pointer to the next node. The lock also guards that node's `data`.
+type Link<T> = Mutex<Option<Box<Node<T>>>>;
+
+/// `Node` represents a single element of the list.
+struct Node<T> {
+    /// The data stored in the node, guarded by the link that points at this node.
+    data: T,
+    /// The next node.
+    next: Link<T>,
+}
+
+/// Locks a link, ignoring poisoning: every operation leaves its links consistent before
+/// it can panic.
+fn lock<T>(link: &Link<T>) -> MutexGuard<'_, Option<Box<Node<T>>>> {
+    link.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// `HandOverHandList` is a concurrent singly linked list with one lock per link.
+///
+/// Traversal uses *hand-over-hand* (lock coupling) locking: a thread locks the next link
+/// before releasing the current one, so it always holds at least one lock on its path
+/// and nobody can unlink the node it is standing on. Threads working on different parts
+/// of the list only contend where their paths overlap, unlike
+/// [`ConcurrentLinkedList`](crate::concurrent_linked_list::ConcurrentLinkedList), where
+/// every change locks the whole list. The price is one lock acquisition per node visited.
+///
+/// All methods take `&self`. Because an element may be changed by another thread as soon
+/// as its lock is released, lookups return clones rather than references.
+pub struct HandOverHandList<T> {
+    /// The link to the first node.
+    head: Link<T>,
+}
+
+impl<T> HandOverHandList<T> {
+    /// Creates a new, empty `HandOverHandList`.
+    ///
+    /// # Returns
+    /// - A new empty `HandOverHandList` instance.
+    pub fn new() -> Self {
+        HandOverHandList {
+            head: Mutex::new(None),
+        }
+    }
+
+    /// Returns the number of elements at the moment the traversal passed them.
+    pub fn len(&self) -> usize {
+        self.lock_link(|_, _| false).0
+    }
+
+    /// Returns `true` if the list was empty at the moment of the check.
+    pub fn is_empty(&self) -> bool {
+        lock(&self.head).is_none()
+    }
+
+    /// Inserts an element at the front of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&self, data: T) {
+        Self::link_before(&mut lock(&self.head), data);
+    }
+
+    /// Inserts an element at the end (tail) of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn insert(&self, data: T) {
+        let (_, mut link) = self.lock_link(|_, _| false);
+        Self::link_before(&mut link, data);
+    }
+
+    /// Inserts an element at a specific index.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn insert_at_index(&self, index: usize, data: T) -> Result<(), LinkedListError> {
+        let (reached, mut link) = self.lock_link(|i, _| i == index);
+        if reached != index {
+            // The walk stopped at the end, so `reached` is the length.
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: reached,
+            });
+        }
+        Self::link_before(&mut link, data);
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn remove(&self, index: usize) -> Option<T> {
+        let (_, mut link) = self.lock_link(|i, _| i == index);
+        Self::unlink(&mut link)
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn delete_at_index(&self, index: usize) -> Result<(), LinkedListError> {
+        self.remove(index)
+            .map(drop)
+            .ok_or_else(|| LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            })
+    }
+
+    /// Updates the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn update_element_at_index(&self, index: usize, data: T) -> Result<(), LinkedListError> {
+        let (reached, mut link) = self.lock_link(|i, _| i == index);
+        match link.as_mut() {
+            Some(node) => {
+                node.data = data;
+                Ok(())
+            }
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: reached,
+            }),
+        }
+    }
+
+    /// Returns a clone of the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        let (_, link) = self.lock_link(|i, _| i == index);
+        link.as_ref().map(|node| node.data.clone())
+    }
+
+    /// Returns a clone of every element, front to back. Each element is read while its
+    /// lock is held, but the result is not a snapshot of one moment.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut items = Vec::new();
+        self.for_each(|data| items.push(data.clone()));
+        items
+    }
+
+    /// Walks the list hand over hand until `stop` returns `true` for a node's index and
+    /// data, and returns that index with the still-locked link pointing at the node. If
+    /// `stop` never fires, returns the length with the locked link at the end of the list.
+    fn lock_link(
+        &self,
+        mut stop: impl FnMut(usize, &T) -> bool,
+    ) -> (usize, MutexGuard<'_, Option<Box<Node<T>>>>) {
+        let mut link = lock(&self.head);
+        let mut index = 0;
+        loop {
+            let next = match link.as_deref() {
+                Some(node) if !stop(index, &node.data) => ptr::addr_of!(node.next),
+                _ => return (index, link),
+            };
+            // SAFETY: a node is only freed by `unlink`, which holds the lock on the link
+            // pointing at it *and* waits for the node's own `next` lock. We hold the first
+            // until the second is ours, so the node outlives this borrow of its `next`.
+            link = lock(unsafe { &*next });
+            index += 1;
+        }
+    }
+
+    /// Calls `f` on every element, front to back, while holding that element's lock.
+    fn for_each(&self, mut f: impl FnMut(&T)) {
+        drop(self.lock_link(|_, data| {
+            f(data);
+            false
+        }));
+    }
+
+    /// Links a new node holding `data` into the locked, possibly empty, `link`.
+    fn link_before(link: &mut MutexGuard<'_, Option<Box<Node<T>>>>, data: T) {
+        let rest = link.take();
+        **link = Some(Box::new(Node {
+            data,
+            next: Mutex::new(rest),
+        }));
+    }
+
+    /// Unlinks the node behind the locked `link` and returns its data.
+    fn unlink(link: &mut MutexGuard<'_, Option<Box<Node<T>>>>) -> Option<T> {
+        // Locking the node's own link first waits out any thread still standing on it;
+        // no new one can arrive while we hold `link`.
+        let rest = lock(&link.as_ref()?.next).take();
+        let node = std::mem::replace(&mut **link, rest)?;
+        Some(node.data)
+    }
+}
+
+impl<T> HandOverHandList<T> {
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    pub fn delete_element<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let (_, mut link) = self.lock_link(|_, item| item.borrow() == data);
+        Self::unlink(&mut link).is_some()
+    }
+
+    /// Updates the first element that matches `old_data` with `new_data`.
+    ///
+    /// # Parameters
+    /// - `old_data`: A reference to the value to replace.
+    /// - `new_data`: The new value.
+    ///
+    /// # Returns
+    /// - `true` if an update occurred.
+    /// - `false` if the old value was not found.
+    pub fn update_element<Q>(&self, old_data: &Q, new_data: T) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let (_, mut link) = self.lock_link(|_, item| item.borrow() == old_data);
+        match link.as_mut() {
+            Some(node) => {
+                node.data = new_data;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Checks whether a given value exists in the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to search for.
+    ///
+    /// # Returns
+    /// - `true` if the value exists in the list.
+    /// - `false` otherwise.
+    pub fn find<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.lock_link(|_, item| item.borrow() == data).1.is_some()
+    }
+}
+
+impl<T> Drop for HandOverHandList<T> {
+    /// Unlinks nodes one at a time so long lists don't recurse through `Box` drops.
+    fn drop(&mut self) {
+        let mut current = self.head.get_mut().unwrap_or_else(PoisonError::into_inner).take();
+        while let Some(mut node) = current {
+            current = node.next.get_mut().unwrap_or_else(PoisonError::into_inner).take();
+        }
+    }
+}
+
+impl<T> Default for HandOverHandList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for HandOverHandList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.for_each(|data| {
+            list.entry(data);
+        });
+        list.finish()
+    }
+}
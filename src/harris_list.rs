@@ -0,0 +1,304 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::ptr;
+
+use crate::reclaim::{Guard, Reclaimer};
+use crate::sync::{spin_loop, AtomicPtr, Ordering};
+
+/// The low pointer bit used as the logical deletion mark. Nodes are at least
+/// pointer-aligned, so this bit is always zero in a real node address.
+const MARK: usize = 1;
+
+/// Returns `ptr` with the deletion mark set.
+fn marked<T>(ptr: *mut Node<T>) -> *mut Node<T> {
+    ptr.map_addr(|addr| addr | MARK)
+}
+
+/// Returns `ptr` with the deletion mark cleared.
+fn unmarked<T>(ptr: *mut Node<T>) -> *mut Node<T> {
+    ptr.map_addr(|addr| addr & !MARK)
+}
+
+/// Returns `true` if the deletion mark is set on `ptr`.
+fn is_marked<T>(ptr: *mut Node<T>) -> bool {
+    ptr.addr() & MARK != 0
+}
+
+/// `Node` represents a single element of the list.
+struct Node<T> {
+    /// The data stored in the node. `None` only for the head sentinel.
+    data: Option<T>,
+    /// The next node. The mark bit is set once this node has been logically deleted.
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    /// Allocates a node on the heap and leaks it as a raw pointer.
+    fn alloc(data: Option<T>, next: *mut Node<T>) -> *mut Self {
+        Box::into_raw(Box::new(Node {
+            data,
+            next: AtomicPtr::new(next),
+        }))
+    }
+}
+
+/// `HarrisList` is a lock-free sorted linked list, usable as a concurrent set.
+///
+/// It follows Harris's algorithm: removal first *marks* a node by setting the low bit of
+/// its `next` pointer, which makes it logically deleted and stops anyone from linking
+/// after it, and only then unlinks it with a compare-and-swap on the predecessor. Any
+/// traversal that runs into a marked node helps unlink it, so every operation completes
+/// no matter how other threads are scheduled. `contains` never writes and never retries.
+///
+/// As in [`MsQueue`](crate::ms_queue::MsQueue), unlinked nodes are retired rather than
+/// freed, since a concurrent reader may still hold them, and are freed once no thread can
+/// see them. Elements are therefore read out by cloning, and may be dropped on any thread
+/// after the list itself, hence the `Send + 'static` bound.
+pub struct HarrisList<T> {
+    /// The head sentinel. The first element is in `head.next`.
+    head: *mut Node<T>,
+    /// Frees unlinked nodes once no thread can still be reading them.
+    reclaimer: Reclaimer<Node<T>>,
+}
+
+// SAFETY: the list owns its elements and all shared node state is accessed through atomics.
+unsafe impl<T: Send> Send for HarrisList<T> {}
+// SAFETY: `&HarrisList` hands out `&T` to several threads and lets any thread drop the
+// list's elements, so `T` must be both `Send` and `Sync`.
+unsafe impl<T: Send + Sync> Sync for HarrisList<T> {}
+
+impl<T: Ord + Send + 'static> HarrisList<T> {
+    /// Creates a new, empty `HarrisList`.
+    ///
+    /// # Returns
+    /// - A new empty `HarrisList` instance.
+    pub fn new() -> Self {
+        HarrisList {
+            head: Node::alloc(None, ptr::null_mut()),
+            reclaimer: Reclaimer::new(),
+        }
+    }
+
+    /// Returns `true` if the list had no elements at the moment of the check.
+    pub fn is_empty(&self) -> bool {
+        let mut empty = true;
+        self.for_each(|_| empty = false);
+        empty
+    }
+
+    /// Inserts an element into its sorted position, unless an equal element is present.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `true` if the value was inserted.
+    /// - `false` if an equal value was already in the list.
+    pub fn insert(&self, data: T) -> bool {
+        let node = Node::alloc(Some(data), ptr::null_mut());
+        // SAFETY: `node` is not shared until the CAS below succeeds.
+        let key = unsafe { (*node).data.as_ref().expect("new nodes hold data") };
+        let guard = self.reclaimer.pin();
+        loop {
+            let (prev, curr) = self.search(key, &guard);
+            // SAFETY: `curr` cannot be freed while we are pinned.
+            if !curr.is_null() && unsafe { Self::data(curr) } == key {
+                // SAFETY: `node` was never shared.
+                drop(unsafe { Box::from_raw(node) });
+                return false;
+            }
+            // SAFETY: as above.
+            unsafe { (*node).next.store(curr, Ordering::Relaxed) };
+            if prev
+                .compare_exchange(curr, node, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return true;
+            }
+            spin_loop();
+        }
+    }
+
+    /// Removes the element equal to `data`, if any.
+    ///
+    /// # Parameters
+    /// - `data`: The value to remove.
+    ///
+    /// # Returns
+    /// - `true` if this call removed the value.
+    /// - `false` if the value was not in the list.
+    pub fn remove(&self, data: &T) -> bool {
+        let guard = self.reclaimer.pin();
+        loop {
+            let (prev, curr) = self.search(data, &guard);
+            // SAFETY: `curr` cannot be freed while we are pinned.
+            if curr.is_null() || unsafe { Self::data(curr) } != data {
+                return false;
+            }
+            // SAFETY: as above.
+            let next = unsafe { (*curr).next.load(Ordering::Acquire) };
+            if is_marked(next) {
+                // Another thread is removing it; search again, which helps unlink it.
+                spin_loop();
+                continue;
+            }
+            // SAFETY: as above.
+            let mark = unsafe {
+                (*curr).next.compare_exchange(
+                    next,
+                    marked(next),
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                )
+            };
+            if mark.is_err() {
+                spin_loop();
+                continue;
+            }
+            // Marking was the linearization point; unlinking is cleanup, and a search
+            // finishes it if this CAS loses.
+            if prev
+                .compare_exchange(curr, next, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                // SAFETY: this thread's CAS unlinked `curr`, so nobody else retires it.
+                unsafe { self.reclaimer.retire(curr, &guard) };
+            } else {
+                self.search(data, &guard);
+            }
+            return true;
+        }
+    }
+
+    /// Checks whether a value equal to `data` is in the list, without locking or retrying.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to search for.
+    ///
+    /// # Returns
+    /// - `true` if the value exists in the list.
+    /// - `false` otherwise.
+    pub fn contains(&self, data: &T) -> bool {
+        let _guard = self.reclaimer.pin();
+        // SAFETY: no node reached can be freed while we are pinned.
+        unsafe {
+            let mut curr = unmarked((*self.head).next.load(Ordering::Acquire));
+            while !curr.is_null() && Self::data(curr) < data {
+                curr = unmarked((*curr).next.load(Ordering::Acquire));
+            }
+            !curr.is_null()
+                && Self::data(curr) == data
+                && !is_marked((*curr).next.load(Ordering::Acquire))
+        }
+    }
+
+    /// Returns a clone of every element that is not logically deleted, in ascending
+    /// order. Elements inserted or removed concurrently may or may not be included.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut items = Vec::new();
+        self.for_each(|data| items.push(data.clone()));
+        items
+    }
+
+    /// Calls `f` on every element that is not logically deleted, in ascending order,
+    /// while pinned so that no element can be freed meanwhile.
+    fn for_each(&self, mut f: impl FnMut(&T)) {
+        let _guard = self.reclaimer.pin();
+        // SAFETY: no node reached can be freed while we are pinned.
+        unsafe {
+            let mut curr = unmarked((*self.head).next.load(Ordering::Acquire));
+            while !curr.is_null() {
+                let next = (*curr).next.load(Ordering::Acquire);
+                if !is_marked(next) {
+                    f(Self::data(curr));
+                }
+                curr = unmarked(next);
+            }
+        }
+    }
+
+    /// Returns the first unmarked node whose data is not less than `key`, together with
+    /// the link pointing at it, unlinking any marked nodes on the way.
+    fn search<'g>(&'g self, key: &T, guard: &'g Guard) -> (&'g AtomicPtr<Node<T>>, *mut Node<T>) {
+        'retry: loop {
+            // SAFETY: no node reached can be freed while `guard` is held.
+            unsafe {
+                let mut prev = &(*self.head).next;
+                let mut curr = prev.load(Ordering::Acquire);
+                loop {
+                    if curr.is_null() {
+                        return (prev, curr);
+                    }
+                    let next = (*curr).next.load(Ordering::Acquire);
+                    if is_marked(next) {
+                        let next = unmarked(next);
+                        if prev
+                            .compare_exchange(curr, next, Ordering::AcqRel, Ordering::Acquire)
+                            .is_err()
+                        {
+                            // `prev` changed or was itself marked; start over.
+                            spin_loop();
+                            continue 'retry;
+                        }
+                        self.reclaimer.retire(curr, guard);
+                        curr = next;
+                        continue;
+                    }
+                    if Self::data(curr) >= key {
+                        return (prev, curr);
+                    }
+                    prev = &(*curr).next;
+                    curr = next;
+                }
+            }
+        }
+    }
+
+    /// Returns the data of a non-sentinel node.
+    ///
+    /// # Safety
+    /// `node` must point to a live node other than the head sentinel.
+    unsafe fn data<'a>(node: *mut Node<T>) -> &'a T {
+        (*node)
+            .data
+            .as_ref()
+            .expect("only the sentinel has no data")
+    }
+}
+
+impl<T> Drop for HarrisList<T> {
+    /// Frees the linked nodes, dropping their elements. Retired nodes are freed by the
+    /// reclaimer.
+    fn drop(&mut self) {
+        // SAFETY: `&mut self` guarantees no other thread is using the list. Retired nodes
+        // are no longer linked, so none of them is freed twice.
+        unsafe {
+            let mut current = self.head;
+            while !current.is_null() {
+                let node = Box::from_raw(current);
+                current = unmarked(node.next.load(Ordering::Relaxed));
+            }
+        }
+    }
+}
+
+impl<T: Ord + Send + 'static> Default for HarrisList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Send + Debug + 'static> Debug for HarrisList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut set = f.debug_set();
+        self.for_each(|data| {
+            set.entry(data);
+        });
+        set.finish()
+    }
+}
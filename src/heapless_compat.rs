@@ -0,0 +1,353 @@
+//! `heapless`-style fronts for `StaticLinkedList`.
+//!
+//! Embedded code written against `heapless::Vec` and `heapless::Deque` expects fallible
+//! pushes that hand the rejected element back, a capacity fixed by a const parameter, and
+//! method names like `push`, `pop` and `push_front`. [`Vec`] and [`Deque`] wrap a
+//! `StaticLinkedList<T, N>` behind those signatures, so the list can be dropped into such
+//! code by changing an import. Like the list, neither type ever allocates.
+//!
+//! The list is singly linked, so operations at the back that remove an element (`pop`,
+//! `pop_back`) and positional ones walk the chain.
+
+use core::borrow::Borrow;
+
+use crate::static_linked_list::{Iter, StaticLinkedList};
+use crate::{ListRead, ListWrite};
+
+/// A fixed-capacity vector with the `heapless::Vec` interface, backed by a
+/// `StaticLinkedList`.
+#[derive(Debug, Default)]
+pub struct Vec<T, const N: usize> {
+    /// The backing list.
+    list: StaticLinkedList<T, N>,
+}
+
+impl<T, const N: usize> Vec<T, N> {
+    /// Creates an empty vector. Like `heapless`, this is a `const fn`.
+    pub const fn new() -> Self {
+        Vec {
+            list: StaticLinkedList::new(),
+        }
+    }
+
+    /// Returns the maximum number of elements the vector can hold, which is `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns `true` if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.list.is_full()
+    }
+
+    /// Appends an element to the back.
+    ///
+    /// # Arguments
+    ///
+    /// * item - The element to append.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If the element was appended.
+    /// * Err(T) - The rejected element, if the vector is full.
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        self.list.push_back(item)
+    }
+
+    /// Removes and returns the last element.
+    ///
+    /// # Returns
+    ///
+    /// * Some(T) - The removed element.
+    /// * None - If the vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        Some(self.remove(last))
+    }
+
+    /// Inserts an element at `index`, shifting the elements after it back by one.
+    ///
+    /// # Arguments
+    ///
+    /// * index - The position of the new element.
+    /// * element - The element to insert.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If the element was inserted.
+    /// * Err(T) - The rejected element, if the vector is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) -> Result<(), T> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+        if self.is_full() {
+            return Err(element);
+        }
+        let mut cursor = self.list.cursor_mut();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        // The list has a free slot, so the insertion cannot fail.
+        let _ = cursor.insert_before(element);
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting the elements after it forward
+    /// by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+        let mut cursor = self.list.cursor_mut();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        cursor.remove_current().unwrap()
+    }
+
+    /// Shortens the vector to its first `len` elements. Does nothing if it is not longer
+    /// than that.
+    pub fn truncate(&mut self, len: usize) {
+        let mut cursor = self.list.cursor_mut();
+        for _ in 0..len {
+            cursor.move_next();
+        }
+        while cursor.remove_current().is_some() {}
+    }
+
+    /// Removes every element.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`, in order.
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        let mut cursor = self.list.cursor_mut();
+        while let Some(item) = cursor.current() {
+            if keep(item) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
+            }
+        }
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    /// Returns the first element, or `None` if the vector is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    /// Returns the last element, or `None` if the vector is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.list.iter().last()
+    }
+
+    /// Returns `true` if the vector holds an element equal to `x`.
+    pub fn contains<Q>(&self, x: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.list.find(x)
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        self.list.iter()
+    }
+
+    /// Returns a reference to the backing list.
+    pub fn inner(&self) -> &StaticLinkedList<T, N> {
+        &self.list
+    }
+
+    /// Consumes the vector and returns the backing list.
+    pub fn into_inner(self) -> StaticLinkedList<T, N> {
+        self.list
+    }
+}
+
+impl<T, const N: usize> From<StaticLinkedList<T, N>> for Vec<T, N> {
+    /// Wraps a list, keeping its order: the head becomes index 0.
+    fn from(list: StaticLinkedList<T, N>) -> Self {
+        Vec { list }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Vec<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A fixed-capacity double-ended queue with the `heapless::Deque` interface, backed by a
+/// `StaticLinkedList`.
+#[derive(Debug, Default)]
+pub struct Deque<T, const N: usize> {
+    /// The backing list, front first.
+    list: StaticLinkedList<T, N>,
+}
+
+impl<T, const N: usize> Deque<T, N> {
+    /// Creates an empty queue. Like `heapless`, this is a `const fn`.
+    pub const fn new() -> Self {
+        Deque {
+            list: StaticLinkedList::new(),
+        }
+    }
+
+    /// Returns the maximum number of elements the queue can hold, which is `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns `true` if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.list.is_full()
+    }
+
+    /// Appends an element to the back.
+    ///
+    /// # Arguments
+    ///
+    /// * item - The element to append.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If the element was appended.
+    /// * Err(T) - The rejected element, if the queue is full.
+    pub fn push_back(&mut self, item: T) -> Result<(), T> {
+        self.list.push_back(item)
+    }
+
+    /// Prepends an element to the front.
+    ///
+    /// # Arguments
+    ///
+    /// * item - The element to prepend.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If the element was prepended.
+    /// * Err(T) - The rejected element, if the queue is full.
+    pub fn push_front(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        // The list has a free slot, so the insertion cannot fail.
+        let _ = self.list.cursor_mut().insert_before(item);
+        Ok(())
+    }
+
+    /// Removes and returns the first element, or `None` if the queue is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Removes and returns the last element, or `None` if the queue is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        let mut cursor = self.list.cursor_mut();
+        for _ in 0..last {
+            cursor.move_next();
+        }
+        cursor.remove_current()
+    }
+
+    /// Returns the first element, or `None` if the queue is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if the queue is empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.get_mut(0)
+    }
+
+    /// Returns the last element, or `None` if the queue is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.list.iter().last()
+    }
+
+    /// Removes every element.
+    pub fn clear(&mut self) {
+        while self.list.pop_front().is_some() {}
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        self.list.iter()
+    }
+
+    /// Returns a reference to the backing list.
+    pub fn inner(&self) -> &StaticLinkedList<T, N> {
+        &self.list
+    }
+
+    /// Consumes the queue and returns the backing list.
+    pub fn into_inner(self) -> StaticLinkedList<T, N> {
+        self.list
+    }
+}
+
+impl<T, const N: usize> From<StaticLinkedList<T, N>> for Deque<T, N> {
+    /// Wraps a list, keeping its order: the head becomes the front.
+    fn from(list: StaticLinkedList<T, N>) -> Self {
+        Deque { list }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Deque<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,30 @@
+//! `tracing` instrumentation, enabled by the `tracing` feature.
+//!
+//! `DynamicLinkedList` and `StaticLinkedList` emit a `TRACE` event for each insertion and
+//! deletion, with the index it applies to, and `DEBUG` spans and events around the
+//! operations that move or allocate many nodes: compaction, layout optimization, pool
+//! reservation and release. The static list also reports its length, which it keeps; the
+//! dynamic list would have to walk itself to count, so instead it reports how many nodes
+//! each lookup by position walked, which is where an unexpected O(n) shows up.
+//!
+//! Without the feature the macros below expand to nothing, so the call sites need no
+//! `#[cfg]` of their own and their field expressions are never evaluated.
+
+/// Emits a `tracing` event at the given level, e.g. `event!(TRACE, index, "insert")`.
+macro_rules! event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::event!(tracing::Level::$level, $($arg)+);
+    };
+}
+
+/// Enters a `tracing` span at the given level, e.g. `span!(DEBUG, "compact", len);`, for
+/// the rest of the enclosing block.
+macro_rules! span {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _entered = tracing::span!(tracing::Level::$level, $($arg)+).entered();
+    };
+}
+
+pub(crate) use {event, span};
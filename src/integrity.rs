@@ -0,0 +1,198 @@
+//! Structural integrity checks for the lists that link their nodes by slot index.
+//!
+//! `validate()` on `StaticLinkedList` and `SlabLinkedList` walks the chain and the free
+//! list and reports every broken invariant it finds as a [`Violation`], gathered in an
+//! [`IntegrityReport`]. A healthy list never has any; a violation means a bug in the list
+//! or in `unsafe` code that reached into it.
+//!
+//! With the `strict-invariants` feature, debug builds run the same checks after every
+//! operation that links or unlinks a node, and panic at the first violation, so
+//! corruption is caught by the operation that caused it. Each check walks the whole
+//! storage, which makes those operations O(n); release builds skip them.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A broken invariant of a slot-based list. Slots are the indices into the list's storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Violation {
+    /// A link points past the end of the storage.
+    LinkOutOfRange {
+        /// The slot holding the link, or `None` for the head link.
+        from: Option<usize>,
+        /// The slot the link points at.
+        to: usize,
+    },
+    /// The chain comes back to a slot it already passed, so it never ends.
+    Cycle {
+        /// The first slot visited twice.
+        slot: usize,
+    },
+    /// The chain reaches a slot that holds no element.
+    EmptySlotLinked {
+        /// The empty slot.
+        slot: usize,
+    },
+    /// A node's back link does not point at the node before it in the chain.
+    BrokenBackLink {
+        /// The node with the wrong back link.
+        slot: usize,
+        /// The slot its back link points at.
+        prev: Option<usize>,
+        /// The slot before it in the chain.
+        expected: Option<usize>,
+    },
+    /// The tail pointer is not the last node of the chain.
+    TailMismatch {
+        /// The slot the tail pointer holds.
+        tail: Option<usize>,
+        /// The last slot of the chain.
+        last: Option<usize>,
+    },
+    /// The free list holds a slot past the end of the storage.
+    FreeOutOfRange {
+        /// The slot in the free list.
+        slot: usize,
+    },
+    /// A slot is both in the chain and in the free list.
+    LinkedAndFree {
+        /// The slot in both.
+        slot: usize,
+    },
+    /// A slot appears in the free list twice.
+    FreedTwice {
+        /// The repeated slot.
+        slot: usize,
+    },
+    /// A slot in the free list holds an element that is not in the chain.
+    FreeSlotOccupied {
+        /// The occupied slot.
+        slot: usize,
+    },
+    /// A slot is in neither the chain nor the free list, so it can never be used again.
+    Leaked {
+        /// The lost slot.
+        slot: usize,
+    },
+    /// The number of nodes in the chain differs from the length the list reports.
+    LengthMismatch {
+        /// The number of nodes reached from the head.
+        chain: usize,
+        /// The length the list reports.
+        len: usize,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::LinkOutOfRange {
+                from: Some(from),
+                to,
+            } => {
+                write!(
+                    f,
+                    "slot {from} links to slot {to}, past the end of the storage"
+                )
+            }
+            Violation::LinkOutOfRange { from: None, to } => {
+                write!(
+                    f,
+                    "the head links to slot {to}, past the end of the storage"
+                )
+            }
+            Violation::Cycle { slot } => write!(f, "the chain passes slot {slot} twice"),
+            Violation::EmptySlotLinked { slot } => {
+                write!(f, "the chain reaches slot {slot}, which holds no element")
+            }
+            Violation::BrokenBackLink {
+                slot,
+                prev,
+                expected,
+            } => write!(
+                f,
+                "slot {slot} links back to {prev:?}, but follows {expected:?} in the chain"
+            ),
+            Violation::TailMismatch { tail, last } => {
+                write!(f, "the tail is {tail:?}, but the chain ends at {last:?}")
+            }
+            Violation::FreeOutOfRange { slot } => {
+                write!(
+                    f,
+                    "the free list holds slot {slot}, past the end of the storage"
+                )
+            }
+            Violation::LinkedAndFree { slot } => {
+                write!(f, "slot {slot} is both in the chain and in the free list")
+            }
+            Violation::FreedTwice { slot } => {
+                write!(f, "slot {slot} is in the free list twice")
+            }
+            Violation::FreeSlotOccupied { slot } => {
+                write!(f, "slot {slot} is in the free list but holds an element")
+            }
+            Violation::Leaked { slot } => {
+                write!(f, "slot {slot} is in neither the chain nor the free list")
+            }
+            Violation::LengthMismatch { chain, len } => {
+                write!(f, "the chain has {chain} nodes, but the length is {len}")
+            }
+        }
+    }
+}
+
+/// What a slot was found to be while checking a list.
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "strict-invariants", debug_assertions)
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SlotState {
+    /// Neither in the chain nor in the free list, so far.
+    Unseen,
+    /// In the chain.
+    Linked,
+    /// In the free list.
+    Free,
+}
+
+/// The result of `validate()`: what the check walked and the invariants it found broken.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// The number of nodes reached by following the chain from the head.
+    pub chain_len: usize,
+    /// The number of slots reached in the free list.
+    pub free_len: usize,
+    /// The broken invariants, in the order they were found.
+    pub violations: Vec<Violation>,
+}
+
+#[cfg(feature = "alloc")]
+impl IntegrityReport {
+    /// Returns `true` if no invariant is broken.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for IntegrityReport {
+    /// Writes the number of linked and free slots and of violations, followed by one line
+    /// per violation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} linked, {} free, {} violations",
+            self.chain_len,
+            self.free_len,
+            self.violations.len()
+        )?;
+        for violation in &self.violations {
+            write!(f, "\n- {violation}")?;
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,379 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod allocator;
+#[cfg(feature = "bumpalo")]
+pub mod arena_linked_list;
+#[cfg(feature = "alloc")]
+pub mod bounded_list;
+#[cfg(feature = "alloc")]
+pub mod chunks;
+#[cfg(feature = "alloc")]
+pub mod circular_doubly_linked_list;
+#[cfg(feature = "alloc")]
+pub mod circular_linked_list;
+#[cfg(feature = "std")]
+pub mod concurrent_linked_list;
+pub mod conformance;
+pub mod counted;
+#[cfg(feature = "defmt")]
+mod defmt_format;
+#[cfg(feature = "alloc")]
+pub mod deque;
+#[cfg(feature = "alloc")]
+pub mod doubly_linked_list;
+#[cfg(feature = "alloc")]
 pub mod dynamic_linked_list;
+pub mod error;
+mod extend;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod footprint;
+#[cfg(feature = "std")]
+pub mod hand_over_hand_list;
+#[cfg(feature = "std")]
+pub mod harris_list;
+pub mod heapless_compat;
+mod instrument;
+pub mod integrity;
+#[cfg(feature = "std")]
+pub mod linked_hash_map;
+#[cfg(feature = "alloc")]
+pub mod list_builder;
+#[cfg(feature = "std")]
+pub mod list_channel;
+pub mod list_view;
+mod macros;
+#[cfg(feature = "std")]
+pub mod ms_queue;
+#[cfg(feature = "alloc")]
+pub mod multilevel_list;
+#[cfg(feature = "alloc")]
+pub mod observed;
+#[cfg(feature = "rayon")]
+pub mod par_iter;
+#[cfg(feature = "alloc")]
+pub mod persistent_list;
+#[cfg(feature = "alloc")]
+pub mod priority_list;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "alloc")]
+pub mod queue;
+#[cfg(feature = "alloc")]
+pub mod rc_linked_list;
+#[cfg(feature = "std")]
+mod reclaim;
+#[cfg(feature = "alloc")]
+mod render;
+#[cfg(feature = "alloc")]
+pub mod rope;
+#[cfg(feature = "alloc")]
+pub mod skip_list;
+#[cfg(feature = "alloc")]
+pub mod slab_linked_list;
+mod slice_eq;
+#[cfg(feature = "alloc")]
+mod slots;
+#[cfg(feature = "alloc")]
+pub mod small_list;
+#[cfg(feature = "alloc")]
+pub mod sorted_linked_list;
+#[cfg(feature = "alloc")]
+pub mod stack;
 pub mod static_linked_list;
+pub mod stats;
+#[cfg(feature = "alloc")]
+pub mod std_compat;
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(feature = "std")]
+mod sync;
+#[cfg(feature = "alloc")]
+pub mod unrolled_linked_list;
+#[cfg(feature = "alloc")]
+pub mod versioned_list;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "alloc")]
+pub mod xor_linked_list;
 
-/// A trait defining the interface for all linked list implementations.
-pub trait LinkedListTrait<T> {
-    fn insert(&mut self, data: T);
-    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), String>;
-    fn delete_element(&mut self, data: T) -> bool;
-    fn delete_at_index(&mut self, index: usize) -> Result<(), String>;
-    fn update_element(&mut self, old_data: T, new_data: T) -> bool;
-    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), String>;
-    fn find(&self, data: &T) -> bool;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+use core::ops::RangeBounds;
+
+pub use crate::error::LinkedListError;
+pub use crate::stats::ListStats;
+
+/// The read-only half of the list interface.
+///
+/// The value-based lookups are generic over a borrowed form `Q` of the element, so a list
+/// of `String`s can be searched with a `&str`. They are built on
+/// [`position_by`](Self::position_by) and require `Self: Sized`, which keeps the trait
+/// object safe: `&dyn ListRead<T>` can look into any list. On a trait object, call them
+/// through the `Box` or reference, which implement the trait as well.
+pub trait ListRead<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize;
+    /// Returns `true` if the list contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the index of the first element for which `predicate` returns `true`. The
+    /// default probes `get` for each index, which walks a plain linked list once per
+    /// element; lists override it with a single walk.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        (0..self.len()).find(|&index| self.get(index).is_some_and(&mut *predicate))
+    }
+    /// Returns the index of the first element equal to `data`, using `position_by`.
+    fn position<Q>(&self, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+        Self: Sized,
+    {
+        self.position_by(&mut |item| item.borrow() == data)
+    }
+    /// Checks whether a given value exists in the list, using `position_by`.
+    fn find<Q>(&self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+        Self: Sized,
+    {
+        self.position(data).is_some()
+    }
     fn get(&self, index: usize) -> Option<&T>;
 }
+
+/// The mutating half of the list interface.
+///
+/// Like [`ListRead`], the trait is object safe, so a `Box<dyn ListWrite<T>>` can hold any
+/// implementation and be swapped for another at runtime. The value-based `delete_element`
+/// and `update_element` find the element with `position_by` and then go through
+/// `delete_at_index` and `update_element_at_index`, so an adapter that intercepts the
+/// index-based operations sees every change.
+pub trait ListWrite<T>: ListRead<T> {
+    /// Inserts an element at the end of the list. A bounded list that is full does not
+    /// take the element; use `try_insert` to find out.
+    fn insert(&mut self, data: T);
+    /// Inserts an element at the end of the list, reporting an error if the list cannot
+    /// hold it. The default forwards to `insert`, which suits lists without a capacity;
+    /// bounded lists override it.
+    fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.insert(data);
+        Ok(())
+    }
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError>;
+    /// Inserts the elements of `items` in order, the first one at `index`. The default
+    /// calls `insert_at_index` once per element, which walks a plain linked list from the
+    /// head every time; lists override it to walk to `index` once. If a bounded list fills
+    /// up, the elements inserted so far stay in the list and the error is returned.
+    fn insert_many_at<I>(&mut self, index: usize, items: I) -> Result<(), LinkedListError>
+    where
+        I: IntoIterator<Item = T>,
+        Self: Sized,
+    {
+        if index > self.len() {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        for (offset, item) in items.into_iter().enumerate() {
+            self.insert_at_index(index + offset, item)?;
+        }
+        Ok(())
+    }
+    /// Deletes the first element equal to `data`. Returns `false` if no element matched.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+        Self: Sized,
+    {
+        match self.position(data) {
+            Some(index) => self.delete_at_index(index).is_ok(),
+            None => false,
+        }
+    }
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError>;
+    /// Returns a mutable reference to the element at `index`, or `None` if the index is
+    /// out of bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+    /// Replaces the first element equal to `old_data` with `new_data`. Returns `false` if
+    /// no element matched.
+    fn update_element<Q>(&mut self, old_data: &Q, new_data: T) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+        Self: Sized,
+    {
+        match self.position(old_data) {
+            Some(index) => self.update_element_at_index(index, new_data).is_ok(),
+            None => false,
+        }
+    }
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError>;
+}
+
+/// Implements `ListRead` for pointers to a list by forwarding to the list, so the generic
+/// lookups can be called on a `Box<dyn ListRead<T>>` or `&dyn ListRead<T>`.
+macro_rules! forward_list_read {
+    ($($pointer:ty),*) => {$(
+        impl<T, L: ListRead<T> + ?Sized> ListRead<T> for $pointer {
+            fn len(&self) -> usize {
+                (**self).len()
+            }
+
+            fn is_empty(&self) -> bool {
+                (**self).is_empty()
+            }
+
+            fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+                (**self).position_by(predicate)
+            }
+
+            fn get(&self, index: usize) -> Option<&T> {
+                (**self).get(index)
+            }
+        }
+    )*};
+}
+
+/// Implements `ListWrite` for owning or exclusive pointers to a list by forwarding to the
+/// list.
+macro_rules! forward_list_write {
+    ($($pointer:ty),*) => {$(
+        impl<T, L: ListWrite<T> + ?Sized> ListWrite<T> for $pointer {
+            fn insert(&mut self, data: T) {
+                (**self).insert(data)
+            }
+
+            fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+                (**self).try_insert(data)
+            }
+
+            fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+                (**self).insert_at_index(index, data)
+            }
+
+            fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+                (**self).delete_at_index(index)
+            }
+
+            fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+                (**self).get_mut(index)
+            }
+
+            fn update_element_at_index(
+                &mut self,
+                index: usize,
+                data: T,
+            ) -> Result<(), LinkedListError> {
+                (**self).update_element_at_index(index, data)
+            }
+        }
+    )*};
+}
+
+forward_list_read!(&L, &mut L);
+forward_list_write!(&mut L);
+#[cfg(feature = "alloc")]
+forward_list_read!(Box<L>);
+#[cfg(feature = "alloc")]
+forward_list_write!(Box<L>);
+
+/// A trait defining the interface for all linked list implementations: reading, writing
+/// and iterating.
+///
+/// The iterator types are generic associated types, so generic code over the trait can
+/// walk any list without naming its concrete iterator. That makes this trait not object
+/// safe; use [`ListRead`] and [`ListWrite`] for trait objects. Bring them into scope as
+/// well to call the reading and writing methods.
+pub trait LinkedListTrait<T>: ListWrite<T> {
+    /// The iterator over shared references to the elements, front to back.
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// The iterator over mutable references to the elements, front to back.
+    type IterMut<'a>: Iterator<Item = &'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+
+    /// Returns a borrowed view of the elements in `range`, which iterates, indexes and
+    /// searches only inside it. See [`ListView`](list_view::ListView).
+    ///
+    /// # Parameters
+    /// - `range`: The positions to view, e.g. `1..3` or `2..`.
+    ///
+    /// # Returns
+    /// - `Ok(ListView)` over the range.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the range does not fit in the list.
+    fn view<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> Result<list_view::ListView<'_, T, Self>, LinkedListError>
+    where
+        Self: Sized,
+    {
+        list_view::ListView::new(self, range)
+    }
+
+    /// Returns an iterator over the elements in groups of `size`, front to back, each
+    /// collected into a `Vec` of references. The last group is shorter if the length does
+    /// not divide evenly. See [`Chunks`](chunks::Chunks).
+    ///
+    /// # Parameters
+    /// - `size`: The number of elements in each group.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn chunks(&self, size: usize) -> chunks::Chunks<Self::Iter<'_>> {
+        chunks::Chunks::new(self.iter(), size)
+    }
+
+    /// Returns an iterator over every run of `size` adjacent elements, overlapping and
+    /// front to back, each collected into a `Vec` of references, e.g. for moving
+    /// averages. A list shorter than `size` has no windows. See
+    /// [`Windows`](chunks::Windows).
+    ///
+    /// # Parameters
+    /// - `size`: The number of elements in each window.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn windows(&self, size: usize) -> chunks::Windows<Self::Iter<'_>> {
+        chunks::Windows::new(self.iter(), size)
+    }
+
+    /// Returns an iterator over the maximal runs of adjacent elements for which
+    /// `predicate` holds between each element and the next, front to back, each collected
+    /// into a `Vec` of references. See [`ChunkBy`](chunks::ChunkBy).
+    ///
+    /// # Parameters
+    /// - `predicate`: The test between two adjacent elements, e.g. `|a, b| a <= b` for
+    ///   the ascending runs.
+    #[cfg(feature = "alloc")]
+    fn chunk_by<F>(&self, predicate: F) -> chunks::ChunkBy<'_, T, Self::Iter<'_>, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        chunks::ChunkBy::new(self.iter(), predicate)
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+
+use crate::slab_linked_list::{Key, SlabLinkedList};
+
+/// `LinkedHashMap` is a hash map that remembers the order in which keys were inserted.
+///
+/// Entries are threaded through a [`SlabLinkedList`], and the hash table maps each key to
+/// the stable slab key of its entry. Lookups, insertion, removal, [`move_to_back`] and
+/// [`pop_front`] are all O(1) on average, and iteration visits entries oldest first.
+///
+/// Re-inserting an existing key replaces its value but keeps its position; use
+/// [`move_to_back`] to mark an entry as most recently used, e.g. for an LRU cache.
+///
+/// [`move_to_back`]: LinkedHashMap::move_to_back
+/// [`pop_front`]: LinkedHashMap::pop_front
+pub struct LinkedHashMap<K, V> {
+    /// Maps each key to its entry in `entries`.
+    index: HashMap<K, Key>,
+    /// The entries in insertion order.
+    entries: SlabLinkedList<(K, V)>,
+}
+
+impl<K: Hash + Eq + Clone, V> LinkedHashMap<K, V> {
+    /// Creates a new, empty `LinkedHashMap`.
+    ///
+    /// # Returns
+    /// - A new empty `LinkedHashMap` instance.
+    pub fn new() -> Self {
+        LinkedHashMap {
+            index: HashMap::new(),
+            entries: SlabLinkedList::new(),
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a key-value pair at the back, or replaces the value of an existing key in place.
+    ///
+    /// # Parameters
+    /// - `key`: The key to insert.
+    /// - `value`: The value to associate with `key`.
+    ///
+    /// # Returns
+    /// - `Some(V)` with the previous value if the key was already present.
+    /// - `None` otherwise.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&slot) = self.index.get(&key) {
+            let entry = self.entries.get_mut(slot).expect("indexed entry must be live");
+            return Some(std::mem::replace(&mut entry.1, value));
+        }
+        let slot = self.entries.push_back((key.clone(), value));
+        self.index.insert(key, slot);
+        None
+    }
+
+    /// Returns a reference to the value associated with `key`.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let slot = *self.index.get(key)?;
+        self.entries.get(slot).map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value associated with `key`.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let slot = *self.index.get(key)?;
+        self.entries.get_mut(slot).map(|(_, value)| value)
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// Removes `key` from the map.
+    ///
+    /// # Returns
+    /// - `Some(V)` with the removed value if the key was present.
+    /// - `None` otherwise.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.index.remove(key)?;
+        self.entries.remove(slot).map(|(_, value)| value)
+    }
+
+    /// Moves the entry for `key` to the back, making it the most recently inserted.
+    ///
+    /// # Returns
+    /// - `true` if the key was present.
+    /// - `false` otherwise.
+    pub fn move_to_back(&mut self, key: &K) -> bool {
+        let Some(slot) = self.index.get_mut(key) else {
+            return false;
+        };
+        let entry = self.entries.remove(*slot).expect("indexed entry must be live");
+        *slot = self.entries.push_back(entry);
+        true
+    }
+
+    /// Returns the oldest entry, or `None` if the map is empty.
+    pub fn front(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    /// Removes and returns the oldest entry.
+    ///
+    /// # Returns
+    /// - `Some((K, V))` if the map was not empty.
+    /// - `None` otherwise.
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let slot = self.entries.key_at(0)?;
+        let (key, value) = self.entries.remove(slot).expect("front entry must be live");
+        self.index.remove(&key);
+        Some((key, value))
+    }
+
+    /// Returns an iterator over the entries in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.entries.iter().map(|(_, (key, value))| (key, value))
+    }
+
+    /// Returns an iterator over the keys in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values in insertion order of their keys.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Default for LinkedHashMap<K, V> {
+    /// Provides a default (empty) instance of the map using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone + Debug, V: Debug> Debug for LinkedHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// `LinkedHashSet` is a hash set that remembers the order in which values were inserted.
+///
+/// It is a [`LinkedHashMap`] with `()` values and shares its O(1) operations and ordering.
+pub struct LinkedHashSet<T> {
+    /// The underlying map from values to nothing.
+    map: LinkedHashMap<T, ()>,
+}
+
+impl<T: Hash + Eq + Clone> LinkedHashSet<T> {
+    /// Creates a new, empty `LinkedHashSet`.
+    ///
+    /// # Returns
+    /// - A new empty `LinkedHashSet` instance.
+    pub fn new() -> Self {
+        LinkedHashSet {
+            map: LinkedHashMap::new(),
+        }
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Adds a value at the back of the set. An existing value keeps its position.
+    ///
+    /// # Returns
+    /// - `true` if the value was newly inserted.
+    /// - `false` if it was already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// Returns `true` if the set contains `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.map.contains_key(value)
+    }
+
+    /// Removes `value` from the set, returning `true` if it was present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// Moves `value` to the back, returning `true` if it was present.
+    pub fn move_to_back(&mut self, value: &T) -> bool {
+        self.map.move_to_back(value)
+    }
+
+    /// Returns the oldest value, or `None` if the set is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.map.front().map(|(value, _)| value)
+    }
+
+    /// Removes and returns the oldest value, or `None` if the set is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.map.pop_front().map(|(value, _)| value)
+    }
+
+    /// Returns an iterator over the values in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.map.keys()
+    }
+}
+
+impl<T: Hash + Eq + Clone> Default for LinkedHashSet<T> {
+    /// Provides a default (empty) instance of the set using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq + Clone + Debug> Debug for LinkedHashSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
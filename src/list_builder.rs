@@ -0,0 +1,99 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::bounded_list::{BoundedList, OverflowPolicy};
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{LinkedListError, ListWrite};
+
+/// `ListBuilder` configures and creates a [`BoundedList`]: its initial elements, an
+/// optional maximum length, the [`OverflowPolicy`] applied past that length, and the
+/// backing store.
+///
+/// The backing store defaults to `DynamicLinkedList<T>`; any `ListWrite`
+/// implementation with a `Default` can be chosen with [`backing_store`](Self::backing_store),
+/// so new list types plug in without a new entry point.
+#[derive(Debug)]
+pub struct ListBuilder<T, L = DynamicLinkedList<T>> {
+    /// The elements to insert, front to back.
+    elements: Vec<T>,
+    /// The maximum number of elements, or `None` for no bound.
+    max_len: Option<usize>,
+    /// What to do with an insertion past `max_len`.
+    policy: OverflowPolicy,
+    _marker: PhantomData<L>,
+}
+
+impl<T> ListBuilder<T> {
+    /// Creates a builder for an empty, unbounded `DynamicLinkedList`.
+    pub fn new() -> Self {
+        ListBuilder {
+            elements: Vec::new(),
+            max_len: None,
+            policy: OverflowPolicy::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for ListBuilder<T> {
+    /// Provides a default builder using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, L> ListBuilder<T, L> {
+    /// Adds initial elements, inserted front to back after any added before.
+    ///
+    /// # Parameters
+    /// - `elements`: The values to insert.
+    pub fn elements(mut self, elements: impl IntoIterator<Item = T>) -> Self {
+        self.elements.extend(elements);
+        self
+    }
+
+    /// Bounds the list to at most `max_len` elements.
+    ///
+    /// # Parameters
+    /// - `max_len`: The maximum number of elements.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Sets what happens to insertions past the maximum length. Defaults to `Reject`.
+    ///
+    /// # Parameters
+    /// - `policy`: The overflow policy.
+    pub fn overflow(mut self, policy: OverflowPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Chooses the list type that stores the elements, e.g. `StaticLinkedList<T, 8>`.
+    pub fn backing_store<M>(self) -> ListBuilder<T, M> {
+        ListBuilder {
+            elements: self.elements,
+            max_len: self.max_len,
+            policy: self.policy,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates the list and inserts the initial elements under the overflow policy.
+    ///
+    /// # Returns
+    /// - `Ok(BoundedList<T, L>)` on success.
+    /// - `Err(LinkedListError)` if an initial element was rejected, by the `Reject` policy or
+    ///   by the backing store.
+    pub fn build(self) -> Result<BoundedList<T, L>, LinkedListError>
+    where
+        L: ListWrite<T> + Default,
+    {
+        let mut list = BoundedList::new(L::default(), self.max_len, self.policy);
+        for data in self.elements {
+            list.insert_at_index(list.len(), data)?;
+        }
+        Ok(list)
+    }
+}
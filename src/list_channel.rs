@@ -0,0 +1,77 @@
+use std::panic;
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+
+/// Creates a multi-producer channel whose elements are collected into a
+/// `DynamicLinkedList` by a dedicated collector thread.
+///
+/// Worker threads send elements through clones of the returned [`ListSender`]; nothing is
+/// locked on the sending side beyond what the channel itself does. The collector links
+/// each element in O(1) as it arrives, and [`ListCollector::finish`] hands back the
+/// finished list in arrival order once every sender is gone.
+///
+/// # Returns
+/// - The sending handle and the collector to finish the list with.
+pub fn channel<T: Send + 'static>() -> (ListSender<T>, ListCollector<T>) {
+    let (sender, receiver) = mpsc::channel();
+    let collector = thread::spawn(move || {
+        // Building back to front keeps every link O(1) without a tail pointer.
+        let mut list = DynamicLinkedList::new();
+        for data in receiver {
+            list.push_front(data);
+        }
+        list.reverse();
+        list
+    });
+    (ListSender { sender }, ListCollector { collector })
+}
+
+/// The sending half of a [`channel`]. Clone it to give each worker its own handle.
+#[derive(Debug)]
+pub struct ListSender<T> {
+    /// The channel to the collector thread.
+    sender: Sender<T>,
+}
+
+impl<T> ListSender<T> {
+    /// Sends an element to be appended to the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to append.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(T)` with the element if the collector thread has stopped.
+    pub fn send(&self, data: T) -> Result<(), T> {
+        self.sender.send(data).map_err(|error| error.0)
+    }
+}
+
+impl<T> Clone for ListSender<T> {
+    fn clone(&self) -> Self {
+        ListSender {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// The collecting half of a [`channel`], owning the collector thread.
+#[derive(Debug)]
+pub struct ListCollector<T> {
+    /// The thread building the list.
+    collector: JoinHandle<DynamicLinkedList<T>>,
+}
+
+impl<T> ListCollector<T> {
+    /// Waits until every [`ListSender`] has been dropped and returns the collected list.
+    ///
+    /// # Returns
+    /// - The list of every element sent, in the order the collector received them.
+    pub fn finish(self) -> DynamicLinkedList<T> {
+        self.collector
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+}
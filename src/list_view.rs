@@ -0,0 +1,153 @@
+use core::fmt::{self, Debug};
+use core::iter::{Skip, Take};
+use core::marker::PhantomData;
+use core::ops::{Bound, RangeBounds};
+
+use crate::{LinkedListError, LinkedListTrait, ListRead};
+
+/// The iterator over the elements of a `ListView`, front to back.
+pub type Iter<'a, T, L> = Take<Skip<<L as LinkedListTrait<T>>::Iter<'a>>>;
+
+/// `ListView` is a borrowed window onto a contiguous range of a list, made with
+/// [`LinkedListTrait::view`].
+///
+/// Indices into the view start at the first element of the range, and iteration, `get`
+/// and the searches only see the elements inside it, so an algorithm written against
+/// `ListRead` can work on part of a list without splitting or copying it. The view holds
+/// a shared borrow, so the list cannot change while it exists.
+pub struct ListView<'a, T, L: ?Sized> {
+    /// The viewed list.
+    list: &'a L,
+    /// The index in `list` of the first element of the view.
+    start: usize,
+    /// The number of elements in the view.
+    len: usize,
+    /// Tells the compiler the view reads elements of type `T`.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T, L: LinkedListTrait<T>> ListView<'a, T, L> {
+    /// Creates a view of the elements of `list` in `range`.
+    ///
+    /// # Parameters
+    /// - `list`: The list to view.
+    /// - `range`: The positions to view, e.g. `1..3` or `2..`.
+    ///
+    /// # Returns
+    /// - `Ok(ListView)` over the range.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the range does not fit in the list.
+    pub fn new<R: RangeBounds<usize>>(list: &'a L, range: R) -> Result<Self, LinkedListError> {
+        let len = list.len();
+        // A bound of `usize::MAX` has no position after it.
+        let overflow = LinkedListError::IndexOutOfBounds {
+            index: usize::MAX,
+            len,
+        };
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).ok_or(overflow)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).ok_or(overflow)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        if start > end || end > len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index: start.max(end),
+                len,
+            });
+        }
+        Ok(ListView {
+            list,
+            start,
+            len: end - start,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the index in the list of the first element of the view.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the viewed list.
+    pub fn list(&self) -> &'a L {
+        self.list
+    }
+
+    /// Returns an iterator over the elements of the view, front to back. Reaching the
+    /// start of the view walks the list once.
+    pub fn iter(&self) -> Iter<'a, T, L> {
+        self.list.iter().skip(self.start).take(self.len)
+    }
+}
+
+impl<T, L: LinkedListTrait<T>> ListRead<T> for ListView<'_, T, L> {
+    /// Returns the number of elements in the view.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index, relative to the view, of the first element in the view matching
+    /// `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element of the view, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element of the view matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index of the view.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element, relative to the start of the view.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is inside the view.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        self.list.get(self.start + index)
+    }
+}
+
+impl<T, L: ?Sized> Clone for ListView<'_, T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, L: ?Sized> Copy for ListView<'_, T, L> {}
+
+impl<'a, T: 'a, L: LinkedListTrait<T>> IntoIterator for ListView<'a, T, L> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Debug, L: LinkedListTrait<T>> Debug for ListView<'_, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
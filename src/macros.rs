@@ -0,0 +1,60 @@
+/// Builds a `DynamicLinkedList` from a comma-separated list of elements, front to back.
+///
+/// `dynamic_list![1, 2, 3]` is the list `1 -> 2 -> 3`; `dynamic_list![]` is an empty list.
+#[macro_export]
+macro_rules! dynamic_list {
+    ($($element:expr),* $(,)?) => {{
+        let mut list = $crate::dynamic_linked_list::DynamicLinkedList::new();
+        $($crate::ListWrite::insert(&mut list, $element);)*
+        list
+    }};
+}
+
+/// Builds a `StaticLinkedList<T, N>` from a comma-separated list of elements, front to back.
+///
+/// `static_list![i32; 5 => 1, 2, 3]` is a list of capacity 5 holding `1 -> 2 -> 3`, and
+/// `static_list![i32; 5]` is an empty one. Passing more than `N` elements is a compile-time
+/// error rather than a silently dropped insert.
+#[macro_export]
+macro_rules! static_list {
+    ($t:ty; $n:expr) => {
+        $crate::static_linked_list::StaticLinkedList::<$t, $n>::new()
+    };
+    ($t:ty; $n:expr => $($element:expr),* $(,)?) => {{
+        const {
+            assert!(
+                [$(stringify!($element)),*].len() <= $n,
+                "static_list! was given more elements than the list's capacity"
+            )
+        };
+        let mut list = $crate::static_linked_list::StaticLinkedList::<$t, $n>::new();
+        $($crate::ListWrite::insert(&mut list, $element);)*
+        list
+    }};
+}
+
+/// Writes one `#[test]` per check in [`conformance`](crate::conformance) for a list of
+/// `i32`s, named after the check.
+///
+/// `linked_list_trait_tests!(MyList<i32>)` builds each list with `Default`, and
+/// `linked_list_trait_tests!(MyList<i32>, MyList::with_capacity(8))` with the given
+/// expression. Invoke it once per module; wrap each list type in a module of its own.
+#[macro_export]
+macro_rules! linked_list_trait_tests {
+    ($list:ty) => {
+        $crate::linked_list_trait_tests!($list, <$list as ::core::default::Default>::default());
+    };
+    ($list:ty, $new:expr) => {
+        $crate::linked_list_trait_tests!(@tests $list, $new;
+            empty_list insert_and_get insert_at_index delete_at_index delete_element update
+            position_and_find iter_mut drain_and_refill);
+    };
+    (@tests $list:ty, $new:expr; $($check:ident)*) => {
+        $(
+            #[test]
+            fn $check() {
+                $crate::conformance::$check(|| -> $list { $new });
+            }
+        )*
+    };
+}
@@ -1,21 +1,265 @@
+//! An interactive shell for trying out the lists.
+//!
+//! Each line is a command such as `insert 5`, `delete_at 2` or `print`; `help` lists them
+//! all. The commands go through the object-safe `ListWrite` trait, so `switch static 10`
+//! and `switch dynamic` swap the implementation underneath while keeping the elements.
+//!
+//! `linked_list_impls bench ...` runs timed workloads instead and prints CSV; see the
+//! `bench` module for the options.
+
+mod bench;
+
+use std::io::{self, BufRead, Write};
+use std::str::{FromStr, SplitWhitespace};
+use std::{env, process};
+
 use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+use linked_list_impls::list_builder::ListBuilder;
 use linked_list_impls::static_linked_list::StaticLinkedList;
-use linked_list_impls::LinkedListTrait;
+use linked_list_impls::{ListRead, ListWrite};
 
-fn main() {
-    // Example usage of DynamicLinkedList
-    let mut dynamic_list: DynamicLinkedList<i32> = DynamicLinkedList::new();
-    dynamic_list.insert(1);
-    dynamic_list.insert(2);
-    dynamic_list.insert(3);
+/// The largest capacity `switch static` accepts, which is the size of the static list's
+/// array; smaller capacities bound it with a `BoundedList`.
+const MAX_STATIC_CAPACITY: usize = 1024;
 
-    println!("Dynamic List: {:?}", dynamic_list);
+/// The text printed by `help`.
+const HELP: &str = "\
+commands:
+  insert <value>             append a value
+  insert_at <index> <value>  insert a value at an index
+  delete <value>             delete the first element equal to a value
+  delete_at <index>          delete the element at an index
+  get <index>                print the element at an index
+  set <index> <value>        replace the element at an index
+  find <value>               print the index of the first element equal to a value
+  len                        print the number of elements
+  print                      print the elements and the implementation
+  switch dynamic             move the elements into a DynamicLinkedList
+  switch static <capacity>   move the elements into a StaticLinkedList of that capacity
+  help                       print this text
+  quit                       leave";
 
-    // Example usage of StaticLinkedList
-    let mut static_list: StaticLinkedList<i32, 5> = StaticLinkedList::new();
-    static_list.insert(4);
-    static_list.insert(5);
-    static_list.insert(6);
+/// A parsed command line.
+enum Command {
+    Insert(i64),
+    InsertAt(usize, i64),
+    Delete(i64),
+    DeleteAt(usize),
+    Get(usize),
+    Set(usize, i64),
+    Find(i64),
+    Len,
+    Print,
+    SwitchDynamic,
+    SwitchStatic(usize),
+    Help,
+}
+
+impl Command {
+    /// Parses a command line.
+    ///
+    /// # Returns
+    /// - `Ok(Some(Command))` for a command.
+    /// - `Ok(None)` for a blank line.
+    /// - `Err(String)` with a message if the line is not a command.
+    fn parse(line: &str) -> Result<Option<Command>, String> {
+        let mut words = line.split_whitespace();
+        let Some(name) = words.next() else {
+            return Ok(None);
+        };
+        let command = match name {
+            "insert" => Command::Insert(arg(&mut words, "value")?),
+            "insert_at" => Command::InsertAt(arg(&mut words, "index")?, arg(&mut words, "value")?),
+            "delete" => Command::Delete(arg(&mut words, "value")?),
+            "delete_at" => Command::DeleteAt(arg(&mut words, "index")?),
+            "get" => Command::Get(arg(&mut words, "index")?),
+            "set" => Command::Set(arg(&mut words, "index")?, arg(&mut words, "value")?),
+            "find" => Command::Find(arg(&mut words, "value")?),
+            "len" => Command::Len,
+            "print" => Command::Print,
+            "switch" => match words.next() {
+                Some("dynamic") => Command::SwitchDynamic,
+                Some("static") => Command::SwitchStatic(arg(&mut words, "capacity")?),
+                _ => return Err("use `switch dynamic` or `switch static <capacity>`".to_string()),
+            },
+            "help" => Command::Help,
+            _ => return Err(format!("unknown command `{name}`; try `help`")),
+        };
+        if let Some(extra) = words.next() {
+            return Err(format!("unexpected argument `{extra}`"));
+        }
+        Ok(Some(command))
+    }
+}
+
+/// The list being edited and the name of the implementation behind it.
+struct Session {
+    /// The list, behind the trait so the implementation can change.
+    list: Box<dyn ListWrite<i64>>,
+    /// How `print` describes the implementation, e.g. `static, capacity 10`.
+    kind: String,
+}
 
-    println!("Static List: {:?}", static_list);
+impl Session {
+    /// Starts with an empty dynamic list.
+    fn new() -> Self {
+        Session {
+            list: Box::new(DynamicLinkedList::new()),
+            kind: "dynamic".to_string(),
+        }
+    }
+
+    /// Runs one command.
+    ///
+    /// # Returns
+    /// - `Ok(Some(String))` with the text to print.
+    /// - `Ok(None)` if there is nothing to print.
+    /// - `Err(String)` with a message if the command failed.
+    fn run(&mut self, command: Command) -> Result<Option<String>, String> {
+        let output = match command {
+            Command::Insert(value) => {
+                self.list
+                    .try_insert(value)
+                    .map_err(|error| error.to_string())?;
+                None
+            }
+            Command::InsertAt(index, value) => {
+                self.list
+                    .insert_at_index(index, value)
+                    .map_err(|error| error.to_string())?;
+                None
+            }
+            Command::Delete(value) => {
+                if !self.list.delete_element(&value) {
+                    return Err(format!("{value} is not in the list"));
+                }
+                None
+            }
+            Command::DeleteAt(index) => {
+                self.list
+                    .delete_at_index(index)
+                    .map_err(|error| error.to_string())?;
+                None
+            }
+            Command::Get(index) => {
+                let value = self.list.get(index).ok_or_else(|| {
+                    format!(
+                        "index {index} is out of bounds for a list of length {}",
+                        self.list.len()
+                    )
+                })?;
+                Some(value.to_string())
+            }
+            Command::Set(index, value) => {
+                self.list
+                    .update_element_at_index(index, value)
+                    .map_err(|error| error.to_string())?;
+                None
+            }
+            Command::Find(value) => match self.list.position(&value) {
+                Some(index) => Some(index.to_string()),
+                None => Some(format!("{value} is not in the list")),
+            },
+            Command::Len => Some(self.list.len().to_string()),
+            Command::Print => Some(self.describe()),
+            Command::SwitchDynamic => {
+                self.switch(Box::new(DynamicLinkedList::new()), "dynamic".to_string())?;
+                Some(self.describe())
+            }
+            Command::SwitchStatic(capacity) => {
+                if capacity > MAX_STATIC_CAPACITY {
+                    return Err(format!("the capacity can be at most {MAX_STATIC_CAPACITY}"));
+                }
+                let list = ListBuilder::new()
+                    .max_len(capacity)
+                    .backing_store::<StaticLinkedList<i64, MAX_STATIC_CAPACITY>>()
+                    .build()
+                    .map_err(|error| error.to_string())?;
+                self.switch(Box::new(list), format!("static, capacity {capacity}"))?;
+                Some(self.describe())
+            }
+            Command::Help => Some(HELP.to_string()),
+        };
+        Ok(output)
+    }
+
+    /// Moves the elements into `list`, which becomes the list being edited. If they do
+    /// not fit, the current list is kept.
+    fn switch(&mut self, mut list: Box<dyn ListWrite<i64>>, kind: String) -> Result<(), String> {
+        for index in 0..self.list.len() {
+            let value = *self.list.get(index).unwrap();
+            list.try_insert(value)
+                .map_err(|error| format!("the elements do not fit: {error}"))?;
+        }
+        self.list = list;
+        self.kind = kind;
+        Ok(())
+    }
+
+    /// Formats the elements and the implementation, e.g. `[1, 2] (dynamic, 2 elements)`.
+    fn describe(&self) -> String {
+        let values: Vec<String> = (0..self.list.len())
+            .map(|index| self.list.get(index).unwrap().to_string())
+            .collect();
+        format!(
+            "[{}] ({}, {} elements)",
+            values.join(", "),
+            self.kind,
+            values.len()
+        )
+    }
+}
+
+/// Parses the next word of a command as the argument called `name`.
+fn arg<T: FromStr>(words: &mut SplitWhitespace, name: &str) -> Result<T, String> {
+    let word = words.next().ok_or_else(|| format!("missing {name}"))?;
+    word.parse().map_err(|_| format!("invalid {name} `{word}`"))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().is_some_and(|command| command == "bench") {
+        if let Err(message) = bench::run(&args[1..]) {
+            eprintln!("error: {message}");
+            process::exit(2);
+        }
+        return;
+    }
+    println!("linked list shell; type `help` for the commands");
+    let mut session = Session::new();
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        if let Err(error) = io::stdout().flush() {
+            eprintln!("error: {error}");
+            break;
+        }
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break, // End of input
+            Ok(_) => {}
+            // The bad line has been consumed, so the next read starts after it.
+            Err(error) if error.kind() == io::ErrorKind::InvalidData => {
+                println!("error: {error}");
+                continue;
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                break;
+            }
+        }
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+        let output = match Command::parse(&line) {
+            Ok(Some(command)) => session.run(command),
+            Ok(None) => Ok(None), // Blank line
+            Err(message) => Err(message),
+        };
+        match output {
+            Ok(Some(output)) => println!("{output}"),
+            Ok(None) => {}
+            Err(message) => println!("error: {message}"),
+        }
+    }
 }
@@ -0,0 +1,201 @@
+use alloc::boxed::Box;
+use core::cell::UnsafeCell;
+use core::fmt::{self, Debug};
+use core::mem::MaybeUninit;
+use core::ptr;
+
+use crate::reclaim::Reclaimer;
+use crate::sync::{spin_loop, AtomicPtr, Ordering};
+
+/// `Node` represents a single element of the queue.
+struct Node<T> {
+    /// The data stored in the node. Uninitialized in the dummy node, and moved out when
+    /// the node becomes the dummy.
+    data: UnsafeCell<MaybeUninit<T>>,
+    /// The next node in the queue.
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    /// Allocates a node on the heap and leaks it as a raw pointer.
+    fn alloc(data: MaybeUninit<T>) -> *mut Self {
+        Box::into_raw(Box::new(Node {
+            data: UnsafeCell::new(data),
+            next: AtomicPtr::new(ptr::null_mut()),
+        }))
+    }
+}
+
+/// `MsQueue` is a lock-free multi-producer, multi-consumer FIFO queue.
+///
+/// It implements the Michael–Scott algorithm: a singly linked list with a dummy node at
+/// the front, an atomic `head` that consumers advance with compare-and-swap, and an
+/// atomic `tail` that producers swing forward after linking a new node. Any thread that
+/// sees `tail` lagging behind helps move it, so no operation ever waits on another.
+/// All methods take `&self`; share the queue between threads with an `Arc`.
+///
+/// Dequeued nodes cannot be freed right away, because a concurrent thread may still be
+/// reading them, so they are retired through the crate's reclaimer, which frees them by
+/// epoch-based reclamation once no thread can still see them. No node is reused while a
+/// thread may hold a stale pointer to it, so the algorithm is immune to the ABA problem.
+pub struct MsQueue<T> {
+    /// The dummy node. The first element is in `head.next`.
+    head: AtomicPtr<Node<T>>,
+    /// The last node, or a node shortly before it while an enqueue is in progress.
+    tail: AtomicPtr<Node<T>>,
+    /// Frees dequeued nodes once no thread can still be reading them.
+    reclaimer: Reclaimer<Node<T>>,
+}
+
+// SAFETY: elements are moved in by one thread and out by exactly one other, and all
+// shared node state is accessed through atomics.
+unsafe impl<T: Send> Send for MsQueue<T> {}
+// SAFETY: as above; `&MsQueue` only permits moving `T`s across threads, never sharing them.
+unsafe impl<T: Send> Sync for MsQueue<T> {}
+
+impl<T> MsQueue<T> {
+    /// Creates a new, empty `MsQueue`.
+    ///
+    /// # Returns
+    /// - A new empty `MsQueue` instance.
+    pub fn new() -> Self {
+        let dummy = Node::alloc(MaybeUninit::uninit());
+        MsQueue {
+            head: AtomicPtr::new(dummy),
+            tail: AtomicPtr::new(dummy),
+            reclaimer: Reclaimer::new(),
+        }
+    }
+
+    /// Returns `true` if the queue was empty at the moment of the check.
+    pub fn is_empty(&self) -> bool {
+        let _guard = self.reclaimer.pin();
+        let head = self.head.load(Ordering::Acquire);
+        // SAFETY: `head` cannot be freed while we are pinned.
+        unsafe { (*head).next.load(Ordering::Acquire).is_null() }
+    }
+
+    /// Adds an element to the back of the queue.
+    ///
+    /// # Parameters
+    /// - `data`: The value to enqueue.
+    pub fn enqueue(&self, data: T) {
+        let node = Node::alloc(MaybeUninit::new(data));
+        let _guard = self.reclaimer.pin();
+        loop {
+            let tail = self.tail.load(Ordering::Acquire);
+            // SAFETY: `tail` cannot be freed while we are pinned.
+            let next = unsafe { (*tail).next.load(Ordering::Acquire) };
+            if tail != self.tail.load(Ordering::Acquire) {
+                spin_loop();
+                continue;
+            }
+            if next.is_null() {
+                // SAFETY: as above.
+                let linked = unsafe {
+                    (*tail).next.compare_exchange(
+                        ptr::null_mut(),
+                        node,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    )
+                };
+                if linked.is_ok() {
+                    // Failure means another thread already helped swing the tail.
+                    let _ = self.tail.compare_exchange(
+                        tail,
+                        node,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    );
+                    return;
+                }
+                spin_loop();
+            } else {
+                // The tail is lagging behind; help the other producer finish.
+                let _ =
+                    self.tail
+                        .compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+                spin_loop();
+            }
+        }
+    }
+
+    /// Removes and returns the element at the front of the queue, if any.
+    ///
+    /// # Returns
+    /// - `Some(T)` if an element was dequeued.
+    /// - `None` if the queue was empty.
+    pub fn try_dequeue(&self) -> Option<T> {
+        let guard = self.reclaimer.pin();
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            let tail = self.tail.load(Ordering::Acquire);
+            // SAFETY: `head` cannot be freed while we are pinned.
+            let next = unsafe { (*head).next.load(Ordering::Acquire) };
+            if head != self.head.load(Ordering::Acquire) {
+                spin_loop();
+                continue;
+            }
+            if next.is_null() {
+                return None;
+            }
+            if head == tail {
+                // The tail is lagging behind a node that is already linked; help it along.
+                let _ =
+                    self.tail
+                        .compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+                spin_loop();
+                continue;
+            }
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                // SAFETY: winning the CAS makes this thread the only one to take the data
+                // out of `next`, which is now the dummy node.
+                let data = unsafe { (*(*next).data.get()).assume_init_read() };
+                // SAFETY: winning the CAS unlinked `head`, so no one else retires it. Its
+                // data was moved out (or never set), so freeing it drops no `T`.
+                unsafe { self.reclaimer.retire(head, &guard) };
+                return Some(data);
+            }
+            spin_loop();
+        }
+    }
+}
+
+impl<T> Drop for MsQueue<T> {
+    /// Drops the remaining elements and frees the linked nodes. Retired nodes are freed
+    /// by the reclaimer.
+    fn drop(&mut self) {
+        // SAFETY: `&mut self` guarantees no other thread is using the queue. The dummy
+        // node holds no data; every node after it holds an initialized element.
+        unsafe {
+            let dummy = self.head.load(Ordering::Relaxed);
+            let mut current = (*dummy).next.load(Ordering::Relaxed);
+            drop(Box::from_raw(dummy));
+            while !current.is_null() {
+                let node = Box::from_raw(current);
+                current = node.next.load(Ordering::Relaxed);
+                (*node.data.get()).assume_init_drop();
+            }
+        }
+    }
+}
+
+impl<T> Default for MsQueue<T> {
+    /// Provides a default (empty) instance of the queue using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for MsQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MsQueue")
+            .field("is_empty", &self.is_empty())
+            .finish_non_exhaustive()
+    }
+}
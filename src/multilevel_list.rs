@@ -0,0 +1,213 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+
+use crate::LinkedListError;
+
+/// `Node` represents a single element of a multilevel list.
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The next node on the same level.
+    next: Option<Box<Node<T>>>,
+    /// An optional nested list hanging below this node.
+    child: Option<MultilevelList<T>>,
+}
+
+/// `MultilevelList` is a singly linked list whose nodes may each carry a child list.
+///
+/// Every node has a `next` pointer along its level and an optional `child` pointer to a
+/// nested `MultilevelList`, which can have children of its own. [`flatten`](Self::flatten)
+/// splices all children into the top level depth-first, and
+/// [`iter_depth_first`](Self::iter_depth_first) walks the nested structure without
+/// modifying it.
+pub struct MultilevelList<T> {
+    /// The first node on this level.
+    head: Option<Box<Node<T>>>,
+}
+
+impl<T> MultilevelList<T> {
+    /// Creates a new, empty `MultilevelList`.
+    ///
+    /// # Returns
+    /// - A new empty `MultilevelList` instance.
+    pub fn new() -> Self {
+        MultilevelList { head: None }
+    }
+
+    /// Returns `true` if this level contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns the number of elements on this level, ignoring children.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns the number of elements on every level.
+    pub fn total_len(&self) -> usize {
+        self.iter_depth_first().count()
+    }
+
+    /// Inserts an element at the front of this level.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        self.head = Some(Box::new(Node {
+            data,
+            next: self.head.take(),
+            child: None,
+        }));
+    }
+
+    /// Inserts an element at the back of this level.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+        *link = Some(Box::new(Node {
+            data,
+            next: None,
+            child: None,
+        }));
+    }
+
+    /// Attaches `child` below the element at `index`, replacing any previous child.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element on this level.
+    /// - `child`: The list to hang below it.
+    ///
+    /// # Returns
+    /// - `Ok(Option<MultilevelList>)` with the previous child, if any.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn set_child(
+        &mut self,
+        index: usize,
+        child: MultilevelList<T>,
+    ) -> Result<Option<MultilevelList<T>>, LinkedListError> {
+        let len = self.len();
+        let node = self
+            .node_mut(index)
+            .ok_or(LinkedListError::IndexOutOfBounds { index, len })?;
+        Ok(node.child.replace(child))
+    }
+
+    /// Returns the child list of the element at `index`, if it has one.
+    pub fn child(&self, index: usize) -> Option<&MultilevelList<T>> {
+        let mut current = self.head.as_deref();
+        for _ in 0..index {
+            current = current?.next.as_deref();
+        }
+        current?.child.as_ref()
+    }
+
+    /// Returns the child list of the element at `index` mutably, if it has one.
+    pub fn child_mut(&mut self, index: usize) -> Option<&mut MultilevelList<T>> {
+        self.node_mut(index)?.child.as_mut()
+    }
+
+    /// Splices every child list into this level, depth-first.
+    ///
+    /// Each node's child (itself flattened) is placed directly after the node and before
+    /// the node's original successor. Afterwards no node has a child.
+    pub fn flatten(&mut self) {
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            if let Some(mut child) = node.child.take() {
+                if let Some(child_head) = child.head.take() {
+                    let rest = node.next.take();
+                    node.next = Some(child_head);
+                    let mut tail = &mut node.next;
+                    while let Some(child_node) = tail {
+                        tail = &mut child_node.next;
+                    }
+                    *tail = rest;
+                }
+            }
+            link = &mut node.next;
+        }
+    }
+
+    /// Returns an iterator over the elements on this level, ignoring children.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        core::iter::successors(self.head.as_deref(), |node| node.next.as_deref())
+            .map(|node| &node.data)
+    }
+
+    /// Returns an iterator over every element as `(depth, &T)`, visiting each node's
+    /// children before its successor. Top-level elements have depth 0.
+    pub fn iter_depth_first(&self) -> DepthFirst<'_, T> {
+        DepthFirst {
+            stack: self.head.as_deref().map(|node| (node, 0)).into_iter().collect(),
+        }
+    }
+
+    /// Returns the node at `index` on this level.
+    fn node_mut(&mut self, index: usize) -> Option<&mut Node<T>> {
+        let mut current = self.head.as_deref_mut();
+        for _ in 0..index {
+            current = current?.next.as_deref_mut();
+        }
+        current
+    }
+}
+
+impl<T> Drop for MultilevelList<T> {
+    /// Unlinks nodes one at a time so long levels don't recurse through `Box` drops.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+impl<T> Default for MultilevelList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for MultilevelList<T> {
+    /// Formats each level as a list, with a node's child shown right after it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        let nodes = core::iter::successors(self.head.as_deref(), |node| node.next.as_deref());
+        for node in nodes {
+            list.entry(&node.data);
+            if let Some(child) = &node.child {
+                list.entry(child);
+            }
+        }
+        list.finish()
+    }
+}
+
+/// A depth-first iterator over the nested elements of a `MultilevelList`.
+pub struct DepthFirst<'a, T> {
+    /// Nodes still to visit with their depth; the next one is on top.
+    stack: Vec<(&'a Node<T>, usize)>,
+}
+
+impl<'a, T> Iterator for DepthFirst<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<(usize, &'a T)> {
+        let (node, depth) = self.stack.pop()?;
+        if let Some(next) = node.next.as_deref() {
+            self.stack.push((next, depth));
+        }
+        if let Some(child) = node.child.as_ref().and_then(|child| child.head.as_deref()) {
+            self.stack.push((child, depth + 1));
+        }
+        Some((depth, &node.data))
+    }
+}
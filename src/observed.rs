@@ -0,0 +1,287 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::mem;
+
+use crate::counted::Counted;
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// A callback told about an inserted or removed element and its index.
+type Observer<T> = Box<dyn FnMut(usize, &T)>;
+
+/// A callback told about a replaced element: its index, the old value and the new one.
+type UpdateObserver<T> = Box<dyn FnMut(usize, &T, &T)>;
+
+/// `Observed` wraps any list and calls the registered observers after each change, so a
+/// view or a cache can follow the list without wrapping every call site.
+///
+/// The observers see the changes made through `ListWrite`, including the value-based
+/// `delete_element` and `update_element`, which go through the index-based operations:
+///
+/// - [`on_insert`](Self::on_insert) observers get the index and the new element, once it
+///   is in the list.
+/// - [`on_remove`](Self::on_remove) observers get the index and the element just before it
+///   is removed.
+/// - [`on_update`](Self::on_update) observers get the index, the old element and the new
+///   one, after the replacement.
+///
+/// Changes made in place through `get_mut` or `iter_mut` are not observed. The wrapped
+/// list is kept behind a [`Counted`], so finding the index of an appended element is O(1).
+pub struct Observed<T, L> {
+    /// The wrapped list.
+    list: Counted<L>,
+    /// The observers of insertions, in registration order.
+    on_insert: Vec<Observer<T>>,
+    /// The observers of removals, in registration order.
+    on_remove: Vec<Observer<T>>,
+    /// The observers of updates, in registration order.
+    on_update: Vec<UpdateObserver<T>>,
+}
+
+impl<T, L: ListRead<T>> Observed<T, L> {
+    /// Wraps `list` with no observers.
+    ///
+    /// # Parameters
+    /// - `list`: The list to wrap. The elements it already holds are not reported.
+    pub fn new(list: L) -> Self {
+        Observed {
+            list: Counted::new(list),
+            on_insert: Vec::new(),
+            on_remove: Vec::new(),
+            on_update: Vec::new(),
+        }
+    }
+}
+
+impl<T, L> Observed<T, L> {
+    /// Registers an observer of insertions, called with the index and the new element.
+    ///
+    /// # Parameters
+    /// - `observer`: The callback, kept until the wrapper is dropped.
+    pub fn on_insert(&mut self, observer: impl FnMut(usize, &T) + 'static) -> &mut Self {
+        self.on_insert.push(Box::new(observer));
+        self
+    }
+
+    /// Registers an observer of removals, called with the index and the element about to
+    /// be removed.
+    ///
+    /// # Parameters
+    /// - `observer`: The callback, kept until the wrapper is dropped.
+    pub fn on_remove(&mut self, observer: impl FnMut(usize, &T) + 'static) -> &mut Self {
+        self.on_remove.push(Box::new(observer));
+        self
+    }
+
+    /// Registers an observer of updates, called with the index, the old element and the
+    /// new one.
+    ///
+    /// # Parameters
+    /// - `observer`: The callback, kept until the wrapper is dropped.
+    pub fn on_update(&mut self, observer: impl FnMut(usize, &T, &T) + 'static) -> &mut Self {
+        self.on_update.push(Box::new(observer));
+        self
+    }
+
+    /// Returns the number of elements in the list in O(1).
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns a reference to the wrapped list.
+    pub fn inner(&self) -> &L {
+        self.list.inner()
+    }
+
+    /// Consumes the wrapper, dropping the observers, and returns the wrapped list.
+    pub fn into_inner(self) -> L {
+        self.list.into_inner()
+    }
+}
+
+impl<T, L: ListRead<T>> Observed<T, L> {
+    /// Tells the insertion observers about the element now at `index`.
+    fn notify_insert(&mut self, index: usize) {
+        if let Some(data) = self.list.get(index) {
+            for observer in &mut self.on_insert {
+                observer(index, data);
+            }
+        }
+    }
+}
+
+impl<T, L: ListRead<T> + Default> Default for Observed<T, L> {
+    /// Wraps an empty list with no observers.
+    fn default() -> Self {
+        Observed::new(L::default())
+    }
+}
+
+impl<T, L: Debug> Debug for Observed<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Observed")
+            .field("list", self.list.inner())
+            .field(
+                "observers",
+                &(self.on_insert.len() + self.on_remove.len() + self.on_update.len()),
+            )
+            .finish()
+    }
+}
+
+impl<T, L: ListRead<T>> ListRead<T> for Observed<T, L> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.list.position_by(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+}
+
+impl<T, L: ListWrite<T>> ListWrite<T> for Observed<T, L> {
+    /// Inserts an element at the end (tail) of the list, notifying the insertion
+    /// observers if the wrapped list took it.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        let index = self.list.len();
+        self.list.insert(data);
+        if self.list.len() > index {
+            self.notify_insert(index);
+        }
+    }
+
+    /// Inserts an element at the end (tail) of the list and notifies the insertion
+    /// observers.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        let index = self.list.len();
+        self.list.try_insert(data)?;
+        self.notify_insert(index);
+        Ok(())
+    }
+
+    /// Inserts an element at a specific index and notifies the insertion observers.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list.insert_at_index(index, data)?;
+        self.notify_insert(index);
+        Ok(())
+    }
+
+    /// Notifies the removal observers and deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise, in which case no
+    ///   observer is called.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        if let Some(data) = self.list.get(index) {
+            for observer in &mut self.on_remove {
+                observer(index, data);
+            }
+        }
+        self.list.delete_at_index(index)
+    }
+
+    /// Returns a mutable reference to the element at the specified index. Changes made
+    /// through it are not observed.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    /// Replaces the element at the specified index and notifies the update observers.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        let Some(slot) = self.list.get_mut(index) else {
+            return self.list.update_element_at_index(index, data);
+        };
+        let old = mem::replace(slot, data);
+        for observer in &mut self.on_update {
+            observer(index, &old, slot);
+        }
+        Ok(())
+    }
+}
+
+impl<T, L: LinkedListTrait<T>> LinkedListTrait<T> for Observed<T, L> {
+    type Iter<'a>
+        = L::Iter<'a>
+    where
+        Self: 'a,
+        T: 'a;
+    type IterMut<'a>
+        = L::IterMut<'a>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns an iterator over the elements of the wrapped list, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator that allows modifying each element of the wrapped list. Changes
+    /// made through it are not observed.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.list.iter_mut()
+    }
+}
@@ -0,0 +1,90 @@
+//! Parallel iteration with `rayon`, enabled by the `rayon` feature.
+//!
+//! Every list below implements `IntoParallelIterator` for `&List`, so with
+//! `use rayon::prelude::*` you can write `list.par_iter().map(...)`.
+//!
+//! A linked chain cannot be split in the middle without walking to it, so the parallel
+//! iterator first collects a reference to each element in one sequential pass. That pass
+//! only copies pointers; rayon then splits the collected references in halves across
+//! threads, which pays off as soon as the per-element work outweighs a pointer chase.
+
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::circular_doubly_linked_list::CircularDoublyLinkedList;
+use crate::circular_linked_list::CircularLinkedList;
+use crate::doubly_linked_list::DoublyLinkedList;
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::skip_list::IndexedSkipList;
+use crate::static_linked_list::StaticLinkedList;
+use crate::unrolled_linked_list::UnrolledLinkedList;
+use crate::xor_linked_list::XorLinkedList;
+
+/// A parallel iterator over references to the elements of a list, in list order.
+pub struct ParIter<'a, T> {
+    /// A reference to every element, gathered by walking the list once.
+    items: Vec<&'a T>,
+}
+
+impl<'a, T> ParIter<'a, T> {
+    /// Gathers the references yielded by a sequential iterator over a list.
+    fn new(iter: impl Iterator<Item = &'a T>) -> Self {
+        ParIter {
+            items: iter.collect(),
+        }
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.items.into_par_iter().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+impl<T: Sync> IndexedParallelIterator for ParIter<'_, T> {
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.items.into_par_iter().drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.items.into_par_iter().with_producer(callback)
+    }
+}
+
+/// Implements `IntoParallelIterator` for a shared reference to a list with an `iter()`
+/// method yielding `&T`. The brackets hold any generic parameters besides `T`.
+macro_rules! impl_into_par_iter {
+    ($([$($generics:tt)*] $list:ty;)*) => {
+        $(
+            impl<'a, T: Sync + 'a, $($generics)*> IntoParallelIterator for &'a $list {
+                type Iter = ParIter<'a, T>;
+                type Item = &'a T;
+
+                fn into_par_iter(self) -> ParIter<'a, T> {
+                    ParIter::new(self.iter())
+                }
+            }
+        )*
+    };
+}
+
+impl_into_par_iter! {
+    [] CircularDoublyLinkedList<T>;
+    [] CircularLinkedList<T>;
+    [] DoublyLinkedList<T>;
+    [] DynamicLinkedList<T>;
+    [] IndexedSkipList<T>;
+    [const N: usize] StaticLinkedList<T, N>;
+    [const B: usize] UnrolledLinkedList<T, B>;
+    [] XorLinkedList<T>;
+}
@@ -0,0 +1,345 @@
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::ops::Deref;
+
+use crate::LinkedListError;
+
+/// `SharedPointerKind` abstracts over the reference-counted pointer used to share nodes.
+///
+/// Implemented by [`RcPointer`] (the default, single-threaded) and [`ArcPointer`]
+/// (atomically counted, so lists can be shared across threads).
+pub trait SharedPointerKind {
+    /// The pointer type wrapping a value of type `U`.
+    type Pointer<U>: Clone + Deref<Target = U>;
+
+    /// Moves `value` into a new shared allocation.
+    fn new<U>(value: U) -> Self::Pointer<U>;
+
+    /// Returns the inner value if `pointer` is the only reference to it.
+    fn try_unwrap<U>(pointer: Self::Pointer<U>) -> Result<U, Self::Pointer<U>>;
+
+    /// Returns `true` if both pointers refer to the same allocation.
+    fn ptr_eq<U>(a: &Self::Pointer<U>, b: &Self::Pointer<U>) -> bool;
+}
+
+/// Shares nodes through [`Rc`].
+#[derive(Debug)]
+pub struct RcPointer;
+
+impl SharedPointerKind for RcPointer {
+    type Pointer<U> = Rc<U>;
+
+    fn new<U>(value: U) -> Rc<U> {
+        Rc::new(value)
+    }
+
+    fn try_unwrap<U>(pointer: Rc<U>) -> Result<U, Rc<U>> {
+        Rc::try_unwrap(pointer)
+    }
+
+    fn ptr_eq<U>(a: &Rc<U>, b: &Rc<U>) -> bool {
+        Rc::ptr_eq(a, b)
+    }
+}
+
+/// Shares nodes through [`Arc`], making the list `Send + Sync` when `T` is.
+#[derive(Debug)]
+pub struct ArcPointer;
+
+impl SharedPointerKind for ArcPointer {
+    type Pointer<U> = Arc<U>;
+
+    fn new<U>(value: U) -> Arc<U> {
+        Arc::new(value)
+    }
+
+    fn try_unwrap<U>(pointer: Arc<U>) -> Result<U, Arc<U>> {
+        Arc::try_unwrap(pointer)
+    }
+
+    fn ptr_eq<U>(a: &Arc<U>, b: &Arc<U>) -> bool {
+        Arc::ptr_eq(a, b)
+    }
+}
+
+/// A `PersistentList` whose nodes are shared through `Arc`, so that immutable lists can
+/// be sent to and extended from other threads without locks.
+pub type SyncPersistentList<T> = PersistentList<T, ArcPointer>;
+
+/// Shorthand for the pointer to a node of a list using pointer kind `P`.
+type Link<T, P> = Option<<P as SharedPointerKind>::Pointer<Node<T, P>>>;
+
+/// `Node` represents a single, immutable element of a persistent list.
+struct Node<T, P: SharedPointerKind> {
+    /// The data stored in the node.
+    data: T,
+    /// The rest of the list. Possibly shared with other lists.
+    next: Link<T, P>,
+}
+
+/// `PersistentList` is an immutable singly linked list with structural sharing.
+///
+/// Operations never modify an existing list; they return a new list that reuses
+/// (shares) every node that did not need to change. `push_front` and `tail` are O(1)
+/// and share the whole list, while positional operations copy only the nodes in front
+/// of the edited position and share the rest. Cloning a list is O(1), which makes
+/// snapshots essentially free.
+///
+/// Nodes are shared through `Rc` by default; use [`SyncPersistentList`] (or any other
+/// [`SharedPointerKind`]) to choose a different pointer.
+pub struct PersistentList<T, P: SharedPointerKind = RcPointer> {
+    /// The first node of the list.
+    head: Link<T, P>,
+    /// The number of elements in the list.
+    len: usize,
+}
+
+impl<T> PersistentList<T> {
+    /// Creates a new, empty `Rc`-backed `PersistentList`.
+    ///
+    /// # Returns
+    /// - A new empty `PersistentList` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, P: SharedPointerKind> PersistentList<T, P> {
+    /// Creates a new, empty `PersistentList` using any pointer kind, e.g.
+    /// `SyncPersistentList::empty()`.
+    ///
+    /// # Returns
+    /// - A new empty `PersistentList` instance.
+    pub fn empty() -> Self {
+        PersistentList { head: None, len: 0 }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.data)
+    }
+
+    /// Returns a new list with `data` in front of the elements of this one, in O(1).
+    ///
+    /// The returned list shares every node of `self`.
+    ///
+    /// # Parameters
+    /// - `data`: The value to put at the front.
+    pub fn push_front(&self, data: T) -> Self {
+        PersistentList {
+            head: Some(P::new(Node {
+                data,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    /// Returns the list without its first element, in O(1).
+    ///
+    /// The tail of an empty list is the empty list.
+    pub fn tail(&self) -> Self {
+        match &self.head {
+            Some(node) => PersistentList {
+                head: node.next.clone(),
+                len: self.len - 1,
+            },
+            None => PersistentList::empty(),
+        }
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Checks whether a given value exists in the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to search for.
+    pub fn find(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        Iter {
+            current: self.head.as_deref(),
+        }
+    }
+
+    /// Returns `true` if both lists start with the very same node, i.e. one is a
+    /// snapshot of the other with no differences.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => P::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the node at `index` (or `None` for `index == len`), which becomes the
+    /// shared suffix of an edited copy.
+    fn suffix_at(&self, index: usize) -> Link<T, P> {
+        let mut current = self.head.clone();
+        for _ in 0..index {
+            current = current.and_then(|node| node.next.clone());
+        }
+        current
+    }
+
+    /// Builds a list that copies the first `count` elements of `self` in front of `suffix`.
+    fn with_prefix(&self, count: usize, suffix: Link<T, P>, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        let prefix: Vec<&T> = self.iter().take(count).collect();
+        let mut head = suffix;
+        for data in prefix.into_iter().rev() {
+            head = Some(P::new(Node {
+                data: data.clone(),
+                next: head,
+            }));
+        }
+        PersistentList { head, len }
+    }
+}
+
+impl<T: Clone, P: SharedPointerKind> PersistentList<T, P> {
+    /// Returns a new list with `data` inserted at `index`.
+    ///
+    /// The nodes before `index` are copied; everything from `index` on is shared.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(PersistentList)` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn insert(&self, index: usize, data: T) -> Result<Self, LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let suffix = self.suffix_at(index);
+        let node = P::new(Node { data, next: suffix });
+        Ok(self.with_prefix(index, Some(node), self.len + 1))
+    }
+
+    /// Returns a new list without the element at `index`.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to remove.
+    ///
+    /// # Returns
+    /// - `Ok(PersistentList)` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn remove(&self, index: usize) -> Result<Self, LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let suffix = self.suffix_at(index + 1);
+        Ok(self.with_prefix(index, suffix, self.len - 1))
+    }
+
+    /// Returns a new list with the element at `index` replaced by `data`.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to replace.
+    /// - `data`: The new value.
+    ///
+    /// # Returns
+    /// - `Ok(PersistentList)` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    pub fn update(&self, index: usize, data: T) -> Result<Self, LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let suffix = self.suffix_at(index + 1);
+        let node = P::new(Node { data, next: suffix });
+        Ok(self.with_prefix(index, Some(node), self.len))
+    }
+}
+
+impl<T, P: SharedPointerKind> Clone for PersistentList<T, P> {
+    /// Returns a snapshot sharing every node with `self`, in O(1).
+    fn clone(&self) -> Self {
+        PersistentList {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T, P: SharedPointerKind> Drop for PersistentList<T, P> {
+    /// Releases uniquely owned nodes iteratively, stopping at the first shared one.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            match P::try_unwrap(node) {
+                Ok(mut node) => current = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T, P: SharedPointerKind> Default for PersistentList<T, P> {
+    /// Provides a default (empty) instance of the list using `empty()`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: Debug, P: SharedPointerKind> Debug for PersistentList<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the elements of a `PersistentList`.
+pub struct Iter<'a, T, P: SharedPointerKind = RcPointer> {
+    /// The next node to yield.
+    current: Option<&'a Node<T, P>>,
+}
+
+impl<'a, T, P: SharedPointerKind> Iterator for Iter<'a, T, P> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.current?;
+        self.current = node.next.as_deref();
+        Some(&node.data)
+    }
+}
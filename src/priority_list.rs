@@ -0,0 +1,125 @@
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::static_linked_list::StaticLinkedList;
+use crate::{LinkedListError, ListWrite};
+
+/// A `PriorityList` with room for at most `N` elements, stored in a `StaticLinkedList`.
+pub type BoundedPriorityList<T, const N: usize> = PriorityList<T, StaticLinkedList<T, N>>;
+
+/// `PriorityList` is a priority queue kept as a linked list in descending order.
+///
+/// `push` walks to the first element of lower priority and inserts in front of it, so it
+/// is O(n), while `peek` and `pop` only touch the head and are O(1). Elements of equal
+/// priority are popped in the order they were pushed.
+///
+/// The backing list defaults to `DynamicLinkedList`; [`BoundedPriorityList`] uses a
+/// fixed-capacity `StaticLinkedList` instead.
+pub struct PriorityList<T, L = DynamicLinkedList<T>> {
+    /// The backing list, highest priority first.
+    list: L,
+    /// The number of queued elements.
+    len: usize,
+    /// Marks the element type, which `L` only mentions through its trait impls.
+    _marker: PhantomData<T>,
+}
+
+impl<T> PriorityList<T> {
+    /// Creates a new, empty `PriorityList` backed by a `DynamicLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `PriorityList` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Default> PriorityList<T, L> {
+    /// Creates a new, empty `PriorityList` using any backing list, e.g.
+    /// `BoundedPriorityList::<i32, 8>::empty()`.
+    ///
+    /// # Returns
+    /// - A new empty `PriorityList` instance.
+    pub fn empty() -> Self {
+        PriorityList {
+            list: L::default(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L> PriorityList<T, L> {
+    /// Returns the number of queued elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the priority list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Ord + Clone, L> PriorityList<T, L>
+where
+    L: ListWrite<T>,
+    for<'a> &'a L: IntoIterator<Item = &'a T>,
+{
+    /// Inserts an element behind every element of greater or equal priority.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` if the backing list rejected the element (e.g. it is full).
+    pub fn push(&mut self, data: T) -> Result<(), LinkedListError> {
+        let index = self
+            .iter()
+            .position(|item| *item < data)
+            .unwrap_or(self.len);
+        self.list.insert_at_index(index, data)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element with the highest priority in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the priority list was not empty.
+    /// - `None` otherwise.
+    pub fn pop(&mut self) -> Option<T> {
+        let data = self.list.get(0)?.clone();
+        self.list.delete_at_index(0).expect("head must be in bounds");
+        self.len -= 1;
+        Some(data)
+    }
+
+    /// Returns a reference to the element with the highest priority without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    /// Returns an iterator over the elements, highest priority first.
+    pub fn iter(&self) -> <&L as IntoIterator>::IntoIter {
+        (&self.list).into_iter()
+    }
+}
+
+impl<T, L: Default> Default for PriorityList<T, L> {
+    /// Provides a default (empty) instance of the priority list using `empty()`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: Debug, L> Debug for PriorityList<T, L>
+where
+    for<'a> &'a L: IntoIterator<Item = &'a T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.list).finish()
+    }
+}
@@ -0,0 +1,147 @@
+//! Python bindings through `pyo3`, enabled by the `python` feature.
+//!
+//! The extension module `linked_list_impls` holds one class, `LinkedList`, which wraps a
+//! `DynamicLinkedList` of Python objects. It supports `insert`, indexing with negative
+//! indices, `del`, `len`, `in` and iteration, so it can stand in for a Python `list` when
+//! comparing implementations in a notebook. Build and install it into the active
+//! environment with `maturin develop --features python`.
+
+use alloc::string::String;
+use alloc::vec::{self, Vec};
+
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{ListRead, ListWrite};
+
+/// A singly linked list of Python objects, exported as `LinkedList`.
+#[pyclass(name = "LinkedList", module = "linked_list_impls")]
+#[derive(Default)]
+pub struct PyLinkedList {
+    /// The wrapped list.
+    list: DynamicLinkedList<Py<PyAny>>,
+}
+
+#[pymethods]
+impl PyLinkedList {
+    /// Creates a list: `LinkedList()` is empty, and `LinkedList(iterable)` holds the items
+    /// of `iterable` in order.
+    #[new]
+    #[pyo3(signature = (iterable = None))]
+    pub fn new(iterable: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let mut list = PyLinkedList::default();
+        if let Some(iterable) = iterable {
+            for item in iterable.try_iter()? {
+                list.list.insert(item?.unbind());
+            }
+        }
+        Ok(list)
+    }
+
+    /// Appends `value` to the end of the list.
+    pub fn insert(&mut self, value: Py<PyAny>) {
+        self.list.insert(value);
+    }
+
+    /// Returns the number of items, for `len(list)`.
+    pub fn __len__(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns the item at `index`, for `list[index]`. A negative index counts from the
+    /// end.
+    ///
+    /// # Returns
+    /// - `Err(IndexError)` if `index` is out of range.
+    pub fn __getitem__(&self, py: Python<'_>, index: isize) -> PyResult<Py<PyAny>> {
+        let index = self.resolve(index)?;
+        Ok(self.list.get(index).unwrap().clone_ref(py))
+    }
+
+    /// Removes the item at `index`, for `del list[index]`. A negative index counts from
+    /// the end.
+    ///
+    /// # Returns
+    /// - `Err(IndexError)` if `index` is out of range.
+    pub fn __delitem__(&mut self, index: isize) -> PyResult<()> {
+        let index = self.resolve(index)?;
+        self.list.delete_at_index(index).unwrap();
+        Ok(())
+    }
+
+    /// Returns `true` if an item equals `value`, for `value in list`.
+    pub fn __contains__(&self, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+        for item in self.list.iter() {
+            if value.eq(item)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns an iterator over the items, front to back. It iterates over the items
+    /// present when it was created, so the list may change while it is in use.
+    pub fn __iter__(&self, py: Python<'_>) -> PyLinkedListIter {
+        let items: Vec<Py<PyAny>> = self.list.iter().map(|item| item.clone_ref(py)).collect();
+        PyLinkedListIter {
+            items: items.into_iter(),
+        }
+    }
+
+    /// Returns `LinkedList([...])` with the `repr` of each item.
+    pub fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let mut repr = String::from("LinkedList([");
+        for (i, item) in self.list.iter().enumerate() {
+            if i > 0 {
+                repr.push_str(", ");
+            }
+            repr.push_str(&item.bind(py).repr()?.to_cow()?);
+        }
+        repr.push_str("])");
+        Ok(repr)
+    }
+}
+
+impl PyLinkedList {
+    /// Turns a Python index, which may count from the end, into a position in the list.
+    fn resolve(&self, index: isize) -> PyResult<usize> {
+        let len = self.list.len();
+        let resolved = if index < 0 {
+            len.checked_sub(index.unsigned_abs())
+        } else {
+            Some(index as usize)
+        };
+        resolved
+            .filter(|&index| index < len)
+            .ok_or_else(|| PyIndexError::new_err("LinkedList index out of range"))
+    }
+}
+
+/// The iterator returned by `iter(list)`.
+#[pyclass(module = "linked_list_impls")]
+pub struct PyLinkedListIter {
+    /// The items that are left.
+    items: vec::IntoIter<Py<PyAny>>,
+}
+
+#[pymethods]
+impl PyLinkedListIter {
+    /// Returns the iterator itself, as Python iterators do.
+    pub fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Returns the next item, or raises `StopIteration` at the end.
+    pub fn __next__(&mut self) -> Option<Py<PyAny>> {
+        self.items.next()
+    }
+}
+
+/// The `linked_list_impls` extension module.
+#[pymodule]
+fn linked_list_impls(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyLinkedList>()?;
+    module.add_class::<PyLinkedListIter>()?;
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{LinkedListError, ListWrite};
+
+/// `Queue` is a first-in, first-out adapter over any `ListWrite` backend.
+///
+/// Elements are enqueued at the back of the backing list and dequeued from its front.
+/// The backend defaults to `DynamicLinkedList`; pass a different one (for example
+/// `Queue<T, StaticLinkedList<T, N>>` for a bounded queue) to compare implementations.
+///
+/// The cost of each operation is that of the backend: enqueueing walks to the back of
+/// lists that do not track their tail.
+pub struct Queue<T, L = DynamicLinkedList<T>> {
+    /// The backing list. Its front is the front of the queue.
+    list: L,
+    /// The number of queued elements.
+    len: usize,
+    /// Marks the element type, which `L` only mentions through its trait impls.
+    _marker: PhantomData<T>,
+}
+
+impl<T> Queue<T> {
+    /// Creates a new, empty `Queue` backed by a `DynamicLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `Queue` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Default> Queue<T, L> {
+    /// Creates a new, empty `Queue` using any backing list, e.g.
+    /// `Queue::<i32, DoublyLinkedList<i32>>::empty()`.
+    ///
+    /// # Returns
+    /// - A new empty `Queue` instance.
+    pub fn empty() -> Self {
+        Queue {
+            list: L::default(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L> Queue<T, L> {
+    /// Returns the number of queued elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Consumes the queue and returns the backing list, front first.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<T: Clone, L: ListWrite<T>> Queue<T, L> {
+    /// Adds an element to the back of the queue.
+    ///
+    /// # Parameters
+    /// - `data`: The value to enqueue.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` if the backing list rejected the element (e.g. it is full).
+    pub fn enqueue(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.list.insert_at_index(self.len, data)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element at the front of the queue.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the queue was not empty.
+    /// - `None` otherwise.
+    pub fn dequeue(&mut self) -> Option<T> {
+        let data = self.list.get(0)?.clone();
+        self.list
+            .delete_at_index(0)
+            .expect("front must be in bounds");
+        self.len -= 1;
+        Some(data)
+    }
+
+    /// Returns a reference to the element at the front of the queue without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+}
+
+impl<T, L: Default> Default for Queue<T, L> {
+    /// Provides a default (empty) instance of the queue using `empty()`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Debug> Debug for Queue<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Queue").field(&self.list).finish()
+    }
+}
@@ -0,0 +1,421 @@
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::cell::{Ref, RefCell, RefMut};
+use core::fmt::{self, Debug};
+
+/// Shorthand for a strong, shared reference to a node.
+type NodeRef<T> = Rc<RefCell<Node<T>>>;
+
+/// `Node` represents a single element in the shared-mutation list.
+struct Node<T> {
+    /// The data stored in the node. `None` once the node has been removed from the list.
+    data: Option<T>,
+    /// A strong reference to the next node.
+    next: Option<NodeRef<T>>,
+    /// A weak back-reference to the previous node, so the links never form an `Rc` cycle.
+    prev: Weak<RefCell<Node<T>>>,
+}
+
+/// The state shared by every handle to the same `RcLinkedList`.
+struct Inner<T> {
+    /// The first node of the list.
+    head: Option<NodeRef<T>>,
+    /// The last node of the list.
+    tail: Option<NodeRef<T>>,
+    /// The number of elements in the list.
+    len: usize,
+}
+
+/// `RcLinkedList` is a doubly linked list built from `Rc<RefCell<Node>>` with `Weak`
+/// back-references.
+///
+/// Where `DynamicLinkedList` has a single `Box` owner per node and `StaticLinkedList`
+/// links by index, here every node is reference counted: cloning the list yields another
+/// owner of the *same* list, and [`NodeHandle`]s let any number of holders read and mutate
+/// individual elements through `RefCell`. All methods take `&self`.
+///
+/// Borrowing rules are enforced at runtime, so holding a `Ref` from
+/// [`NodeHandle::borrow`] while mutating that same node will panic.
+///
+/// Neither the list nor its handles are `Send` or `Sync`, since `Rc` counts are not
+/// atomic; use `ConcurrentLinkedList` to share a list between threads.
+pub struct RcLinkedList<T> {
+    /// The shared list state.
+    inner: Rc<RefCell<Inner<T>>>,
+}
+
+/// `NodeHandle` is a shared handle to one node of an `RcLinkedList`.
+///
+/// A handle keeps its node alive. Once the element is removed from the list the handle
+/// becomes detached: it no longer has neighbours and its data is gone.
+pub struct NodeHandle<T> {
+    /// The node this handle refers to.
+    node: NodeRef<T>,
+}
+
+/// `WeakNodeHandle` is a non-owning reference to one node of an `RcLinkedList`, made with
+/// [`NodeHandle::downgrade`].
+///
+/// Unlike a `NodeHandle` it does not keep the node alive, so an observer can hold on to
+/// elements without keeping them around after they are removed. [`upgrade`](Self::upgrade)
+/// gives a `NodeHandle` back for as long as the element is in the list.
+pub struct WeakNodeHandle<T> {
+    /// The node this handle refers to.
+    node: Weak<RefCell<Node<T>>>,
+}
+
+impl<T> RcLinkedList<T> {
+    /// Creates a new, empty `RcLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `RcLinkedList` instance.
+    pub fn new() -> Self {
+        RcLinkedList {
+            inner: Rc::new(RefCell::new(Inner {
+                head: None,
+                tail: None,
+                len: 0,
+            })),
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if both values are owners of the same underlying list.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Inserts an element at the back of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - A handle to the newly inserted node.
+    pub fn push_back(&self, data: T) -> NodeHandle<T> {
+        let node = Self::new_node(data);
+        let mut inner = self.inner.borrow_mut();
+        match inner.tail.take() {
+            Some(old_tail) => {
+                node.borrow_mut().prev = Rc::downgrade(&old_tail);
+                old_tail.borrow_mut().next = Some(Rc::clone(&node));
+            }
+            None => inner.head = Some(Rc::clone(&node)),
+        }
+        inner.tail = Some(Rc::clone(&node));
+        inner.len += 1;
+        NodeHandle { node }
+    }
+
+    /// Inserts an element at the front of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - A handle to the newly inserted node.
+    pub fn push_front(&self, data: T) -> NodeHandle<T> {
+        let node = Self::new_node(data);
+        let mut inner = self.inner.borrow_mut();
+        match inner.head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Rc::downgrade(&node);
+                node.borrow_mut().next = Some(old_head);
+            }
+            None => inner.tail = Some(Rc::clone(&node)),
+        }
+        inner.head = Some(Rc::clone(&node));
+        inner.len += 1;
+        NodeHandle { node }
+    }
+
+    /// Removes and returns the first element.
+    ///
+    /// Any outstanding handle to the removed node becomes detached.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_front(&self) -> Option<T> {
+        let mut inner = self.inner.borrow_mut();
+        let head = inner.head.take()?;
+        let mut node = head.borrow_mut();
+        match node.next.take() {
+            Some(next) => {
+                next.borrow_mut().prev = Weak::new();
+                inner.head = Some(next);
+            }
+            None => inner.tail = None,
+        }
+        inner.len -= 1;
+        node.data.take()
+    }
+
+    /// Removes and returns the last element.
+    ///
+    /// Any outstanding handle to the removed node becomes detached.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_back(&self) -> Option<T> {
+        let mut inner = self.inner.borrow_mut();
+        let tail = inner.tail.take()?;
+        let mut node = tail.borrow_mut();
+        match node.prev.upgrade() {
+            Some(prev) => {
+                prev.borrow_mut().next = None;
+                inner.tail = Some(prev);
+            }
+            None => inner.head = None,
+        }
+        node.prev = Weak::new();
+        inner.len -= 1;
+        node.data.take()
+    }
+
+    /// Returns a handle to the first node, or `None` if the list is empty.
+    pub fn front(&self) -> Option<NodeHandle<T>> {
+        let node = self.inner.borrow().head.clone()?;
+        Some(NodeHandle { node })
+    }
+
+    /// Returns a handle to the last node, or `None` if the list is empty.
+    pub fn back(&self) -> Option<NodeHandle<T>> {
+        let node = self.inner.borrow().tail.clone()?;
+        Some(NodeHandle { node })
+    }
+
+    /// Returns a handle to the node at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the node.
+    ///
+    /// # Returns
+    /// - `Some(NodeHandle)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn node_at(&self, index: usize) -> Option<NodeHandle<T>> {
+        let mut current = self.front();
+        for _ in 0..index {
+            current = current?.next();
+        }
+        current
+    }
+
+    /// Returns a clone of the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn get(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.node_at(index)?.borrow().map(|data| data.clone())
+    }
+
+    /// Checks whether a given value exists in the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to search for.
+    pub fn find(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut current = self.front();
+        while let Some(handle) = current {
+            if handle.borrow().is_some_and(|item| &*item == data) {
+                return true;
+            }
+            current = handle.next();
+        }
+        false
+    }
+
+    /// Returns a snapshot of the list's contents as a `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut values = Vec::with_capacity(self.len());
+        let mut current = self.front();
+        while let Some(handle) = current {
+            if let Some(data) = handle.borrow() {
+                values.push(data.clone());
+            }
+            current = handle.next();
+        }
+        values
+    }
+
+    /// Allocates a new, unlinked node.
+    fn new_node(data: T) -> NodeRef<T> {
+        Rc::new(RefCell::new(Node {
+            data: Some(data),
+            next: None,
+            prev: Weak::new(),
+        }))
+    }
+}
+
+impl<T> Clone for RcLinkedList<T> {
+    /// Returns another owner of the same list. Mutations through either are visible to both.
+    fn clone(&self) -> Self {
+        RcLinkedList {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    /// Unlinks nodes one at a time so long lists don't recurse through `Rc` drops.
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            current = node.borrow_mut().next.take();
+        }
+    }
+}
+
+impl<T> Default for RcLinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for RcLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        let mut current = self.front();
+        while let Some(handle) = current {
+            if let Some(data) = handle.borrow() {
+                list.entry(&*data);
+            }
+            current = handle.next();
+        }
+        list.finish()
+    }
+}
+
+impl<T> NodeHandle<T> {
+    /// Immutably borrows the node's data.
+    ///
+    /// # Returns
+    /// - `Some(Ref<T>)` while the node is still in its list.
+    /// - `None` if the element has been removed.
+    pub fn borrow(&self) -> Option<Ref<'_, T>> {
+        Ref::filter_map(self.node.borrow(), |node| node.data.as_ref()).ok()
+    }
+
+    /// Mutably borrows the node's data.
+    ///
+    /// # Returns
+    /// - `Some(RefMut<T>)` while the node is still in its list.
+    /// - `None` if the element has been removed.
+    pub fn borrow_mut(&self) -> Option<RefMut<'_, T>> {
+        RefMut::filter_map(self.node.borrow_mut(), |node| node.data.as_mut()).ok()
+    }
+
+    /// Replaces the node's data, returning the previous value.
+    ///
+    /// # Returns
+    /// - `Ok(T)` with the old value on success.
+    /// - `Err(data)` handing the value back if the element has been removed.
+    pub fn replace(&self, data: T) -> Result<T, T> {
+        match self.borrow_mut() {
+            Some(mut current) => Ok(core::mem::replace(&mut *current, data)),
+            None => Err(data),
+        }
+    }
+
+    /// Returns `true` while the element is still part of its list.
+    pub fn is_linked(&self) -> bool {
+        self.node.borrow().data.is_some()
+    }
+
+    /// Returns a handle to the following node, if any.
+    pub fn next(&self) -> Option<NodeHandle<T>> {
+        let node = self.node.borrow().next.clone()?;
+        Some(NodeHandle { node })
+    }
+
+    /// Returns a handle to the preceding node, if any.
+    pub fn prev(&self) -> Option<NodeHandle<T>> {
+        let node = self.node.borrow().prev.upgrade()?;
+        Some(NodeHandle { node })
+    }
+
+    /// Returns `true` if both handles refer to the same node.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.node, &other.node)
+    }
+
+    /// Creates a weak handle to the same node, which does not keep the node alive.
+    pub fn downgrade(&self) -> WeakNodeHandle<T> {
+        WeakNodeHandle {
+            node: Rc::downgrade(&self.node),
+        }
+    }
+}
+
+impl<T> WeakNodeHandle<T> {
+    /// Returns a strong handle to the node if its element is still in the list.
+    ///
+    /// # Returns
+    /// - `Some(NodeHandle)` while the element is in its list.
+    /// - `None` once it has been removed, even if another `NodeHandle` still keeps the
+    ///   detached node alive.
+    pub fn upgrade(&self) -> Option<NodeHandle<T>> {
+        let node = self.node.upgrade()?;
+        let handle = NodeHandle { node };
+        handle.is_linked().then_some(handle)
+    }
+
+    /// Returns `true` if the node has been freed, because it was removed and no
+    /// `NodeHandle` to it is left.
+    pub fn is_dangling(&self) -> bool {
+        self.node.strong_count() == 0
+    }
+}
+
+impl<T> Clone for WeakNodeHandle<T> {
+    fn clone(&self) -> Self {
+        WeakNodeHandle {
+            node: Weak::clone(&self.node),
+        }
+    }
+}
+
+impl<T> Debug for WeakNodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WeakNodeHandle")
+    }
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        NodeHandle {
+            node: Rc::clone(&self.node),
+        }
+    }
+}
+
+impl<T: Debug> Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NodeHandle").field(&self.node.borrow().data).finish()
+    }
+}
@@ -0,0 +1,127 @@
+//! Memory reclamation for the lock-free types.
+//!
+//! A node unlinked from a lock-free structure cannot be freed right away, because another
+//! thread may still be reading it. Operations therefore [`pin`](Reclaimer::pin) a
+//! [`Guard`] for their whole duration and hand unlinked nodes to
+//! [`retire`](Reclaimer::retire) instead of freeing them.
+//!
+//! Retired nodes are freed by `crossbeam-epoch` once every thread that was pinned at the
+//! time has unpinned, so memory use stays bounded. `crossbeam-epoch` needs `std`, so the
+//! lock-free types do too.
+//!
+//! Under `--cfg loom`, which cannot model `crossbeam-epoch`'s own atomics, retired nodes
+//! are instead kept on a lock-free list and freed when the structure is dropped. That
+//! keeps every removed node until then, which suits the short-lived structures of a model
+//! check but nothing else.
+
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+
+#[cfg(loom)]
+use core::ptr;
+
+#[cfg(loom)]
+use crate::sync::{spin_loop, AtomicPtr, Ordering};
+
+/// Keeps the current thread's view of the structure valid while it is held.
+#[cfg(not(loom))]
+pub(crate) use crossbeam_epoch::Guard;
+
+/// Keeps the current thread's view of the structure valid while it is held. Under loom,
+/// nothing is freed before the structure is dropped, so this is a no-op.
+#[cfg(loom)]
+pub(crate) struct Guard {
+    _private: (),
+}
+
+/// An entry on the retired list.
+#[cfg(loom)]
+struct Retired<N> {
+    /// The retired node.
+    node: *mut N,
+    /// The next entry.
+    next: *mut Retired<N>,
+}
+
+/// `Reclaimer` frees the nodes of type `N` that a lock-free structure has unlinked.
+pub(crate) struct Reclaimer<N> {
+    /// Retired nodes waiting for the structure to be dropped.
+    #[cfg(loom)]
+    retired: AtomicPtr<Retired<N>>,
+    /// Marks ownership of the retired nodes.
+    _marker: PhantomData<Box<N>>,
+}
+
+impl<N> Reclaimer<N> {
+    /// Creates a reclaimer with nothing retired.
+    pub(crate) fn new() -> Self {
+        Reclaimer {
+            #[cfg(loom)]
+            retired: AtomicPtr::new(ptr::null_mut()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Pins the current thread. No node retired after this call is freed before the
+    /// returned guard is dropped.
+    pub(crate) fn pin(&self) -> Guard {
+        #[cfg(not(loom))]
+        return crossbeam_epoch::pin();
+        #[cfg(loom)]
+        Guard { _private: () }
+    }
+
+    /// Frees `node`, a `Box` leaked with `Box::into_raw`, once no pinned thread can
+    /// still be reading it.
+    ///
+    /// # Safety
+    /// `node` must have been unlinked by the caller, exactly once, so that threads pinning
+    /// from now on cannot reach it. Dropping `N` must be safe on any thread.
+    #[cfg(not(loom))]
+    pub(crate) unsafe fn retire(&self, node: *mut N, guard: &Guard) {
+        guard.defer_unchecked(move || drop(Box::from_raw(node)));
+    }
+
+    /// Frees `node`, a `Box` leaked with `Box::into_raw`, once no pinned thread can
+    /// still be reading it.
+    ///
+    /// # Safety
+    /// `node` must have been unlinked by the caller, exactly once, so that threads pinning
+    /// from now on cannot reach it. Dropping `N` must be safe on any thread.
+    #[cfg(loom)]
+    pub(crate) unsafe fn retire(&self, node: *mut N, _guard: &Guard) {
+        let entry = Box::into_raw(Box::new(Retired {
+            node,
+            next: self.retired.load(Ordering::Relaxed),
+        }));
+        loop {
+            match self.retired.compare_exchange_weak(
+                (*entry).next,
+                entry,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => {
+                    (*entry).next = current;
+                    spin_loop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(loom)]
+impl<N> Drop for Reclaimer<N> {
+    /// Frees every retired node.
+    fn drop(&mut self) {
+        let mut current = self.retired.load(Ordering::Acquire);
+        while !current.is_null() {
+            // SAFETY: `&mut self` means no thread is pinned on the structure any more, and
+            // each entry and node was leaked from a `Box` exactly once.
+            let entry = unsafe { Box::from_raw(current) };
+            drop(unsafe { Box::from_raw(entry.node) });
+            current = entry.next;
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Plain-text drawings of list structure, for terminals and test snapshots.
+//!
+//! The lists' `render_ascii()` methods draw the chain as `[head] -> 3 -> 7 -> X`; lists
+//! with a slot array add a table of the slots below it.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+
+/// Draws the chain through `items`, front to back, ending in `X` for the null link.
+pub(crate) fn chain<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    let mut out = String::from("[head]");
+    for item in items {
+        let _ = write!(out, " -> {item}");
+    }
+    out.push_str(" -> X");
+    out
+}
+
+/// Lays out `rows` as left-aligned columns under `header`, two spaces apart, with
+/// trailing spaces trimmed from each line.
+pub(crate) fn table<const C: usize>(header: [&str; C], rows: &[[String; C]]) -> String {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let header = header.map(ToString::to_string);
+    let lines: Vec<String> = core::iter::once(&header)
+        .chain(rows)
+        .map(|row| {
+            let mut line = String::new();
+            for (cell, width) in row.iter().zip(widths) {
+                let _ = write!(line, "{cell:<width$}  ");
+            }
+            line.trim_end().to_string()
+        })
+        .collect();
+    lines.join("\n")
+}
@@ -0,0 +1,356 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display};
+
+use crate::LinkedListError;
+
+/// The maximum number of characters stored in a single chunk.
+const MAX_CHUNK: usize = 512;
+
+/// `Chunk` is a run of up to `MAX_CHUNK` characters in the rope.
+struct Chunk {
+    /// The text of this chunk.
+    text: String,
+    /// The number of `char`s in `text`.
+    chars: usize,
+    /// A pointer to the next chunk.
+    next: Option<Box<Chunk>>,
+}
+
+impl Chunk {
+    /// Allocates a chunk holding `text`, which must not exceed `MAX_CHUNK` characters.
+    fn new(text: String, chars: usize, next: Option<Box<Chunk>>) -> Box<Self> {
+        Box::new(Chunk { text, chars, next })
+    }
+
+    /// Returns the byte offset of the character at `offset` within this chunk.
+    fn byte_offset(&self, offset: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(offset)
+            .map_or(self.text.len(), |(byte, _)| byte)
+    }
+
+    /// Splits an oversized chunk into chunks of at most `MAX_CHUNK` characters, linked
+    /// in place of this one.
+    fn split(&mut self) {
+        let text = core::mem::take(&mut self.text);
+        let mut pieces = pieces(&text).into_iter();
+        let (first, first_chars) = pieces.next().expect("oversized chunk is not empty");
+        let mut next = self.next.take();
+        for (piece, chars) in pieces.rev() {
+            next = Some(Chunk::new(piece, chars, next));
+        }
+        self.text = first;
+        self.chars = first_chars;
+        self.next = next;
+    }
+
+    /// Moves the text of the next chunk into this one and unlinks it, provided it fits.
+    fn merge_next(&mut self) {
+        if let Some(mut next) = self.next.take() {
+            if self.chars + next.chars <= MAX_CHUNK {
+                self.text.push_str(&next.text);
+                self.chars += next.chars;
+                self.next = next.next.take();
+            } else {
+                self.next = Some(next);
+            }
+        }
+    }
+}
+
+/// Cuts `text` into pieces of at most `MAX_CHUNK` characters, with their character counts.
+fn pieces(text: &str) -> Vec<(String, usize)> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut chars = 0;
+    for (byte, _) in text.char_indices() {
+        if chars == MAX_CHUNK {
+            pieces.push((text[start..byte].to_string(), chars));
+            start = byte;
+            chars = 0;
+        }
+        chars += 1;
+    }
+    if chars > 0 {
+        pieces.push((text[start..].to_string(), chars));
+    }
+    pieces
+}
+
+/// `Rope` is a text buffer stored as a linked list of `String` chunks.
+///
+/// It is the text counterpart of `UnrolledLinkedList`: each chunk holds up to a fixed
+/// number of characters, so editing touches a single chunk instead of shifting the whole
+/// text. Inserting or removing at a character offset walks the chunk list and then costs
+/// O(chunk size); oversized chunks are split and undersized neighbours merged.
+///
+/// All offsets are counted in `char`s, not bytes.
+pub struct Rope {
+    /// The first chunk of the rope.
+    head: Option<Box<Chunk>>,
+    /// The number of characters in the rope.
+    len: usize,
+}
+
+impl Rope {
+    /// Creates a new, empty `Rope`.
+    ///
+    /// # Returns
+    /// - A new empty `Rope` instance.
+    pub fn new() -> Self {
+        Rope { head: None, len: 0 }
+    }
+
+    /// Returns the number of characters in the rope.
+    pub fn len_chars(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of bytes of UTF-8 text in the rope.
+    pub fn len_bytes(&self) -> usize {
+        self.chunks().map(str::len).sum()
+    }
+
+    /// Returns `true` if the rope contains no text.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of chunks the text is stored in.
+    pub fn chunk_count(&self) -> usize {
+        self.chunks().count()
+    }
+
+    /// Inserts `text` at character offset `index`.
+    ///
+    /// # Parameters
+    /// - `index`: The character offset to insert at.
+    /// - `text`: The text to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is past the end of the rope.
+    pub fn insert(&mut self, index: usize, text: &str) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+        let added = text.chars().count();
+        if added == 0 {
+            return Ok(());
+        }
+        self.len += added;
+
+        let Some(mut chunk) = self.head.as_deref_mut() else {
+            *self = Rope::from(text);
+            return Ok(());
+        };
+        let mut offset = index;
+        while offset > chunk.chars {
+            offset -= chunk.chars;
+            chunk = chunk.next.as_deref_mut().expect("offset must be in bounds");
+        }
+        let byte = chunk.byte_offset(offset);
+        chunk.text.insert_str(byte, text);
+        chunk.chars += added;
+        if chunk.chars > MAX_CHUNK {
+            chunk.split();
+        }
+        Ok(())
+    }
+
+    /// Appends `text` to the end of the rope.
+    pub fn push_str(&mut self, text: &str) {
+        self.insert(self.len, text)
+            .expect("the end of the rope is always in bounds");
+    }
+
+    /// Removes the characters in `start..end`.
+    ///
+    /// # Parameters
+    /// - `start`: The character offset of the first character to remove.
+    /// - `end`: The character offset just past the last character to remove.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the range is reversed or past the end.
+    pub fn remove(&mut self, start: usize, end: usize) -> Result<(), LinkedListError> {
+        if start > end || end > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index: start.max(end),
+                len: self.len,
+            });
+        }
+        let mut offset = start;
+        let mut remaining = end - start;
+        self.len -= remaining;
+
+        let mut link = &mut self.head;
+        while remaining > 0 {
+            let chunk = link.as_mut().expect("range must be in bounds");
+            if offset >= chunk.chars {
+                offset -= chunk.chars;
+                link = &mut link.as_mut().unwrap().next;
+                continue;
+            }
+            let taken = remaining.min(chunk.chars - offset);
+            let from = chunk.byte_offset(offset);
+            let to = chunk.byte_offset(offset + taken);
+            chunk.text.replace_range(from..to, "");
+            chunk.chars -= taken;
+            remaining -= taken;
+            offset = 0;
+
+            if chunk.chars == 0 {
+                let next = chunk.next.take();
+                *link = next;
+            } else {
+                if remaining == 0 && chunk.chars < MAX_CHUNK / 2 {
+                    chunk.merge_next();
+                }
+                link = &mut link.as_mut().unwrap().next;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the character at offset `index`, or `None` if it is out of bounds.
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        let mut offset = index;
+        for chunk in self.chunk_nodes() {
+            if offset < chunk.chars {
+                return chunk.text.chars().nth(offset);
+            }
+            offset -= chunk.chars;
+        }
+        None
+    }
+
+    /// Returns an iterator over the text of each chunk, in order.
+    pub fn chunks(&self) -> impl Iterator<Item = &str> + '_ {
+        self.chunk_nodes().map(|chunk| chunk.text.as_str())
+    }
+
+    /// Returns an iterator over the characters of the rope.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chunks().flat_map(str::chars)
+    }
+
+    /// Returns an iterator over the lines of the rope, with the same rules as
+    /// [`str::lines`]: lines end at `\n` or `\r\n`, and a final line ending is optional.
+    pub fn lines(&self) -> Lines<'_> {
+        Lines {
+            chars: Box::new(self.chars()),
+            done: false,
+        }
+    }
+
+    /// Returns an iterator over the chunk nodes.
+    fn chunk_nodes(&self) -> impl Iterator<Item = &Chunk> + '_ {
+        core::iter::successors(self.head.as_deref(), |chunk| chunk.next.as_deref())
+    }
+}
+
+impl Default for Rope {
+    /// Provides a default (empty) instance of the rope using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for Rope {
+    /// Builds a rope from `text`, split into full chunks.
+    fn from(text: &str) -> Self {
+        let mut head = None;
+        let mut len = 0;
+        for (piece, chars) in pieces(text).into_iter().rev() {
+            len += chars;
+            head = Some(Chunk::new(piece, chars, head));
+        }
+        Rope { head, len }
+    }
+}
+
+impl From<&Rope> for String {
+    /// Concatenates the chunks of the rope into a single `String`.
+    fn from(rope: &Rope) -> Self {
+        rope.chunks().collect()
+    }
+}
+
+impl PartialEq<str> for Rope {
+    fn eq(&self, other: &str) -> bool {
+        self.len_bytes() == other.len() && self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for Rope {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl Drop for Rope {
+    /// Unlinks chunks one at a time so long ropes don't recurse through `Box` drops.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut chunk) = current {
+            current = chunk.next.take();
+        }
+    }
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Rope").field(&String::from(self)).finish()
+    }
+}
+
+/// An iterator over the lines of a `Rope`.
+pub struct Lines<'a> {
+    /// The characters not yet consumed.
+    chars: Box<dyn Iterator<Item = char> + 'a>,
+    /// Set once the end of the text has been reached.
+    done: bool,
+}
+
+impl Iterator for Lines<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+        let mut line = String::new();
+        loop {
+            match self.chars.next() {
+                Some('\n') => {
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                    return Some(line);
+                }
+                Some(c) => line.push(c),
+                None => {
+                    self.done = true;
+                    return if line.is_empty() { None } else { Some(line) };
+                }
+            }
+        }
+    }
+}
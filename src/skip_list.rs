@@ -0,0 +1,431 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+use crate::slots::SlotNode;
+
+pub use crate::slots::IterMut;
+
+/// The number of levels every tower is capped at. Enough for about 2^16 elements before
+/// searches start to degrade.
+const MAX_LEVEL: usize = 16;
+
+/// The slot of the head sentinel, which has a full-height tower and no data.
+const HEAD: usize = 0;
+
+/// `Link` is one forward pointer of a tower.
+#[derive(Debug, Clone, Copy)]
+struct Link {
+    /// The slot of the next node on this level, or `None` at the end of the level.
+    next: Option<usize>,
+    /// How many positions the link spans on the bottom level. A `None` link spans to a
+    /// virtual node one past the last element.
+    width: usize,
+}
+
+/// `Node` represents a single element of the skip list together with its tower.
+#[derive(Debug)]
+struct Node<T> {
+    /// The data stored in the node. `None` only for the head sentinel and freed slots.
+    data: Option<T>,
+    /// The forward links, lowest level first.
+    links: Vec<Link>,
+}
+
+/// `IndexedSkipList` is a skip list ordered by position, with O(log n) positional access.
+///
+/// The bottom level is an ordinary singly linked list of every element; each higher level
+/// skips over roughly twice as many elements as the one below. Every link records its
+/// *width*, the number of elements it jumps over, so a search can descend towards index
+/// `i` by summing widths. `get`, `insert_at_index`, `delete_at_index` and
+/// `update_element_at_index` all run in expected O(log n), instead of the O(n) walk of
+/// `DynamicLinkedList` and `StaticLinkedList`.
+///
+/// Nodes live in a `Vec` with a free list, and tower heights come from a small built-in
+/// xorshift generator, so the structure is deterministic for a given sequence of
+/// operations.
+#[derive(Debug)]
+pub struct IndexedSkipList<T> {
+    /// Node storage. Slot `HEAD` is the sentinel.
+    nodes: Vec<Node<T>>,
+    /// Slots of freed nodes, available for reuse.
+    free: Vec<usize>,
+    /// The number of elements in the list.
+    len: usize,
+    /// State of the xorshift generator choosing tower heights.
+    rng: u64,
+}
+
+impl<T> IndexedSkipList<T> {
+    /// Creates a new, empty `IndexedSkipList`.
+    ///
+    /// # Returns
+    /// - A new empty `IndexedSkipList` instance.
+    pub fn new() -> Self {
+        IndexedSkipList {
+            nodes: vec![Node {
+                data: None,
+                links: vec![Link { next: None, width: 1 }; MAX_LEVEL],
+            }],
+            free: Vec::new(),
+            len: 0,
+            rng: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts an element at the back of the list in expected O(log n).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        self.insert_at(self.len, data);
+    }
+
+    /// Removes and returns the element at `index` in expected O(log n).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        Some(self.remove_at(index))
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            current: self.nodes[HEAD].links[0].next,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    ///
+    /// Nodes only link forwards, so iterating it from the back walks from the front for
+    /// every element.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let first = self.nodes[HEAD].links[0].next;
+        IterMut::new(&mut self.nodes, first, None, self.len)
+    }
+
+    /// Returns the slot of the element at `index`, which must be less than `len`.
+    fn slot_at(&self, index: usize) -> usize {
+        let target = index + 1;
+        let mut slot = HEAD;
+        let mut rank = 0;
+        for level in (0..MAX_LEVEL).rev() {
+            while let Link { next: Some(next), width } = self.nodes[slot].links[level] {
+                if rank + width > target {
+                    break;
+                }
+                rank += width;
+                slot = next;
+            }
+        }
+        debug_assert_eq!(rank, target);
+        slot
+    }
+
+    /// Returns, for every level, the last node before position `rank` and that node's rank.
+    fn predecessors(&self, rank: usize) -> [(usize, usize); MAX_LEVEL] {
+        let mut update = [(HEAD, 0); MAX_LEVEL];
+        let mut slot = HEAD;
+        let mut current = 0;
+        for level in (0..MAX_LEVEL).rev() {
+            while let Link { next: Some(next), width } = self.nodes[slot].links[level] {
+                if current + width >= rank {
+                    break;
+                }
+                current += width;
+                slot = next;
+            }
+            update[level] = (slot, current);
+        }
+        update
+    }
+
+    /// Picks a tower height: 1 with probability 1/2, 2 with probability 1/4, and so on.
+    fn random_level(&mut self) -> usize {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng.trailing_ones() as usize + 1).min(MAX_LEVEL)
+    }
+
+    /// Inserts `data` so that it ends up at `index`, which must be at most `len`.
+    fn insert_at(&mut self, index: usize, data: T) {
+        let rank = index + 1;
+        let update = self.predecessors(rank);
+        let height = self.random_level();
+
+        let mut links = Vec::with_capacity(height);
+        for (level, &(prev, prev_rank)) in update.iter().enumerate().take(height) {
+            let link = self.nodes[prev].links[level];
+            links.push(Link {
+                next: link.next,
+                width: prev_rank + link.width + 1 - rank,
+            });
+        }
+        let node = Node {
+            data: Some(data),
+            links,
+        };
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = node;
+                slot
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+
+        for (level, &(prev, prev_rank)) in update.iter().enumerate() {
+            let link = &mut self.nodes[prev].links[level];
+            if level < height {
+                *link = Link {
+                    next: Some(slot),
+                    width: rank - prev_rank,
+                };
+            } else {
+                link.width += 1;
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Unlinks and returns the element at `index`, which must be less than `len`.
+    fn remove_at(&mut self, index: usize) -> T {
+        let update = self.predecessors(index + 1);
+        let slot = self.nodes[update[0].0].links[0].next.expect("index must be in bounds");
+        let removed = core::mem::take(&mut self.nodes[slot].links);
+
+        for (level, &(prev, _)) in update.iter().enumerate() {
+            let link = &mut self.nodes[prev].links[level];
+            match removed.get(level) {
+                Some(gone) if link.next == Some(slot) => {
+                    link.next = gone.next;
+                    link.width += gone.width - 1;
+                }
+                _ => link.width -= 1,
+            }
+        }
+        self.free.push(slot);
+        self.len -= 1;
+        self.nodes[slot].data.take().expect("linked slot must be occupied")
+    }
+}
+
+impl<T> Default for IndexedSkipList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlotNode for Node<T> {
+    type Item = T;
+
+    fn data(&mut self) -> &mut T {
+        self.data.as_mut().expect("linked node must hold data")
+    }
+
+    fn next(&self) -> Option<usize> {
+        self.links[0].next
+    }
+}
+
+impl<T> ListRead<T> for IndexedSkipList<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index in expected O(log n).
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        self.nodes[self.slot_at(index)].data.as_ref()
+    }
+}
+
+impl<T> ListWrite<T> for IndexedSkipList<T> {
+    /// Inserts an element at the end (tail) of the list in expected O(log n).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in expected O(log n).
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        self.insert_at(index, data);
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.position(data) {
+            Some(index) => {
+                self.remove_at(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index in expected O(log n).
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        match self.remove(index) {
+            Some(_) => Ok(()),
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = self.slot_at(index);
+        self.nodes[slot].data.as_mut()
+    }
+
+    /// Updates the element at the specified index in expected O(log n).
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let slot = self.slot_at(index);
+        self.nodes[slot].data = Some(data);
+        Ok(())
+    }
+}
+
+impl<T> LinkedListTrait<T> for IndexedSkipList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        IndexedSkipList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        IndexedSkipList::iter_mut(self)
+    }
+}
+
+/// An iterator over the elements of an `IndexedSkipList`.
+pub struct Iter<'a, T> {
+    /// The list being iterated.
+    list: &'a IndexedSkipList<T>,
+    /// The slot of the next node to yield.
+    current: Option<usize>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = &self.list.nodes[self.current?];
+        self.current = node.links[0].next;
+        node.data.as_ref()
+    }
+}
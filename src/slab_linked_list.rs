@@ -0,0 +1,666 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::iter;
+use core::mem;
+
+use crate::footprint::{self, MemoryFootprint};
+use crate::integrity::{IntegrityReport, SlotState, Violation};
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+use crate::slots::SlotNode;
+
+pub use crate::slots::IterMut;
+
+/// `Key` is a stable handle to an element of a `SlabLinkedList`.
+///
+/// A key stays valid across any number of unrelated insertions and removals. Once its
+/// element is removed the key is permanently stale: the slot's generation moves on, so
+/// the key can never accidentally refer to a later element that reuses the slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    /// The slot index in the slab.
+    index: usize,
+    /// The generation of the slot when the key was handed out.
+    generation: u64,
+}
+
+/// `Slot` is either a linked element or a member of the free list.
+#[derive(Debug)]
+enum Slot<T> {
+    /// A live element together with its neighbours' slot indices.
+    Occupied {
+        data: T,
+        prev: Option<usize>,
+        next: Option<usize>,
+    },
+    /// An unused slot, linking to the next free slot.
+    Free { next_free: Option<usize> },
+}
+
+/// `Entry` pairs a slot with its current generation.
+#[derive(Debug)]
+struct Entry<T> {
+    /// Incremented each time the slot is freed, invalidating outstanding keys.
+    generation: u64,
+    /// The slot contents.
+    slot: Slot<T>,
+}
+
+/// `SlabLinkedList` is a doubly linked list stored in a slab with generational keys.
+///
+/// Every insertion returns a [`Key`] that can later be used to read, update, or remove
+/// that element in O(1), without searching or index arithmetic. Freed slots are kept in
+/// an intrusive free list and reused, and their generation counter is bumped so that
+/// stale keys are detected rather than silently aliasing a new element.
+#[derive(Debug)]
+pub struct SlabLinkedList<T> {
+    /// Slot storage.
+    entries: Vec<Entry<T>>,
+    /// The first free slot, if any.
+    free_head: Option<usize>,
+    /// The slot of the first element.
+    head: Option<usize>,
+    /// The slot of the last element.
+    tail: Option<usize>,
+    /// The number of elements in the list.
+    len: usize,
+}
+
+impl<T> SlabLinkedList<T> {
+    /// Creates a new, empty `SlabLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `SlabLinkedList` instance.
+    pub fn new() -> Self {
+        SlabLinkedList {
+            entries: Vec::new(),
+            free_head: None,
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns how much memory the list takes up: the slab, including free and spare
+    /// slots. Each slot carries a generation counter as well as its links.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        MemoryFootprint {
+            nodes: self.len,
+            node_overhead: footprint::node_overhead::<Entry<T>, T>(),
+            total_bytes: mem::size_of::<Self>()
+                + self.entries.capacity() * mem::size_of::<Entry<T>>(),
+        }
+    }
+
+    /// Checks the structure of the list: the chain from the head stays within the slab,
+    /// ends at the tail without a cycle, has `len` nodes and links back correctly; and
+    /// every slot is either in the chain or in the free list, never both.
+    ///
+    /// # Returns
+    /// - An `IntegrityReport` with the number of linked and free slots, and each broken
+    ///   invariant.
+    pub fn validate(&self) -> IntegrityReport {
+        let mut violations = Vec::new();
+        let mut states = vec![SlotState::Unseen; self.entries.len()];
+        let mut chain_len = 0;
+        let mut last = None;
+        let mut current = self.head;
+        while let Some(slot) = current {
+            let Some(entry) = self.entries.get(slot) else {
+                violations.push(Violation::LinkOutOfRange {
+                    from: last,
+                    to: slot,
+                });
+                break;
+            };
+            if states[slot] == SlotState::Linked {
+                violations.push(Violation::Cycle { slot });
+                break;
+            }
+            let Slot::Occupied { prev, next, .. } = entry.slot else {
+                violations.push(Violation::EmptySlotLinked { slot });
+                break;
+            };
+            if prev != last {
+                violations.push(Violation::BrokenBackLink {
+                    slot,
+                    prev,
+                    expected: last,
+                });
+            }
+            states[slot] = SlotState::Linked;
+            chain_len += 1;
+            last = Some(slot);
+            current = next;
+        }
+        if self.tail != last {
+            violations.push(Violation::TailMismatch {
+                tail: self.tail,
+                last,
+            });
+        }
+
+        let mut free_len = 0;
+        let mut current = self.free_head;
+        while let Some(slot) = current {
+            let Some(entry) = self.entries.get(slot) else {
+                violations.push(Violation::FreeOutOfRange { slot });
+                break;
+            };
+            let violation = match (states[slot], &entry.slot) {
+                (SlotState::Free, _) => Violation::FreedTwice { slot },
+                (SlotState::Linked, _) => Violation::LinkedAndFree { slot },
+                (SlotState::Unseen, Slot::Occupied { .. }) => Violation::FreeSlotOccupied { slot },
+                (SlotState::Unseen, Slot::Free { next_free }) => {
+                    states[slot] = SlotState::Free;
+                    free_len += 1;
+                    current = *next_free;
+                    continue;
+                }
+            };
+            // The free list is linked through the free slots, so it cannot be followed
+            // past this one.
+            violations.push(violation);
+            break;
+        }
+
+        if chain_len != self.len {
+            violations.push(Violation::LengthMismatch {
+                chain: chain_len,
+                len: self.len,
+            });
+        }
+        for (slot, state) in states.iter().enumerate() {
+            if *state == SlotState::Unseen {
+                violations.push(Violation::Leaked { slot });
+            }
+        }
+        IntegrityReport {
+            chain_len,
+            free_len,
+            violations,
+        }
+    }
+
+    /// Panics if `validate` finds a broken invariant, in debug builds with the
+    /// `strict-invariants` feature; otherwise does nothing.
+    fn check_invariants(&self) {
+        #[cfg(all(feature = "strict-invariants", debug_assertions))]
+        {
+            let report = self.validate();
+            assert!(report.is_ok(), "SlabLinkedList invariant broken: {report}");
+        }
+    }
+
+    /// Inserts an element at the back of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - The key of the new element.
+    pub fn push_back(&mut self, data: T) -> Key {
+        self.link_between(data, self.tail, None)
+    }
+
+    /// Inserts an element at the front of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - The key of the new element.
+    pub fn push_front(&mut self, data: T) -> Key {
+        self.link_between(data, None, self.head)
+    }
+
+    /// Inserts an element directly after the element identified by `key`.
+    ///
+    /// # Parameters
+    /// - `key`: The key of the element to insert after.
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(Key)` with the key of the new element.
+    /// - `Err(LinkedListError::StaleKey)` if `key` no longer refers to an element.
+    pub fn insert_after(&mut self, key: Key, data: T) -> Result<Key, LinkedListError> {
+        let next = match self.links(key) {
+            Some((_, next)) => next,
+            None => return Err(LinkedListError::StaleKey),
+        };
+        Ok(self.link_between(data, Some(key.index), next))
+    }
+
+    /// Returns a reference to the element identified by `key`.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the key is live.
+    /// - `None` if the element has been removed.
+    pub fn get(&self, key: Key) -> Option<&T> {
+        match self.entries.get(key.index) {
+            Some(Entry {
+                generation,
+                slot: Slot::Occupied { data, .. },
+            }) if *generation == key.generation => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element identified by `key`.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the key is live.
+    /// - `None` if the element has been removed.
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        match self.entries.get_mut(key.index) {
+            Some(Entry {
+                generation,
+                slot: Slot::Occupied { data, .. },
+            }) if *generation == key.generation => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `key` still refers to an element of the list.
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes the element identified by `key` in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` with the removed value if the key was live.
+    /// - `None` if the key is stale.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        self.links(key)?;
+        Some(self.unlink(key.index))
+    }
+
+    /// Returns the key of the element at logical position `index`.
+    pub fn key_at(&self, index: usize) -> Option<Key> {
+        self.slot_at(index).map(|slot| self.key_for(slot))
+    }
+
+    /// Returns an iterator over `(Key, &T)` pairs, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            current: self.head,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back. Unlike
+    /// [`iter`](Self::iter) it yields no keys; use [`get_mut`](Self::get_mut) to modify
+    /// an element by key.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&mut self.entries, self.head, self.tail, self.len)
+    }
+
+    /// Returns the key currently referring to the occupied slot `index`.
+    fn key_for(&self, index: usize) -> Key {
+        Key {
+            index,
+            generation: self.entries[index].generation,
+        }
+    }
+
+    /// Returns `(prev, next)` for a live key, or `None` if the key is stale.
+    fn links(&self, key: Key) -> Option<(Option<usize>, Option<usize>)> {
+        match self.entries.get(key.index) {
+            Some(Entry {
+                generation,
+                slot: Slot::Occupied { prev, next, .. },
+            }) if *generation == key.generation => Some((*prev, *next)),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the `prev` link of an occupied slot.
+    fn prev_mut(&mut self, index: usize) -> &mut Option<usize> {
+        match &mut self.entries[index].slot {
+            Slot::Occupied { prev, .. } => prev,
+            Slot::Free { .. } => unreachable!("linked slot must be occupied"),
+        }
+    }
+
+    /// Returns a mutable reference to the `next` link of an occupied slot.
+    fn next_mut(&mut self, index: usize) -> &mut Option<usize> {
+        match &mut self.entries[index].slot {
+            Slot::Occupied { next, .. } => next,
+            Slot::Free { .. } => unreachable!("linked slot must be occupied"),
+        }
+    }
+
+    /// Returns the `next` link of an occupied slot.
+    fn next_of(&self, index: usize) -> Option<usize> {
+        match &self.entries[index].slot {
+            Slot::Occupied { next, .. } => *next,
+            Slot::Free { .. } => unreachable!("linked slot must be occupied"),
+        }
+    }
+
+    /// Stores `data` in a free slot (or a new one) linked between `prev` and `next`.
+    ///
+    /// # Returns
+    /// - The key of the new element.
+    fn link_between(&mut self, data: T, prev: Option<usize>, next: Option<usize>) -> Key {
+        let slot = Slot::Occupied { data, prev, next };
+        let index = match self.free_head {
+            Some(index) => {
+                if let Slot::Free { next_free } = self.entries[index].slot {
+                    self.free_head = next_free;
+                }
+                self.entries[index].slot = slot;
+                index
+            }
+            None => {
+                self.entries.push(Entry { generation: 0, slot });
+                self.entries.len() - 1
+            }
+        };
+
+        match prev {
+            Some(prev) => *self.next_mut(prev) = Some(index),
+            None => self.head = Some(index),
+        }
+        match next {
+            Some(next) => *self.prev_mut(next) = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.len += 1;
+        self.check_invariants();
+        self.key_for(index)
+    }
+
+    /// Unlinks the occupied slot `index`, frees it, and returns its data.
+    fn unlink(&mut self, index: usize) -> T {
+        let entry = &mut self.entries[index];
+        entry.generation += 1;
+        let slot = core::mem::replace(
+            &mut entry.slot,
+            Slot::Free {
+                next_free: self.free_head,
+            },
+        );
+        self.free_head = Some(index);
+        self.len -= 1;
+
+        let Slot::Occupied { data, prev, next } = slot else {
+            unreachable!("linked slot must be occupied");
+        };
+        match prev {
+            Some(prev) => *self.next_mut(prev) = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => *self.prev_mut(next) = prev,
+            None => self.tail = prev,
+        }
+        self.check_invariants();
+        data
+    }
+
+    /// Returns the slot at logical position `index`.
+    fn slot_at(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+        let mut current = self.head;
+        for _ in 0..index {
+            current = self.next_of(current?);
+        }
+        current
+    }
+
+    /// Returns the slot of the first element equal to `data`.
+    fn slot_of<Q>(&self, data: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.iter()
+            .find(|(_, item)| (*item).borrow() == data)
+            .map(|(key, _)| key.index)
+    }
+}
+
+impl<T> Default for SlabLinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlotNode for Entry<T> {
+    type Item = T;
+
+    fn data(&mut self) -> &mut T {
+        match &mut self.slot {
+            Slot::Occupied { data, .. } => data,
+            Slot::Free { .. } => panic!("linked slot must be occupied"),
+        }
+    }
+
+    fn next(&self) -> Option<usize> {
+        match self.slot {
+            Slot::Occupied { next, .. } => next,
+            Slot::Free { .. } => None,
+        }
+    }
+
+    fn prev(&self) -> Option<usize> {
+        match self.slot {
+            Slot::Occupied { prev, .. } => prev,
+            Slot::Free { .. } => None,
+        }
+    }
+}
+
+impl<T> ListRead<T> for SlabLinkedList<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(|(_, item)| predicate(item))
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.key_at(index).and_then(|key| SlabLinkedList::get(self, key))
+    }
+}
+
+impl<T> ListWrite<T> for SlabLinkedList<T> {
+    /// Inserts an element at the end (tail) of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        match self.slot_at(index) {
+            Some(next) => {
+                let prev = self.links(self.key_for(next)).and_then(|(prev, _)| prev);
+                self.link_between(data, prev, Some(next));
+            }
+            None => {
+                self.push_back(data);
+            }
+        }
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.slot_of(data) {
+            Some(index) => {
+                self.unlink(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        match self.slot_at(index) {
+            Some(slot) => {
+                self.unlink(slot);
+                Ok(())
+            }
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let key = self.key_at(index)?;
+        SlabLinkedList::get_mut(self, key)
+    }
+
+    /// Updates the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        match self.key_at(index) {
+            Some(key) => {
+                *self.get_mut(key).unwrap() = data;
+                Ok(())
+            }
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+}
+
+impl<T> LinkedListTrait<T> for SlabLinkedList<T> {
+    type Iter<'a>
+        = iter::Map<Iter<'a, T>, fn((Key, &'a T)) -> &'a T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back, without their keys.
+    fn iter(&self) -> Self::Iter<'_> {
+        SlabLinkedList::iter(self).map(|(_, data)| data)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        SlabLinkedList::iter_mut(self)
+    }
+}
+
+/// An iterator over the `(Key, &T)` pairs of a `SlabLinkedList`.
+pub struct Iter<'a, T> {
+    /// The list being iterated.
+    list: &'a SlabLinkedList<T>,
+    /// The next slot to yield.
+    current: Option<usize>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Key, &'a T);
+
+    fn next(&mut self) -> Option<(Key, &'a T)> {
+        let index = self.current?;
+        let entry = &self.list.entries[index];
+        match &entry.slot {
+            Slot::Occupied { data, next, .. } => {
+                self.current = *next;
+                Some((
+                    Key {
+                        index,
+                        generation: entry.generation,
+                    },
+                    data,
+                ))
+            }
+            Slot::Free { .. } => unreachable!("linked slot must be occupied"),
+        }
+    }
+}
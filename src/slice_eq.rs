@@ -0,0 +1,80 @@
+//! `PartialEq` between the lists and slices, arrays and vectors.
+//!
+//! A list equals a slice when it holds equal elements in the same order, so tests can
+//! write `assert_eq!(list, [1, 2, 3])` instead of checking each index. Like the slice
+//! impls in `core`, the element types may differ as long as `T: PartialEq<U>`. The
+//! comparison walks the list once and stops at the first difference.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::heapless_compat;
+use crate::static_linked_list::StaticLinkedList;
+#[cfg(feature = "alloc")]
+use crate::{
+    allocator::Alloc, circular_doubly_linked_list::CircularDoublyLinkedList,
+    circular_linked_list::CircularLinkedList, doubly_linked_list::DoublyLinkedList,
+    dynamic_linked_list::DynamicLinkedList, skip_list::IndexedSkipList, std_compat,
+    unrolled_linked_list::UnrolledLinkedList, xor_linked_list::XorLinkedList,
+};
+
+/// Implements `PartialEq` against `[U]`, `&[U]`, `[U; M]` and `Vec<U>` for a list with
+/// an `iter()` method yielding `&T`. The brackets hold any generic parameters besides `T`.
+macro_rules! impl_slice_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $list:ty;)*) => {
+        $(
+            $(#[$attr])*
+            impl<T: PartialEq<U>, U, $($generics)*> PartialEq<[U]> for $list {
+                fn eq(&self, other: &[U]) -> bool {
+                    self.iter().eq(other)
+                }
+            }
+
+            $(#[$attr])*
+            impl<T: PartialEq<U>, U, $($generics)*> PartialEq<&[U]> for $list {
+                fn eq(&self, other: &&[U]) -> bool {
+                    self.iter().eq(*other)
+                }
+            }
+
+            $(#[$attr])*
+            impl<T: PartialEq<U>, U, const M: usize, $($generics)*> PartialEq<[U; M]>
+                for $list
+            {
+                fn eq(&self, other: &[U; M]) -> bool {
+                    self.iter().eq(other)
+                }
+            }
+
+            $(#[$attr])*
+            #[cfg(feature = "alloc")]
+            impl<T: PartialEq<U>, U, $($generics)*> PartialEq<Vec<U>> for $list {
+                fn eq(&self, other: &Vec<U>) -> bool {
+                    self.iter().eq(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_eq! {
+    [const N: usize] StaticLinkedList<T, N>;
+    [const N: usize] heapless_compat::Vec<T, N>;
+    [const N: usize] heapless_compat::Deque<T, N>;
+    #[cfg(feature = "alloc")]
+    [] CircularDoublyLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [] CircularLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [] DoublyLinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [A: Alloc] DynamicLinkedList<T, A>;
+    #[cfg(feature = "alloc")]
+    [] IndexedSkipList<T>;
+    #[cfg(feature = "alloc")]
+    [] std_compat::LinkedList<T>;
+    #[cfg(feature = "alloc")]
+    [const B: usize] UnrolledLinkedList<T, B>;
+    #[cfg(feature = "alloc")]
+    [] XorLinkedList<T>;
+}
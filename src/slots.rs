@@ -0,0 +1,163 @@
+//! Mutable iteration for the lists that keep their nodes in a slot vector.
+//!
+//! Those lists link nodes by index, so walking them in list order cannot hand out a
+//! `&mut` borrow of one slot at a time through safe indexing: each borrow of the vector
+//! would lock the others out. Instead the iterator keeps a raw pointer to the slots and
+//! follows the links one node at a time, borrowing only the node it is about to yield.
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// A slot of a slot-based list, as walked by [`IterMut`].
+pub(crate) trait SlotNode {
+    /// The element type of the list.
+    type Item;
+
+    /// Returns the element stored in the slot, which must be occupied.
+    fn data(&mut self) -> &mut Self::Item;
+
+    /// Returns the slot of the next node in list order.
+    fn next(&self) -> Option<usize>;
+
+    /// Returns the slot of the previous node in list order.
+    ///
+    /// Lists that only link forwards keep the default, which makes
+    /// [`IterMut::next_back`] walk from the front instead.
+    fn prev(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// What [`IterMut`] reads from a node: its element and its neighbours' slots.
+struct Links<T> {
+    /// The node's element.
+    data: NonNull<T>,
+    /// The slot of the next node.
+    next: Option<usize>,
+    /// The slot of the previous node, or `None` if nodes do not link backwards.
+    prev: Option<usize>,
+}
+
+/// An iterator over mutable references to the elements of a slot-based list, front to
+/// back.
+///
+/// Each step follows one link, so creating the iterator is O(1) and does not allocate.
+/// On lists whose nodes only link forwards, each call to `next_back` walks from the
+/// front to the last remaining element.
+pub struct IterMut<'a, T> {
+    /// The list's slots, with their type erased so the iterator type does not depend on
+    /// how each list lays out its nodes.
+    slots: NonNull<()>,
+    /// Reads the node in a slot of `slots`.
+    read: unsafe fn(NonNull<()>, usize) -> Links<T>,
+    /// The next slot to yield from the front.
+    front: Option<usize>,
+    /// The next slot to yield from the back, or `None` if it has to be found by walking
+    /// from the front.
+    back: Option<usize>,
+    /// The number of elements not yet yielded from either end.
+    remaining: usize,
+    /// Tells the compiler the iterator holds the unique borrow of the elements.
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator is a unique borrow of the slots, like a `&mut [S]`, and only ever
+// hands out `&mut T`; the slot types hold nothing but the element and slot indices.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: `&IterMut` gives no access to the slots at all.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Creates an iterator over the `len` elements linked from `front`.
+    ///
+    /// # Parameters
+    /// - `slots`: The list's slot storage.
+    /// - `front`: The slot of the first element.
+    /// - `back`: The slot of the last element, or `None` to find it by walking from `front`.
+    /// - `len`: The number of linked elements.
+    pub(crate) fn new<S: SlotNode<Item = T>>(
+        slots: &'a mut [S],
+        front: Option<usize>,
+        back: Option<usize>,
+        len: usize,
+    ) -> Self {
+        IterMut {
+            slots: NonNull::from(slots).cast(),
+            read: read_slot::<S>,
+            front,
+            back,
+            remaining: len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reads the node in `slot`, which must be linked and not yet yielded.
+    fn links(&self, slot: usize) -> Links<T> {
+        // SAFETY: `slots` came from a `&'a mut [S]` matching `read`, and a linked slot is in
+        // bounds. Nothing borrows a slot that has not been yielded yet.
+        unsafe { (self.read)(self.slots, slot) }
+    }
+}
+
+/// Reads the node in `slot` of the erased slot storage `slots`.
+///
+/// # Safety
+/// `slots` must point to a live `[S]` with more than `slot` elements, and slot `slot` must
+/// be occupied and not borrowed elsewhere.
+unsafe fn read_slot<S: SlotNode>(slots: NonNull<()>, slot: usize) -> Links<S::Item> {
+    // SAFETY: upheld by the caller; the borrow covers this one slot only.
+    let node = unsafe { &mut *slots.cast::<S>().as_ptr().add(slot) };
+    Links {
+        next: node.next(),
+        prev: node.prev(),
+        data: NonNull::from(node.data()),
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let links = self.links(self.front?);
+        self.front = links.next;
+        // SAFETY: the slot is never read again, so this is the only borrow of its element,
+        // and the slots stay borrowed for `'a`.
+        Some(unsafe { &mut *links.data.as_ptr() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let slot = match self.back {
+            Some(slot) => slot,
+            None => {
+                let mut slot = self.front?;
+                for _ in 0..self.remaining {
+                    slot = self.links(slot).next?;
+                }
+                slot
+            }
+        };
+        let links = self.links(slot);
+        self.back = links.prev;
+        // SAFETY: as in `next`.
+        Some(unsafe { &mut *links.data.as_ptr() })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
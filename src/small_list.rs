@@ -0,0 +1,250 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::static_linked_list::StaticLinkedList;
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// Where a `SmallList` currently keeps its elements.
+#[derive(Debug)]
+enum Storage<T, const N: usize> {
+    /// Up to `N` elements in fixed slots, without a heap allocation per element.
+    Inline(StaticLinkedList<T, N>),
+    /// Any number of elements in individually boxed nodes.
+    Heap(DynamicLinkedList<T>),
+}
+
+/// `SmallList` is a linked list that stores up to `N` elements inline and spills to the
+/// heap when it grows past that.
+///
+/// While small, the elements live in a `StaticLinkedList` and inserting never allocates
+/// a node. Inserting an `N + 1`-th element moves everything into a `DynamicLinkedList`,
+/// so the list keeps working instead of reporting that it is full. Like `SmallVec`, the
+/// list stays on the heap once it has spilled.
+#[derive(Debug)]
+pub struct SmallList<T, const N: usize> {
+    /// The current storage.
+    storage: Storage<T, N>,
+    /// The number of elements in the list.
+    len: usize,
+}
+
+impl<T, const N: usize> SmallList<T, N> {
+    /// Creates a new, empty `SmallList` using inline storage.
+    ///
+    /// # Returns
+    /// - A new empty `SmallList` instance.
+    pub fn new() -> Self {
+        SmallList {
+            storage: Storage::Inline(StaticLinkedList::new()),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the elements are still stored inline.
+    pub fn is_inline(&self) -> bool {
+        matches!(self.storage, Storage::Inline(_))
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        match &self.storage {
+            Storage::Inline(list) => Box::new(list.iter()),
+            Storage::Heap(list) => Box::new(list.iter()),
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut T> + '_> {
+        match &mut self.storage {
+            Storage::Inline(list) => Box::new(list.iter_mut()),
+            Storage::Heap(list) => Box::new(list.iter_mut()),
+        }
+    }
+}
+
+impl<T, const N: usize> SmallList<T, N> {
+    /// Moves the elements to the heap if the inline storage is full.
+    fn spill_if_full(&mut self) {
+        let Storage::Inline(inline) = &mut self.storage else {
+            return;
+        };
+        if self.len < N {
+            return;
+        }
+        let mut items = Vec::with_capacity(N);
+        while let Some(data) = inline.pop_front() {
+            items.push(data);
+        }
+        let mut heap = DynamicLinkedList::new();
+        for data in items.into_iter().rev() {
+            heap.insert_at_index(0, data)
+                .expect("index 0 is always in bounds");
+        }
+        self.storage = Storage::Heap(heap);
+    }
+
+    /// Returns the active storage as a trait object.
+    fn list(&self) -> &dyn ListRead<T> {
+        match &self.storage {
+            Storage::Inline(list) => list,
+            Storage::Heap(list) => list,
+        }
+    }
+
+    /// Returns the active storage as a mutable trait object.
+    fn list_mut(&mut self) -> &mut dyn ListWrite<T> {
+        match &mut self.storage {
+            Storage::Inline(list) => list,
+            Storage::Heap(list) => list,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SmallList<T, N> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ListRead<T> for SmallList<T, N> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.list().position_by(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.list().get(index)
+    }
+}
+
+impl<T, const N: usize> ListWrite<T> for SmallList<T, N> {
+    /// Inserts an element at the end (tail) of the list, spilling to the heap if needed.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.spill_if_full();
+        self.list_mut().insert(data);
+        self.len += 1;
+    }
+
+    /// Inserts an element at a specific index in the list, spilling to the heap if needed.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        self.spill_if_full();
+        self.list_mut().insert_at_index(index, data)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        self.list_mut().delete_at_index(index)?;
+        self.len -= 1;
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list_mut().get_mut(index)
+    }
+
+    /// Updates the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list_mut().update_element_at_index(index, data)
+    }
+}
+
+impl<T, const N: usize> LinkedListTrait<T> for SmallList<T, N> {
+    type Iter<'a>
+        = Box<dyn Iterator<Item = &'a T> + 'a>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = Box<dyn Iterator<Item = &'a mut T> + 'a>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        SmallList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        SmallList::iter_mut(self)
+    }
+}
@@ -0,0 +1,198 @@
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+use core::ops::{Bound, RangeBounds};
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{LinkedListError, ListWrite};
+
+/// `SortedLinkedList` is a linked list that keeps its elements in ascending order.
+///
+/// `insert` places each element after any equal ones, so insertion order is preserved
+/// among equal elements. Because the order is known, [`contains`](Self::contains),
+/// [`remove`](Self::remove) and [`range`](Self::range) stop walking as soon as they pass
+/// the values they are looking for.
+///
+/// The elements live in a backing list `L`, which defaults to `DynamicLinkedList`. Any
+/// list implementing `ListWrite` that can be iterated by reference works, e.g.
+/// `SortedLinkedList<T, StaticLinkedList<T, N>>` for a fixed-capacity sorted list.
+///
+/// `SortedLinkedList` deliberately does not implement `ListWrite`, since
+/// positional insertion and updates could break the ordering.
+pub struct SortedLinkedList<T, L = DynamicLinkedList<T>> {
+    /// The backing list, always in ascending order.
+    list: L,
+    /// The number of elements in the list.
+    len: usize,
+    /// Marks the element type, which `L` only mentions through its trait impls.
+    _marker: PhantomData<T>,
+}
+
+impl<T> SortedLinkedList<T> {
+    /// Creates a new, empty `SortedLinkedList` backed by a `DynamicLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `SortedLinkedList` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Default> SortedLinkedList<T, L> {
+    /// Creates a new, empty `SortedLinkedList` using any backing list, e.g.
+    /// `SortedLinkedList::<i32, StaticLinkedList<i32, 8>>::empty()`.
+    ///
+    /// # Returns
+    /// - A new empty `SortedLinkedList` instance.
+    pub fn empty() -> Self {
+        SortedLinkedList {
+            list: L::default(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L> SortedLinkedList<T, L> {
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Consumes the sorted list and returns the backing list, still in order.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<T: Ord, L> SortedLinkedList<T, L>
+where
+    L: ListWrite<T>,
+    for<'a> &'a L: IntoIterator<Item = &'a T>,
+{
+    /// Inserts an element at its sorted position, after any equal elements.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` if the backing list rejected the element (e.g. it is full).
+    pub fn insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        let index = self
+            .iter()
+            .position(|item| *item > data)
+            .unwrap_or(self.len);
+        self.list.insert_at_index(index, data)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Checks whether a given value exists in the list, stopping at the first larger element.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to search for.
+    pub fn contains(&self, data: &T) -> bool {
+        self.position(data).is_some()
+    }
+
+    /// Removes the first element equal to `data`, stopping at the first larger element.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to remove.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    pub fn remove(&mut self, data: &T) -> bool {
+        match self.position(data) {
+            Some(index) => {
+                self.list
+                    .delete_at_index(index)
+                    .expect("position must be in bounds");
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+
+    /// Returns a reference to the smallest element, or `None` if the list is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    /// Returns an iterator over the elements in ascending order.
+    pub fn iter(&self) -> <&L as IntoIterator>::IntoIter {
+        (&self.list).into_iter()
+    }
+
+    /// Returns an iterator over the elements that fall within `range`, in ascending order.
+    ///
+    /// Elements below the range are skipped and iteration stops at the first element
+    /// past its end, so the rest of the list is never visited.
+    ///
+    /// # Parameters
+    /// - `range`: The bounds to select, e.g. `3..7` or `..=10`.
+    pub fn range<'a, R>(&'a self, range: R) -> impl Iterator<Item = &'a T> + 'a
+    where
+        R: RangeBounds<T> + 'a,
+    {
+        let mut items = self.iter();
+        core::iter::from_fn(move || loop {
+            let item = items.next()?;
+            let below = match range.start_bound() {
+                Bound::Included(start) => item < start,
+                Bound::Excluded(start) => item <= start,
+                Bound::Unbounded => false,
+            };
+            if below {
+                continue;
+            }
+            return match range.end_bound() {
+                Bound::Included(end) if item > end => None,
+                Bound::Excluded(end) if item >= end => None,
+                _ => Some(item),
+            };
+        })
+    }
+
+    /// Returns the index of the first element equal to `data`, stopping early.
+    fn position(&self, data: &T) -> Option<usize> {
+        self.iter()
+            .take_while(|item| *item <= data)
+            .position(|item| item == data)
+    }
+}
+
+impl<T, L: Default> Default for SortedLinkedList<T, L> {
+    /// Provides a default (empty) instance of the list using `empty()`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: Debug, L> Debug for SortedLinkedList<T, L>
+where
+    for<'a> &'a L: IntoIterator<Item = &'a T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.list).finish()
+    }
+}
@@ -0,0 +1,208 @@
+use core::error::Error;
+use core::fmt::{self, Debug, Display};
+use core::marker::PhantomData;
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::static_linked_list::StaticLinkedList;
+use crate::{LinkedListError, ListWrite};
+
+/// `Stack` is a last-in, first-out adapter over any `ListWrite` backend.
+///
+/// The top of the stack is the front of the backing list, so `push` and `pop` are O(1)
+/// even for singly linked backends. The backend defaults to `DynamicLinkedList`; use
+/// [`BoundedStack`] for a fixed-capacity stack with a typed overflow error.
+pub struct Stack<T, L = DynamicLinkedList<T>> {
+    /// The backing list. Its front is the top of the stack.
+    list: L,
+    /// The number of elements on the stack.
+    len: usize,
+    /// Marks the element type, which `L` only mentions through its trait impls.
+    _marker: PhantomData<T>,
+}
+
+impl<T> Stack<T> {
+    /// Creates a new, empty `Stack` backed by a `DynamicLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `Stack` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Default> Stack<T, L> {
+    /// Creates a new, empty `Stack` using any backing list, e.g.
+    /// `Stack::<i32, DoublyLinkedList<i32>>::empty()`.
+    ///
+    /// # Returns
+    /// - A new empty `Stack` instance.
+    pub fn empty() -> Self {
+        Stack {
+            list: L::default(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, L> Stack<T, L> {
+    /// Returns the number of elements on the stack.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Consumes the stack and returns the backing list, top first.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<T: Clone, L: ListWrite<T>> Stack<T, L> {
+    /// Pushes an element onto the top of the stack.
+    ///
+    /// # Parameters
+    /// - `data`: The value to push.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` if the backing list rejected the element (e.g. it is full).
+    pub fn push(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.list.insert_at_index(0, data)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element on top of the stack.
+    ///
+    /// # Returns
+    /// - `Some(T)` if the stack was not empty.
+    /// - `None` otherwise.
+    pub fn pop(&mut self) -> Option<T> {
+        let data = self.list.get(0)?.clone();
+        self.list.delete_at_index(0).expect("top must be in bounds");
+        self.len -= 1;
+        Some(data)
+    }
+
+    /// Returns a reference to the element on top of the stack without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+}
+
+impl<T, L: Default> Default for Stack<T, L> {
+    /// Provides a default (empty) instance of the stack using `empty()`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Debug> Debug for Stack<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Stack").field(&self.list).finish()
+    }
+}
+
+/// `StackOverflow` is returned when pushing onto a full [`BoundedStack`].
+///
+/// It hands back the value that could not be pushed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StackOverflow<T> {
+    /// The rejected value.
+    pub data: T,
+    /// The capacity of the stack that overflowed.
+    pub capacity: usize,
+}
+
+impl<T> Display for StackOverflow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stack overflow: capacity of {} reached", self.capacity)
+    }
+}
+
+impl<T: Debug> Error for StackOverflow<T> {}
+
+/// `BoundedStack` is a stack of at most `N` elements stored in a `StaticLinkedList`.
+///
+/// Pushing onto a full stack returns a [`StackOverflow`] carrying the rejected value,
+/// instead of printing a message or dropping it.
+#[derive(Debug)]
+pub struct BoundedStack<T, const N: usize> {
+    /// The backing stack over the fixed-capacity list.
+    stack: Stack<T, StaticLinkedList<T, N>>,
+}
+
+impl<T, const N: usize> BoundedStack<T, N> {
+    /// Creates a new, empty `BoundedStack`.
+    ///
+    /// # Returns
+    /// - A new empty `BoundedStack` instance.
+    pub fn new() -> Self {
+        BoundedStack {
+            stack: Stack::empty(),
+        }
+    }
+
+    /// Returns the maximum number of elements the stack can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements on the stack.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Returns `true` if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.stack.len() == N
+    }
+}
+
+impl<T: Clone, const N: usize> BoundedStack<T, N> {
+    /// Pushes an element onto the top of the stack.
+    ///
+    /// # Parameters
+    /// - `data`: The value to push.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(StackOverflow)` with the rejected value if the stack is full.
+    pub fn push(&mut self, data: T) -> Result<(), StackOverflow<T>> {
+        if self.is_full() {
+            return Err(StackOverflow { data, capacity: N });
+        }
+        self.stack
+            .push(data)
+            .expect("a non-full static list must accept a push");
+        Ok(())
+    }
+
+    /// Removes and returns the element on top of the stack.
+    pub fn pop(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+
+    /// Returns a reference to the element on top of the stack without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.stack.peek()
+    }
+}
+
+impl<T, const N: usize> Default for BoundedStack<T, N> {
+    /// Provides a default (empty) instance of the stack using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
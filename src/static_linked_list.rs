@@ -1,8 +1,33 @@
 // src/static_linked_list.rs
 
-use std::fmt::Debug;
+#[cfg(feature = "alloc")]
+use alloc::collections::{LinkedList, VecDeque};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Bound, RangeBounds};
+use core::ptr::NonNull;
 
-use crate::LinkedListTrait;
+#[cfg(feature = "alloc")]
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::footprint::{self, FragmentationStats, LocalityStats, MemoryFootprint};
+use crate::instrument;
+#[cfg(feature = "alloc")]
+use crate::integrity::IntegrityReport;
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "strict-invariants", debug_assertions)
+))]
+use crate::integrity::{SlotState, Violation};
+#[cfg(feature = "alloc")]
+use crate::render;
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
 
 /// Node represents a single element in the static linked list.
 #[derive(Debug, Clone)]
@@ -13,30 +38,711 @@ struct Node<T> {
     next: Option<usize>, // Index of the next node in the array
 }
 
+/// NodeHandle is a stable handle to an element of a StaticLinkedList, returned by
+/// [`insert_with_handle`](StaticLinkedList::insert_with_handle).
+///
+/// A handle stays valid across any number of unrelated insertions and removals. Once its
+/// element is removed, or moved to another slot by `compact`, the slot's generation moves
+/// on, so the handle is stale for good rather than referring to a later element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeHandle {
+    /// The slot of the element in the array.
+    slot: usize,
+    /// The generation of the slot when the handle was given out.
+    generation: u64,
+}
+
 /// StaticLinkedList is a linked list implementation using a fixed-size array for storage.
+///
+/// The list never allocates: the nodes and the free-slot stack are arrays inside the list
+/// value, so it works without `alloc`.
 #[derive(Debug)]
 pub struct StaticLinkedList<T, const N: usize> {
     /// The array of nodes.
     nodes: [Option<Node<T>>; N],
     /// The index of the head node in the array.
     head: Option<usize>, // Index of the head node in the array
-    /// The indices of free slots in the array.
-    free: Vec<usize>,    // Indices of free slots in the array
+    /// The index of the tail node in the array, kept so appends don't walk the chain.
+    tail: Option<usize>,
+    /// A stack of the free slots in the array: `free[..free_len]` are free, and the next
+    /// insertion takes the one on top.
+    free: [usize; N],
+    /// The number of free slots.
+    free_len: usize,
+    /// The generation of each slot, bumped whenever its element is removed or moved
+    /// elsewhere, so that handles to the element go stale.
+    generations: [u64; N],
 }
 
 impl<T, const N: usize> StaticLinkedList<T, N> {
+    /// An empty slot, for initializing the node array in a `const fn`.
+    const EMPTY: Option<Node<T>> = None;
+
     /// Creates a new empty StaticLinkedList.
-    pub fn new() -> Self {
-        let mut free = Vec::with_capacity(N);
-        for i in 0..N {
-            free.push(i);
+    ///
+    /// This is a `const fn`, so a list can be built at compile time, for example to live
+    /// in a `static` on a target without a heap.
+    pub const fn new() -> Self {
+        let mut free = [0; N];
+        let mut i = 0;
+        while i < N {
+            free[i] = N - 1 - i; // Slot 0 on top
+            i += 1;
         }
-
         StaticLinkedList {
-            nodes: array_init::array_init(|_| None),
+            nodes: [Self::EMPTY; N],
             head: None,
+            tail: None,
             free,
+            free_len: N,
+            generations: [0; N],
+        }
+    }
+
+    /// Returns the number of elements, counted from the free slots.
+    pub const fn len(&self) -> usize {
+        N - self.free_len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns the maximum number of elements the list can hold.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns how many more elements can be inserted before the list is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        self.free_len
+    }
+
+    /// Returns `true` if no more elements can be inserted.
+    pub const fn is_full(&self) -> bool {
+        self.free_len == 0
+    }
+
+    /// Checks that `additional` more elements fit. The storage is fixed, so nothing is
+    /// allocated; use `resize` to move the list to a larger capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * additional - The number of insertions to prepare for.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If at least `additional` slots are free.
+    /// * Err(LinkedListError::CapacityExceeded) - If they are not.
+    pub fn reserve(&self, additional: usize) -> Result<(), LinkedListError> {
+        if additional > self.remaining_capacity() {
+            return Err(LinkedListError::CapacityExceeded { capacity: N });
+        }
+        Ok(())
+    }
+
+    /// Returns how much memory the list takes up: the fixed array of `N` slots and the
+    /// free-slot stack, which are both part of the list value.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        MemoryFootprint {
+            nodes: self.len(),
+            node_overhead: footprint::node_overhead::<Option<Node<T>>, T>(),
+            total_bytes: mem::size_of::<Self>(),
+        }
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            list: self,
+            current: self.head,
+        }
+    }
+
+    /// Returns an iterator over `(slot, &T)` pairs, front to back, where `slot` is the
+    /// index in the array that holds the element. A slot stays the same while its element
+    /// is in the list, until `compact` or `optimize_layout` moves it, so slots stored
+    /// elsewhere can be checked against the current contents.
+    pub fn iter_slots(&self) -> IterSlots<'_, T, N> {
+        IterSlots {
+            list: self,
+            current: self.head,
+        }
+    }
+
+    /// Returns mutable references to the elements at several indices at once, found in a
+    /// single walk, e.g. to swap two elements in place.
+    ///
+    /// # Arguments
+    ///
+    /// * indices - The indices of the elements, in any order.
+    ///
+    /// # Returns
+    ///
+    /// * Some([&mut T; K]) - The elements, in the order of `indices`.
+    /// * None - If an index is out of bounds or two indices are equal.
+    pub fn get_many_mut<const K: usize>(&mut self, indices: [usize; K]) -> Option<[&mut T; K]> {
+        let mut order: [usize; K] = core::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| indices[i]);
+        if order
+            .windows(2)
+            .any(|pair| indices[pair[0]] == indices[pair[1]])
+        {
+            return None;
+        }
+        let mut slots = [0; K];
+        let mut current = self.head;
+        let mut position = 0;
+        for &i in &order {
+            while position < indices[i] {
+                current = self.next_of(Some(current?));
+                position += 1;
+            }
+            slots[i] = current?;
+        }
+        let nodes = self.nodes.get_disjoint_mut(slots).ok()?;
+        Some(nodes.map(|node| &mut node.as_mut().unwrap().data))
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            nodes: NonNull::from(&mut self.nodes).cast(),
+            current: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a cursor positioned at the first element.
+    ///
+    /// Walking with a cursor costs O(1) per step, where calling `get(i)` for each `i`
+    /// walks from the head every time.
+    pub fn cursor(&self) -> Cursor<'_, T, N> {
+        Cursor {
+            list: self,
+            current: self.head,
+            index: 0,
+        }
+    }
+
+    /// Returns a mutable cursor positioned at the first element, for O(1) insertions and
+    /// removals at the cursor.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, N> {
+        let current = self.head;
+        CursorMut {
+            list: self,
+            previous: None,
+            current,
+            index: 0,
+        }
+    }
+
+    /// Replaces the elements in `range` with the elements of `other`, returning the
+    /// replaced elements as a list.
+    ///
+    /// The elements of `other` are moved into the slots freed by the range, or into free
+    /// slots elsewhere in the array; no element is cloned.
+    ///
+    /// # Arguments
+    ///
+    /// * range - The positions to replace, e.g. `1..3` or `2..`.
+    /// * other - The list to link in place of the range.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(StaticLinkedList<T, N>) - The elements that were in `range`.
+    /// * Err(LinkedListError) - If the range is out of bounds or the result would not fit in `N`.
+    pub fn splice<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        mut other: StaticLinkedList<T, N>,
+    ) -> Result<StaticLinkedList<T, N>, LinkedListError> {
+        let len = self.len();
+        // A bound of `usize::MAX` has no position after it.
+        let overflow = LinkedListError::IndexOutOfBounds {
+            index: usize::MAX,
+            len,
+        };
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).ok_or(overflow)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).ok_or(overflow)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        if start > end || end > len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index: start.max(end),
+                len,
+            });
+        }
+        if len - (end - start) + other.len() > N {
+            return Err(LinkedListError::CapacityExceeded { capacity: N });
+        }
+
+        let mut previous = None;
+        for _ in 0..start {
+            previous = self.next_of(previous);
+        }
+        let mut removed = StaticLinkedList::new();
+        let mut removed_tail = None;
+        for _ in start..end {
+            let slot = self.next_of(previous).unwrap();
+            let node = self.unlink(previous, slot);
+            removed_tail = removed.link_after(removed_tail, node.data);
+        }
+        while let Some(data) = other.pop_front() {
+            // The capacity was checked above, so a slot is always free.
+            previous = Some(self.link_after(previous, data).unwrap());
         }
+        Ok(removed)
+    }
+
+    /// Removes and returns the first element, freeing its slot.
+    ///
+    /// # Returns
+    ///
+    /// * Some(T) - The removed element.
+    /// * None - If the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let index = self.head?;
+        Some(self.unlink(None, index).data)
+    }
+
+    /// Removes and returns the element at `index`, moving the first element into its
+    /// place, like `Vec::swap_remove` does with the last one.
+    ///
+    /// Only the head slot is unlinked and freed, so no other link changes; finding `index`
+    /// still walks the list. Use it when the order of the elements does not matter. A
+    /// handle to the first element goes stale, since the element moves to another slot.
+    ///
+    /// # Arguments
+    ///
+    /// * index - The index of the element to remove.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(T) - The removed element.
+    /// * Err(LinkedListError) - If the index is out of bounds.
+    pub fn swap_remove_at(&mut self, index: usize) -> Result<T, LinkedListError> {
+        instrument::event!(TRACE, index, len = self.len(), "swap_remove_at");
+        let mut slot = self.head;
+        for _ in 0..index {
+            slot = slot.and_then(|slot| self.next_of(Some(slot)));
+        }
+        let (Some(slot), Some(head)) = (slot, self.head) else {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        };
+        if let Ok([Some(node), Some(first)]) = self.nodes.get_disjoint_mut([slot, head]) {
+            mem::swap(&mut node.data, &mut first.data);
+            self.generations[slot] = self.generations[slot].wrapping_add(1);
+        }
+        Ok(self.unlink(None, head).data)
+    }
+
+    /// Inserts a new element at the tail of the linked list, handing it back if the list
+    /// is full.
+    ///
+    /// # Arguments
+    ///
+    /// * data - The data to be inserted into the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If the element was inserted.
+    /// * Err(T) - The rejected element, if the list is full.
+    pub fn push_back(&mut self, data: T) -> Result<(), T> {
+        instrument::event!(TRACE, index = self.len(), len = self.len(), "push_back");
+        if self.is_full() {
+            instrument::event!(DEBUG, capacity = N, "list is full");
+            return Err(data);
+        }
+        self.link_after(self.tail, data);
+        Ok(())
+    }
+
+    /// Inserts a new element at the tail of the linked list and returns a handle to it, for
+    /// finding it again in O(1) later.
+    ///
+    /// # Arguments
+    ///
+    /// * data - The data to be inserted into the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(NodeHandle) - A handle to the new element.
+    /// * Err(T) - The rejected element, if the list is full.
+    pub fn insert_with_handle(&mut self, data: T) -> Result<NodeHandle, T> {
+        if self.is_full() {
+            instrument::event!(DEBUG, capacity = N, "list is full");
+            return Err(data);
+        }
+        Ok(self.link_with_handle(self.tail, data).unwrap())
+    }
+
+    /// Checks whether the element behind a handle is still in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * handle - The handle to check.
+    ///
+    /// # Returns
+    ///
+    /// * bool - `true` if the handle is not stale.
+    pub fn contains_handle(&self, handle: NodeHandle) -> bool {
+        self.slot_of(handle).is_some()
+    }
+
+    /// Retrieves a reference to the element behind a handle in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * handle - The handle of the element.
+    ///
+    /// # Returns
+    ///
+    /// * Some(&T) - If the handle is live.
+    /// * None - If the handle is stale.
+    pub fn get_by_handle(&self, handle: NodeHandle) -> Option<&T> {
+        let slot = self.slot_of(handle)?;
+        self.nodes[slot].as_ref().map(|node| &node.data)
+    }
+
+    /// Retrieves a mutable reference to the element behind a handle in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * handle - The handle of the element.
+    ///
+    /// # Returns
+    ///
+    /// * Some(&mut T) - If the handle is live.
+    /// * None - If the handle is stale.
+    pub fn get_mut_by_handle(&mut self, handle: NodeHandle) -> Option<&mut T> {
+        let slot = self.slot_of(handle)?;
+        self.nodes[slot].as_mut().map(|node| &mut node.data)
+    }
+
+    /// Inserts a new element directly after the element behind a handle in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * handle - The handle of the element to insert after.
+    /// * data - The data to be inserted into the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(NodeHandle) - A handle to the new element.
+    /// * Err(LinkedListError) - If the handle is stale or the list is full.
+    pub fn insert_after_handle(
+        &mut self,
+        handle: NodeHandle,
+        data: T,
+    ) -> Result<NodeHandle, LinkedListError> {
+        let slot = self.slot_of(handle).ok_or(LinkedListError::StaleKey)?;
+        self.link_with_handle(Some(slot), data)
+    }
+
+    /// Inserts a new element directly before the element behind a handle. The list is
+    /// singly linked, so finding the slot before it walks from the head.
+    ///
+    /// # Arguments
+    ///
+    /// * handle - The handle of the element to insert before.
+    /// * data - The data to be inserted into the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(NodeHandle) - A handle to the new element.
+    /// * Err(LinkedListError) - If the handle is stale or the list is full.
+    pub fn insert_before_handle(
+        &mut self,
+        handle: NodeHandle,
+        data: T,
+    ) -> Result<NodeHandle, LinkedListError> {
+        let slot = self.slot_of(handle).ok_or(LinkedListError::StaleKey)?;
+        self.link_with_handle(self.previous_of(slot), data)
+    }
+
+    /// Removes the element behind a handle and frees its slot. The list is singly linked,
+    /// so finding the slot before it walks from the head.
+    ///
+    /// # Arguments
+    ///
+    /// * handle - The handle of the element to remove.
+    ///
+    /// # Returns
+    ///
+    /// * Some(T) - The removed element, if the handle was live.
+    /// * None - If the handle is stale.
+    pub fn remove_by_handle(&mut self, handle: NodeHandle) -> Option<T> {
+        let slot = self.slot_of(handle)?;
+        let previous = self.previous_of(slot);
+        Some(self.unlink(previous, slot).data)
+    }
+
+    /// Moves the elements, in order, into a `DynamicLinkedList` without a capacity limit.
+    ///
+    /// # Returns
+    ///
+    /// * DynamicLinkedList<T> - A list holding the same elements.
+    #[cfg(feature = "alloc")]
+    pub fn into_dynamic(self) -> DynamicLinkedList<T> {
+        let mut list = DynamicLinkedList::new();
+        self.move_each(|data| list.insert(data));
+        list
+    }
+
+    /// Moves the elements, in order, into a list with capacity `M`, which may be larger or
+    /// smaller than `N`.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(StaticLinkedList<T, M>) - A list holding the same elements.
+    /// * Err(StaticLinkedList<T, N>) - This list, unchanged, if it has more than `M` elements.
+    pub fn resize<const M: usize>(self) -> Result<StaticLinkedList<T, M>, Self> {
+        if self.len() > M {
+            return Err(self);
+        }
+        let mut list = StaticLinkedList::new();
+        self.move_each(|data| list.insert(data));
+        Ok(list)
+    }
+
+    /// Splits the list in two at `index`: the front holds the elements before `index`,
+    /// the back the rest. The elements of the back half move to the slots of a new list;
+    /// the front half keeps its slots.
+    ///
+    /// # Arguments
+    ///
+    /// * index - The index of the first element of the back half.
+    ///
+    /// # Returns
+    ///
+    /// * Ok((StaticLinkedList<T, N>, StaticLinkedList<T, N>)) - The front and back halves.
+    /// * Err(StaticLinkedList<T, N>) - This list, unchanged, if `index` is past the end.
+    pub fn split_at(mut self, index: usize) -> Result<(Self, Self), Self> {
+        if index > self.len() {
+            return Err(self);
+        }
+        if index == 0 {
+            return Ok((StaticLinkedList::new(), self));
+        }
+        let mut cursor = self.cursor_mut();
+        for _ in 1..index {
+            cursor.move_next();
+        }
+        let back = cursor.split_after();
+        Ok((self, back))
+    }
+
+    /// Moves the elements out of their slots in list order, passing each to `push`.
+    fn move_each(mut self, mut push: impl FnMut(T)) {
+        let mut current = self.head;
+        while let Some(slot) = current {
+            let node = self.nodes[slot].take().unwrap();
+            current = node.next;
+            push(node.data);
+        }
+    }
+
+    /// Moves the nodes into the lowest slots in list order and rebuilds the free list.
+    ///
+    /// After heavy churn the nodes can be scattered over the array; once compacted, the
+    /// element at index `i` sits in slot `i`, so walking the list reads the array front to
+    /// back and the occupied slots form a prefix of it. No element is cloned, and the
+    /// nodes are moved in place.
+    pub fn compact(&mut self) {
+        instrument::span!(DEBUG, "compact", len = self.len(), capacity = N);
+        // The `len`th node is swapped into slot `len`. Whatever it displaces moves to the
+        // slot it came from, which is left in the placed node's `next` for links to the
+        // displaced node to follow; the real links are rebuilt at the end.
+        let mut len = 0;
+        let mut current = self.head;
+        while let Some(mut slot) = current {
+            while slot < len {
+                slot = self.nodes[slot].as_ref().unwrap().next.unwrap();
+            }
+            if slot != len {
+                self.nodes.swap(len, slot);
+                self.generations[len] = self.generations[len].wrapping_add(1);
+                self.generations[slot] = self.generations[slot].wrapping_add(1);
+            }
+            current = self.nodes[len].as_mut().unwrap().next.replace(slot);
+            len += 1;
+        }
+        for slot in 0..len {
+            self.nodes[slot].as_mut().unwrap().next = (slot + 1 < len).then_some(slot + 1);
+        }
+        self.head = (len > 0).then_some(0);
+        self.tail = len.checked_sub(1);
+        for (i, free) in self.free[..N - len].iter_mut().enumerate() {
+            *free = N - 1 - i; // The lowest free slot on top
+        }
+        self.free_len = N - len;
+        self.check_invariants();
+    }
+
+    /// Returns how closely the slots of the nodes follow the list order.
+    pub fn locality(&self) -> LocalityStats {
+        footprint::locality(core::iter::successors(self.head, |&slot| {
+            self.next_of(Some(slot))
+        }))
+    }
+
+    /// Reports how the occupied and free slots are spread over the array and how far the
+    /// list order has drifted from the slot order, to decide whether to call `compact`.
+    ///
+    /// # Returns
+    ///
+    /// * FragmentationStats - The slot distribution and the drift.
+    pub fn fragmentation_stats(&self) -> FragmentationStats {
+        let mut free_runs = 0;
+        let mut longest_free_run = 0;
+        let mut run = 0;
+        for node in &self.nodes {
+            if node.is_some() {
+                run = 0;
+                continue;
+            }
+            if run == 0 {
+                free_runs += 1;
+            }
+            run += 1;
+            longest_free_run = longest_free_run.max(run);
+        }
+        let displaced = self
+            .iter_slots()
+            .enumerate()
+            .filter(|&(index, (slot, _))| index != slot)
+            .count();
+        FragmentationStats {
+            occupied: self.len(),
+            free: self.free_len,
+            free_runs,
+            longest_free_run,
+            displaced,
+            locality: self.locality(),
+        }
+    }
+
+    /// Places the nodes in slots that match the traversal order, so iterating reads the
+    /// array front to back. This is `compact` with the locality measured around it.
+    ///
+    /// # Returns
+    ///
+    /// * (LocalityStats, LocalityStats) - The locality before and after.
+    pub fn optimize_layout(&mut self) -> (LocalityStats, LocalityStats) {
+        let before = self.locality();
+        self.compact();
+        (before, self.locality())
+    }
+
+    /// Checks the structure of the list: the chain from the head stays within the array,
+    /// ends at the tail without a cycle, and has `len` nodes; every slot is either in the
+    /// chain or free, never both; and the free slots are empty.
+    ///
+    /// # Returns
+    ///
+    /// * IntegrityReport - The number of linked and free slots, and each broken invariant.
+    #[cfg(feature = "alloc")]
+    pub fn validate(&self) -> IntegrityReport {
+        let mut violations = Vec::new();
+        let (chain_len, free_len) = self.check_integrity(|violation| violations.push(violation));
+        IntegrityReport {
+            chain_len,
+            free_len,
+            violations,
+        }
+    }
+
+    /// Runs the checks behind `validate`, passing each broken invariant to `violation`.
+    ///
+    /// # Returns
+    ///
+    /// * (usize, usize) - The number of slots reached in the chain and in the free list.
+    #[cfg(any(
+        feature = "alloc",
+        all(feature = "strict-invariants", debug_assertions)
+    ))]
+    fn check_integrity(&self, mut violation: impl FnMut(Violation)) -> (usize, usize) {
+        let mut states = [SlotState::Unseen; N];
+        let mut chain_len = 0;
+        let mut last = None;
+        let mut current = self.head;
+        while let Some(slot) = current {
+            if slot >= N {
+                violation(Violation::LinkOutOfRange {
+                    from: last,
+                    to: slot,
+                });
+                break;
+            }
+            if states[slot] == SlotState::Linked {
+                violation(Violation::Cycle { slot });
+                break;
+            }
+            let Some(node) = &self.nodes[slot] else {
+                violation(Violation::EmptySlotLinked { slot });
+                break;
+            };
+            states[slot] = SlotState::Linked;
+            chain_len += 1;
+            last = Some(slot);
+            current = node.next;
+        }
+        if self.tail != last {
+            violation(Violation::TailMismatch {
+                tail: self.tail,
+                last,
+            });
+        }
+
+        let free = &self.free[..self.free_len.min(N)];
+        for &slot in free {
+            if slot >= N {
+                violation(Violation::FreeOutOfRange { slot });
+                continue;
+            }
+            match states[slot] {
+                SlotState::Linked => violation(Violation::LinkedAndFree { slot }),
+                SlotState::Free => violation(Violation::FreedTwice { slot }),
+                SlotState::Unseen if self.nodes[slot].is_some() => {
+                    violation(Violation::FreeSlotOccupied { slot });
+                }
+                SlotState::Unseen => {}
+            }
+            states[slot] = SlotState::Free;
+        }
+
+        if chain_len != self.len() {
+            violation(Violation::LengthMismatch {
+                chain: chain_len,
+                len: self.len(),
+            });
+        }
+        for (slot, state) in states.iter().enumerate() {
+            if *state == SlotState::Unseen {
+                violation(Violation::Leaked { slot });
+            }
+        }
+        (chain_len, free.len())
+    }
+
+    /// Panics at the first broken invariant, in debug builds with the `strict-invariants`
+    /// feature; otherwise does nothing.
+    fn check_invariants(&self) {
+        #[cfg(all(feature = "strict-invariants", debug_assertions))]
+        self.check_integrity(|violation| {
+            panic!("StaticLinkedList invariant broken: {violation}");
+        });
     }
 
     /// Allocates a new node in the array.
@@ -50,15 +756,111 @@ impl<T, const N: usize> StaticLinkedList<T, N> {
     /// * Some(usize) - The index of the newly allocated node.
     /// * None - If the list is full and no more nodes can be allocated.
     fn allocate_node(&mut self, data: T) -> Option<usize> {
-        if self.free.is_empty() {
+        if self.is_full() {
+            instrument::event!(DEBUG, capacity = N, "list is full");
             return None; // List is full
         }
 
-        let index = self.free.remove(0); // Get the first free index
+        self.free_len -= 1;
+        let index = self.free[self.free_len]; // Take the free slot on top
         self.nodes[index] = Some(Node { data, next: None });
         Some(index)
     }
 
+    /// Returns the slot that the link after `previous` points at, where `None` stands for
+    /// the head link.
+    fn next_of(&self, previous: Option<usize>) -> Option<usize> {
+        match previous {
+            Some(previous) => self.nodes[previous].as_ref().unwrap().next,
+            None => self.head,
+        }
+    }
+
+    /// Points the link after `previous` at `slot`, where `None` stands for the head link.
+    ///
+    /// # Returns
+    ///
+    /// * Option<usize> - The slot the link pointed at before.
+    fn relink(&mut self, previous: Option<usize>, slot: Option<usize>) -> Option<usize> {
+        match previous {
+            Some(previous) => mem::replace(&mut self.nodes[previous].as_mut().unwrap().next, slot),
+            None => mem::replace(&mut self.head, slot),
+        }
+    }
+
+    /// Allocates a node for `data` and links it in after `previous`, or at the front for
+    /// `None`.
+    ///
+    /// # Returns
+    ///
+    /// * Some(usize) - The slot of the new node.
+    /// * None - If the list is full.
+    fn link_after(&mut self, previous: Option<usize>, data: T) -> Option<usize> {
+        let slot = self.allocate_node(data)?;
+        let next = self.relink(previous, Some(slot));
+        self.nodes[slot].as_mut().unwrap().next = next;
+        if next.is_none() {
+            self.tail = Some(slot);
+        }
+        self.check_invariants();
+        Some(slot)
+    }
+
+    /// Links in a node for `data` after `previous` like `link_after`, and returns a handle
+    /// to it.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(NodeHandle) - A handle to the new node.
+    /// * Err(LinkedListError::CapacityExceeded) - If the list is full.
+    fn link_with_handle(
+        &mut self,
+        previous: Option<usize>,
+        data: T,
+    ) -> Result<NodeHandle, LinkedListError> {
+        let slot = self
+            .link_after(previous, data)
+            .ok_or(LinkedListError::CapacityExceeded { capacity: N })?;
+        Ok(NodeHandle {
+            slot,
+            generation: self.generations[slot],
+        })
+    }
+
+    /// Returns the slot of the element behind `handle`, or `None` if the handle is stale.
+    fn slot_of(&self, handle: NodeHandle) -> Option<usize> {
+        let live = handle.slot < N
+            && self.nodes[handle.slot].is_some()
+            && self.generations[handle.slot] == handle.generation;
+        live.then_some(handle.slot)
+    }
+
+    /// Returns the slot before `slot` in the chain, or `None` if it is the head.
+    fn previous_of(&self, slot: usize) -> Option<usize> {
+        let mut previous = None;
+        let mut current = self.head;
+        while let Some(i) = current {
+            if i == slot {
+                break;
+            }
+            previous = current;
+            current = self.next_of(current);
+        }
+        previous
+    }
+
+    /// Unlinks the node at `slot`, which follows `previous`, and frees its slot.
+    fn unlink(&mut self, previous: Option<usize>, slot: usize) -> Node<T> {
+        let node = self.nodes[slot].take().unwrap();
+        self.deallocate_node(slot);
+        self.relink(previous, node.next);
+        if node.next.is_none() {
+            self.tail = previous;
+        }
+        self.check_invariants();
+        node
+    }
+
     /// Deallocates a node in the array.
     ///
     /// # Arguments
@@ -66,42 +868,206 @@ impl<T, const N: usize> StaticLinkedList<T, N> {
     /// * index - The index of the node to be deallocated.
     fn deallocate_node(&mut self, index: usize) {
         self.nodes[index] = None;
-        self.free.push(index);
-        self.free.sort_unstable(); // Keep free indices sorted for consistency (optional)
+        self.generations[index] = self.generations[index].wrapping_add(1);
+        self.free[self.free_len] = index;
+        self.free_len += 1;
     }
 }
 
-impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for StaticLinkedList<T, N> {
-    /// Inserts a new element at the tail of the linked list.
+#[cfg(feature = "alloc")]
+impl<T: Display, const N: usize> StaticLinkedList<T, N> {
+    /// Draws the chain, then a table of the `N` slots with the element and the `next`
+    /// link in each. The last column marks the head and tail slots, and numbers the free
+    /// slots in the order insertions will take them, `free #1` first.
+    ///
+    /// # Returns
+    ///
+    /// * String - The drawing, with lines separated by `\n`, e.g.:
+    ///
+    /// ```text
+    /// [head] -> 3 -> 7 -> X
+    /// slot  value  next  note
+    /// 0     -      -     free #1
+    /// 1     3      2     head
+    /// 2     7      X     tail
+    /// 3     -      -     free #2
+    /// ```
+    pub fn render_ascii(&self) -> String {
+        let mut rows: Vec<[String; 4]> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(slot, node)| {
+                let (value, next) = match node {
+                    Some(node) => (
+                        node.data.to_string(),
+                        node.next.map_or("X".to_string(), |next| next.to_string()),
+                    ),
+                    None => ("-".to_string(), "-".to_string()),
+                };
+                let note = match (self.head == Some(slot), self.tail == Some(slot)) {
+                    (true, true) => "head, tail",
+                    (true, false) => "head",
+                    (false, true) => "tail",
+                    (false, false) => "",
+                };
+                [slot.to_string(), value, next, note.to_string()]
+            })
+            .collect();
+        for (depth, &slot) in self.free[..self.free_len].iter().rev().enumerate() {
+            rows[slot][3] = alloc::format!("free #{}", depth + 1);
+        }
+        let mut out = render::chain(self.iter());
+        out.push('\n');
+        out.push_str(&render::table(["slot", "value", "next", "note"], &rows));
+        out
+    }
+}
+
+impl<T, const N: usize> Default for StaticLinkedList<T, N> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryFrom<LinkedList<T>> for StaticLinkedList<T, N> {
+    type Error = LinkedList<T>;
+
+    /// Moves the elements of a standard `LinkedList` into a new list, in order.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(StaticLinkedList<T, N>) - A list holding the same elements.
+    /// * Err(`LinkedList<T>`) - The given list, unchanged, if it has more than `N` elements.
+    fn try_from(elements: LinkedList<T>) -> Result<Self, LinkedList<T>> {
+        if elements.len() > N {
+            return Err(elements);
+        }
+        let mut list = StaticLinkedList::new();
+        for data in elements {
+            list.insert(data);
+        }
+        Ok(list)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryFrom<VecDeque<T>> for StaticLinkedList<T, N> {
+    type Error = VecDeque<T>;
+
+    /// Moves the elements of a `VecDeque` into a new list, front to back.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(StaticLinkedList<T, N>) - A list holding the same elements.
+    /// * Err(`VecDeque<T>`) - The given deque, unchanged, if it has more than `N` elements.
+    fn try_from(elements: VecDeque<T>) -> Result<Self, VecDeque<T>> {
+        if elements.len() > N {
+            return Err(elements);
+        }
+        let mut list = StaticLinkedList::new();
+        for data in elements {
+            list.insert(data);
+        }
+        Ok(list)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<StaticLinkedList<T, N>> for LinkedList<T> {
+    /// Moves the elements into a standard `LinkedList`, in order.
+    fn from(list: StaticLinkedList<T, N>) -> Self {
+        let mut elements = LinkedList::new();
+        list.move_each(|data| elements.push_back(data));
+        elements
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<StaticLinkedList<T, N>> for VecDeque<T> {
+    /// Moves the elements into a `VecDeque`, in order.
+    fn from(list: StaticLinkedList<T, N>) -> Self {
+        let mut elements = VecDeque::with_capacity(list.len());
+        list.move_each(|data| elements.push_back(data));
+        elements
+    }
+}
+
+impl<T, const N: usize> ListRead<T> for StaticLinkedList<T, N> {
+    /// Returns the number of elements in the linked list.
+    fn len(&self) -> usize {
+        StaticLinkedList::len(self)
+    }
+
+    /// Finds the index of the first element matching the predicate.
     ///
     /// # Arguments
     ///
-    /// * data - The data to be inserted into the linked list.
-    fn insert(&mut self, data: T) {
-        if let Some(index) = self.allocate_node(data) {
-            match self.head {
-                None => {
-                    self.head = Some(index);
-                }
-                Some(head_index) => {
-                    let mut current_index = head_index;
-                    loop {
-                        match self.nodes[current_index].as_mut().unwrap().next {
-                            None => {
-                                self.nodes[current_index].as_mut().unwrap().next = Some(index);
-                                break;
-                            }
-                            Some(next_index) => {
-                                current_index = next_index;
-                            }
-                        }
-                    }
+    /// * predicate - The test applied to each element, front to back.
+    ///
+    /// # Returns
+    ///
+    /// * Some(usize) - The index of the first matching element.
+    /// * None - If no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Retrieves the element at the specified index in the linked list.
+    ///
+    /// # Arguments
+    ///
+    /// * index - The index of the element to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * Some(&T) - If an element exists at the specified index.
+    /// * None - If the index is out of bounds.
+    fn get(&self, index: usize) -> Option<&T> {
+        let mut current_index = self.head;
+        for _ in 0..index {
+            match current_index {
+                Some(i) => {
+                    current_index = self.nodes[i].as_ref().unwrap().next;
                 }
+                None => return None,
             }
-        } else {
-            println!("StaticLinkedList is full. Cannot insert more elements.");
+        }
+
+        match current_index {
+            Some(i) => Some(&self.nodes[i].as_ref().unwrap().data),
+            None => None,
         }
     }
+}
+
+impl<T, const N: usize> ListWrite<T> for StaticLinkedList<T, N> {
+    /// Inserts a new element at the tail of the linked list. If the list is full the
+    /// element is dropped; use `try_insert` or `push_back` to find out.
+    ///
+    /// # Arguments
+    ///
+    /// * data - The data to be inserted into the linked list.
+    fn insert(&mut self, data: T) {
+        let _ = self.push_back(data);
+    }
+
+    /// Inserts a new element at the tail of the linked list if there is a free slot.
+    ///
+    /// # Arguments
+    ///
+    /// * data - The data to be inserted into the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If the element was successfully inserted.
+    /// * Err(LinkedListError) - If the list is full.
+    fn try_insert(&mut self, data: T) -> Result<(), LinkedListError> {
+        self.push_back(data)
+            .map_err(|_| LinkedListError::CapacityExceeded { capacity: N })
+    }
 
     /// Inserts a new element at a specified index in the linked list.
     ///
@@ -113,15 +1079,14 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
     /// # Returns
     ///
     /// * Ok(()) - If the element was successfully inserted.
-    /// * Err(String) - If the index is out of bounds or the list is full.
-    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), String> {
+    /// * Err(LinkedListError) - If the index is out of bounds or the list is full.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        instrument::event!(TRACE, index, len = self.len(), "insert_at_index");
         if index == 0 {
-            if let Some(new_index) = self.allocate_node(data) {
-                self.nodes[new_index].as_mut().unwrap().next = self.head;
-                self.head = Some(new_index);
+            if self.link_after(None, data).is_some() {
                 return Ok(());
             } else {
-                return Err("List is full".to_string());
+                return Err(LinkedListError::CapacityExceeded { capacity: N });
             }
         }
 
@@ -132,46 +1097,92 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
                     current_index = self.nodes[i].as_ref().unwrap().next;
                 }
                 None => {
-                    return Err("Index out of bounds".to_string());
+                    return Err(LinkedListError::IndexOutOfBounds {
+                        index,
+                        len: self.len(),
+                    });
                 }
             }
         }
 
         match current_index {
             Some(i) => {
-                if let Some(new_index) = self.allocate_node(data) {
-                    self.nodes[new_index].as_mut().unwrap().next = self.nodes[i].as_mut().unwrap().next;
-                    self.nodes[i].as_mut().unwrap().next = Some(new_index);
+                if self.link_after(Some(i), data).is_some() {
                     Ok(())
                 } else {
-                    Err("List is full".to_string())
+                    Err(LinkedListError::CapacityExceeded { capacity: N })
                 }
             }
-            None => Err("Index out of bounds".to_string()),
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
         }
     }
 
+    /// Inserts the elements of `items` in order, the first one at `index`, walking to
+    /// `index` only once.
+    ///
+    /// # Arguments
+    ///
+    /// * index - The index at which the first element should be inserted.
+    /// * items - The data to be inserted into the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If every element was inserted.
+    /// * Err(LinkedListError) - If the index is out of bounds, in which case nothing is
+    ///   inserted, or the list filled up, in which case the elements before that stay.
+    fn insert_many_at<I>(&mut self, index: usize, items: I) -> Result<(), LinkedListError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        instrument::event!(TRACE, index, len = self.len(), "insert_many_at");
+        if index > self.len() {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+
+        let mut previous = None;
+        for _ in 0..index {
+            previous = self.next_of(previous);
+        }
+        for item in items {
+            match self.link_after(previous, item) {
+                Some(slot) => previous = Some(slot),
+                None => return Err(LinkedListError::CapacityExceeded { capacity: N }),
+            }
+        }
+        Ok(())
+    }
+
     /// Deletes the first occurrence of an element matching the provided data.
     ///
     /// # Arguments
     ///
-    /// * data - The data to be deleted from the linked list.
+    /// * data - A reference to the data to be deleted from the linked list.
     ///
     /// # Returns
     ///
     /// * true - If an element was successfully deleted.
     /// * false - If no element matching the data was found.
-    fn delete_element(&mut self, data: T) -> bool {
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        instrument::event!(TRACE, len = self.len(), "delete_element");
         if self.head.is_none() {
             return false;
         }
 
         let mut current_index = self.head;
 
-        if self.nodes[self.head.unwrap()].as_ref().unwrap().data == data {
-            let head_index = self.head.unwrap();
-            self.head = self.nodes[head_index].as_ref().unwrap().next;
-            self.deallocate_node(head_index);
+        let head_index = self.head.unwrap();
+        if self.nodes[head_index].as_ref().unwrap().data.borrow() == data {
+            self.unlink(None, head_index);
             return true;
         }
 
@@ -179,9 +1190,8 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
             let next_index = self.nodes[i].as_ref().unwrap().next;
             match next_index {
                 Some(j) => {
-                    if self.nodes[j].as_ref().unwrap().data == data {
-                        self.nodes[i].as_mut().unwrap().next = self.nodes[j].as_ref().unwrap().next;
-                        self.deallocate_node(j);
+                    if self.nodes[j].as_ref().unwrap().data.borrow() == data {
+                        self.unlink(Some(i), j);
                         return true;
                     } else {
                         current_index = Some(j);
@@ -203,16 +1213,19 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
     /// # Returns
     ///
     /// * Ok(()) - If the element was successfully deleted.
-    /// * Err(String) - If the index is out of bounds.
-    fn delete_at_index(&mut self, index: usize) -> Result<(), String> {
+    /// * Err(LinkedListError) - If the index is out of bounds.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        instrument::event!(TRACE, index, len = self.len(), "delete_at_index");
         if index == 0 {
             match self.head {
                 Some(head_index) => {
-                    self.head = self.nodes[head_index].as_ref().unwrap().next;
-                    self.deallocate_node(head_index);
+                    self.unlink(None, head_index);
                     Ok(())
                 }
-                None => Err("Index out of bounds".to_string()),
+                None => Err(LinkedListError::IndexOutOfBounds {
+                    index,
+                    len: self.len(),
+                }),
             }
         } else {
             let mut current_index = self.head;
@@ -221,7 +1234,10 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
                     Some(i) => {
                         current_index = self.nodes[i].as_ref().unwrap().next;
                     }
-                    None => return Err("Index out of bounds".to_string()),
+                    None => return Err(LinkedListError::IndexOutOfBounds {
+                        index,
+                        len: self.len(),
+                    }),
                 }
             }
 
@@ -229,39 +1245,39 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
                 Some(i) => {
                     match self.nodes[i].as_ref().unwrap().next {
                         Some(j) => {
-                            self.nodes[i].as_mut().unwrap().next = self.nodes[j].as_ref().unwrap().next;
-                            self.deallocate_node(j);
+                            self.unlink(Some(i), j);
                             Ok(())
                         }
-                        None => Err("Index out of bounds".to_string()),
+                        None => Err(LinkedListError::IndexOutOfBounds {
+                            index,
+                            len: self.len(),
+                        }),
                     }
                 }
-                None => Err("Index out of bounds".to_string()),
+                None => Err(LinkedListError::IndexOutOfBounds {
+                    index,
+                    len: self.len(),
+                }),
             }
         }
     }
 
-    /// Updates the first occurrence of an element matching the old_data with new_data.
+    /// Retrieves a mutable reference to the element at the specified index.
     ///
     /// # Arguments
     ///
-    /// * old_data - The data to be replaced.
-    /// * new_data - The new data to replace the old data.
+    /// * index - The index of the element to retrieve.
     ///
     /// # Returns
     ///
-    /// * true - If an element was successfully updated.
-    /// * false - If no element matching the old_data was found.
-    fn update_element(&mut self, old_data: T, new_data: T) -> bool {
+    /// * Some(&mut T) - If an element exists at the specified index.
+    /// * None - If the index is out of bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
         let mut current_index = self.head;
-        while let Some(i) = current_index {
-            if self.nodes[i].as_ref().unwrap().data == old_data {
-                self.nodes[i].as_mut().unwrap().data = new_data;
-                return true;
-            }
-            current_index = self.nodes[i].as_ref().unwrap().next;
+        for _ in 0..index {
+            current_index = self.nodes[current_index?].as_ref().unwrap().next;
         }
-        false
+        Some(&mut self.nodes[current_index?].as_mut().unwrap().data)
     }
 
     /// Updates the element at the specified index with the provided data.
@@ -274,15 +1290,18 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
     /// # Returns
     ///
     /// * Ok(()) - If the element was successfully updated.
-    /// * Err(String) - If the index is out of bounds.
-    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), String> {
+    /// * Err(LinkedListError) - If the index is out of bounds.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
         let mut current_index = self.head;
         for _ in 0..index {
             match current_index {
                 Some(i) => {
                     current_index = self.nodes[i].as_ref().unwrap().next;
                 }
-                None => return Err("Index out of bounds".to_string()),
+                None => return Err(LinkedListError::IndexOutOfBounds {
+                    index,
+                    len: self.len(),
+                }),
             }
         }
 
@@ -291,55 +1310,378 @@ impl<T: PartialEq + Clone + Debug, const N: usize> LinkedListTrait<T> for Static
                 self.nodes[i].as_mut().unwrap().data = data;
                 Ok(())
             }
-            None => Err("Index out of bounds".to_string()),
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
         }
     }
+}
+
+impl<T, const N: usize> LinkedListTrait<T> for StaticLinkedList<T, N> {
+    type Iter<'a>
+        = Iter<'a, T, N>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        StaticLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        StaticLinkedList::iter_mut(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a StaticLinkedList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Iter<'a, T, N> {
+        self.iter()
+    }
+}
+
+/// An iterator over mutable references to the elements of a `StaticLinkedList`.
+pub struct IterMut<'a, T> {
+    /// The first slot of the list's array.
+    nodes: NonNull<Option<Node<T>>>,
+    /// The slot of the next node to yield.
+    current: Option<usize>,
+    /// Ties the iterator to the exclusive borrow of the list.
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator hands out each element once, like the `&mut T`s it yields.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared iterator gives no access to the elements.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        // SAFETY: the array is exclusively borrowed for `'a`, every slot in the chain is
+        // occupied and in bounds, and the chain visits each slot once.
+        let node = unsafe { (*self.nodes.as_ptr().add(self.current?)).as_mut().unwrap() };
+        self.current = node.next;
+        Some(&mut node.data)
+    }
+}
+
+/// An iterator over the elements of a `StaticLinkedList`.
+pub struct Iter<'a, T, const N: usize> {
+    /// The list being iterated.
+    list: &'a StaticLinkedList<T, N>,
+    /// The index of the next node to yield.
+    current: Option<usize>,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.list.nodes[self.current?].as_ref().unwrap();
+        self.current = node.next;
+        Some(&node.data)
+    }
+}
 
-    /// Finds whether an element matching the provided data exists in the list.
+/// An iterator over the elements of a `StaticLinkedList` and the slots they occupy.
+pub struct IterSlots<'a, T, const N: usize> {
+    /// The list being iterated.
+    list: &'a StaticLinkedList<T, N>,
+    /// The index of the next node to yield.
+    current: Option<usize>,
+}
+
+impl<'a, T, const N: usize> Iterator for IterSlots<'a, T, N> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<(usize, &'a T)> {
+        let slot = self.current?;
+        let node = self.list.nodes[slot].as_ref().unwrap();
+        self.current = node.next;
+        Some((slot, &node.data))
+    }
+}
+
+/// A read-only cursor over a `StaticLinkedList`, remembering its position between steps.
+pub struct Cursor<'a, T, const N: usize> {
+    /// The list the cursor walks.
+    list: &'a StaticLinkedList<T, N>,
+    /// The slot of the node at the cursor, or `None` past the end.
+    current: Option<usize>,
+    /// The position of the cursor.
+    index: usize,
+}
+
+impl<'a, T, const N: usize> Cursor<'a, T, N> {
+    /// Returns the position of the cursor. Past the last element this is the length of
+    /// the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the element at the cursor, or `None` past the end.
+    pub fn peek(&self) -> Option<&'a T> {
+        let slot = self.current?;
+        Some(&self.list.nodes[slot].as_ref().unwrap().data)
+    }
+
+    /// Moves the cursor to the next element. Does nothing once past the end.
+    pub fn move_next(&mut self) {
+        if let Some(slot) = self.current {
+            self.current = self.list.nodes[slot].as_ref().unwrap().next;
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to `index`, walking forward from the current position, or from
+    /// the front if `index` is behind it. An index past the end stops at the end.
     ///
     /// # Arguments
     ///
-    /// * data - The data to search for in the linked list.
+    /// * index - The position to move to.
     ///
     /// # Returns
     ///
-    /// * true - If an element matching the data is found.
-    /// * false - If no element matching the data is found.
-    fn find(&self, data: &T) -> bool {
-        let mut current_index = self.head;
-        while let Some(i) = current_index {
-            if &self.nodes[i].as_ref().unwrap().data == data {
-                return true;
-            }
-            current_index = self.nodes[i].as_ref().unwrap().next;
+    /// * Some(&T) - The element at `index`, if it exists.
+    /// * None - Otherwise.
+    pub fn seek(&mut self, index: usize) -> Option<&'a T> {
+        if index < self.index {
+            *self = self.list.cursor();
         }
-        false
+        while self.index < index && self.current.is_some() {
+            self.move_next();
+        }
+        self.peek()
     }
+}
 
-    /// Retrieves the element at the specified index in the linked list.
+impl<T, const N: usize> Clone for Cursor<'_, T, N> {
+    fn clone(&self) -> Self {
+        Cursor {
+            list: self.list,
+            current: self.current,
+            index: self.index,
+        }
+    }
+}
+
+/// A mutable cursor over a `StaticLinkedList`, editing the list in place at its position.
+///
+/// The list is singly linked, so the cursor only moves forward. Past the last element the
+/// cursor sits at the end, where insertions append to the list.
+pub struct CursorMut<'a, T, const N: usize> {
+    /// The list the cursor edits.
+    list: &'a mut StaticLinkedList<T, N>,
+    /// The slot of the node before the cursor, or `None` at the front.
+    previous: Option<usize>,
+    /// The slot of the node at the cursor, or `None` past the end.
+    current: Option<usize>,
+    /// The position of the cursor.
+    index: usize,
+}
+
+impl<T, const N: usize> CursorMut<'_, T, N> {
+    /// Returns the position of the cursor. Past the last element this is the length of
+    /// the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the element at the cursor, or `None` past the end.
+    pub fn current(&mut self) -> Option<&mut T> {
+        let slot = self.current?;
+        Some(&mut self.list.nodes[slot].as_mut().unwrap().data)
+    }
+
+    /// Moves the cursor to the next element. Does nothing once past the end.
+    pub fn move_next(&mut self) {
+        if let Some(slot) = self.current {
+            self.previous = Some(slot);
+            self.current = self.list.nodes[slot].as_ref().unwrap().next;
+            self.index += 1;
+        }
+    }
+
+    /// Inserts an element before the cursor. The cursor stays on the same element, so its
+    /// index grows by one.
     ///
     /// # Arguments
     ///
-    /// * index - The index of the element to retrieve.
+    /// * data - The data to insert.
     ///
     /// # Returns
     ///
-    /// * Some(&T) - If an element exists at the specified index.
-    /// * None - If the index is out of bounds.
-    fn get(&self, index: usize) -> Option<&T> {
-        let mut current_index = self.head;
-        for _ in 0..index {
-            match current_index {
-                Some(i) => {
-                    current_index = self.nodes[i].as_ref().unwrap().next;
-                }
-                None => return None,
+    /// * Ok(()) - If the element was inserted.
+    /// * Err(LinkedListError) - If the list is full.
+    pub fn insert_before(&mut self, data: T) -> Result<(), LinkedListError> {
+        let slot = self
+            .list
+            .link_after(self.previous, data)
+            .ok_or(LinkedListError::CapacityExceeded { capacity: N })?;
+        self.previous = Some(slot);
+        self.index += 1;
+        Ok(())
+    }
+
+    /// Inserts an element after the cursor. Past the end, the element is appended like
+    /// with `insert_before`.
+    ///
+    /// # Arguments
+    ///
+    /// * data - The data to insert.
+    ///
+    /// # Returns
+    ///
+    /// * Ok(()) - If the element was inserted.
+    /// * Err(LinkedListError) - If the list is full.
+    pub fn insert_after(&mut self, data: T) -> Result<(), LinkedListError> {
+        let Some(current) = self.current else {
+            return self.insert_before(data);
+        };
+        self.list
+            .link_after(Some(current), data)
+            .ok_or(LinkedListError::CapacityExceeded { capacity: N })?;
+        Ok(())
+    }
+
+    /// Removes the element at the cursor and frees its slot, moving the cursor onto the
+    /// element after it.
+    ///
+    /// # Returns
+    ///
+    /// * Some(T) - The removed element.
+    /// * None - If the cursor is past the end.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let slot = self.current?;
+        let node = self.list.unlink(self.previous, slot);
+        self.current = node.next;
+        Some(node.data)
+    }
+
+    /// Splits the list after the cursor, moving the elements after it into a new list and
+    /// leaving the cursor at the end of this list.
+    ///
+    /// # Returns
+    ///
+    /// * StaticLinkedList<T, N> - The elements after the cursor, empty if the cursor is
+    ///   past the end.
+    pub fn split_after(&mut self) -> StaticLinkedList<T, N> {
+        let mut split = StaticLinkedList::new();
+        let Some(current) = self.current else {
+            return split;
+        };
+        let mut tail = None;
+        while let Some(slot) = self.list.next_of(Some(current)) {
+            let node = self.list.unlink(Some(current), slot);
+            // The split list has as many free slots as this list had nodes.
+            tail = split.link_after(tail, node.data);
+        }
+        split
+    }
+}
+
+/// Kani proof harnesses for the slot/free-list index arithmetic.
+///
+/// Run with `cargo kani --features verification`. Each harness drives a small list
+/// through a bounded sequence of arbitrary operations; Kani proves that no array access
+/// goes out of bounds and that every slot is always accounted for exactly once.
+#[cfg(all(kani, feature = "verification"))]
+mod verification {
+    use super::*;
+
+    /// Capacity used by the harnesses. Small enough for Kani to explore exhaustively.
+    const N: usize = 3;
+
+    /// Maximum number of operations applied per harness.
+    const STEPS: usize = 4;
+
+    /// Asserts that each slot is either in the chain or in the free list, never both,
+    /// that every stored index is within the array, and that `tail` is the last slot.
+    fn assert_slots_accounted(list: &StaticLinkedList<u8, N>) {
+        let mut seen = [false; N];
+        let mut current_index = list.head;
+        let mut last = None;
+        let mut chain_len = 0;
+        while let Some(i) = current_index {
+            assert!(i < N);
+            assert!(!seen[i]); // A repeated slot would mean a cycle.
+            seen[i] = true;
+            chain_len += 1;
+            last = Some(i);
+            current_index = list.nodes[i].as_ref().unwrap().next;
+        }
+        assert_eq!(list.tail, last);
+
+        for &i in &list.free[..list.free_len] {
+            assert!(i < N);
+            assert!(!seen[i]); // A slot may not be both linked and free.
+            assert!(list.nodes[i].is_none());
+            seen[i] = true;
+        }
+
+        assert_eq!(chain_len + list.free_len, N); // No slot leaked.
+    }
+
+    /// Proves that `insert_at_index` stays in bounds and never leaks slots.
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn insert_at_index_preserves_slots() {
+        let mut list: StaticLinkedList<u8, N> = StaticLinkedList::new();
+        for _ in 0..STEPS {
+            let index: usize = kani::any();
+            kani::assume(index <= N + 1);
+            let _ = list.insert_at_index(index, kani::any());
+            assert_slots_accounted(&list);
+        }
+    }
+
+    /// Proves that interleaved inserts and `delete_at_index` calls stay in bounds and
+    /// never leak slots.
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn delete_at_index_preserves_slots() {
+        let mut list: StaticLinkedList<u8, N> = StaticLinkedList::new();
+        for _ in 0..STEPS {
+            let index: usize = kani::any();
+            kani::assume(index <= N + 1);
+            if kani::any() {
+                let _ = list.insert_at_index(index, kani::any());
+            } else {
+                let _ = list.delete_at_index(index);
             }
+            assert_slots_accounted(&list);
         }
+    }
 
-        match current_index {
-            Some(i) => Some(&self.nodes[i].as_ref().unwrap().data),
-            None => None,
+    /// Proves that `compact` packs the chain into the lowest slots without losing or
+    /// leaking any of them.
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn compact_packs_slots() {
+        let mut list: StaticLinkedList<u8, N> = StaticLinkedList::new();
+        for _ in 0..STEPS {
+            let index: usize = kani::any();
+            kani::assume(index <= N + 1);
+            if kani::any() {
+                let _ = list.insert_at_index(index, kani::any());
+            } else {
+                let _ = list.delete_at_index(index);
+            }
+        }
+        list.compact();
+        assert_slots_accounted(&list);
+        for slot in 0..N {
+            assert_eq!(list.nodes[slot].is_some(), slot < list.len()); // Occupied prefix.
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,53 @@
+//! Quick numeric reductions over any list, through the [`ListStats`] extension trait.
+
+use core::iter::Sum;
+
+use crate::LinkedListTrait;
+
+/// `ListStats` adds single-pass aggregates to every [`LinkedListTrait`] list, so a
+/// reduction does not need an iterator chain at the call site. Each method walks the
+/// list once and is only available when the element type supports it.
+pub trait ListStats<T>: LinkedListTrait<T> {
+    /// Returns the sum of the elements, or zero for an empty list.
+    fn sum(&self) -> T
+    where
+        T: for<'a> Sum<&'a T>,
+    {
+        self.iter().sum()
+    }
+
+    /// Returns the smallest element, or `None` if the list is empty. Of several equal
+    /// smallest elements, the first is returned.
+    fn min(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns the largest element, or `None` if the list is empty. Of several equal
+    /// largest elements, the last is returned.
+    fn max(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns the arithmetic mean of the elements as an `f64`.
+    ///
+    /// # Returns
+    /// - `Some(f64)` with the mean.
+    /// - `None` if the list is empty.
+    fn mean(&self) -> Option<f64>
+    where
+        T: Copy + Into<f64>,
+    {
+        let (count, total) = self.iter().fold((0usize, 0.0), |(count, total), &data| {
+            (count + 1, total + data.into())
+        });
+        (count != 0).then(|| total / count as f64)
+    }
+}
+
+impl<T, L: LinkedListTrait<T> + ?Sized> ListStats<T> for L {}
@@ -0,0 +1,299 @@
+//! A `std::collections::LinkedList` front for `DoublyLinkedList`.
+//!
+//! [`LinkedList`] has the method names, signatures and trait impls of the standard
+//! library's list, so code written against it can move onto this crate by changing an
+//! import. The iterator types are re-exported under the standard names as well.
+//!
+//! The backing list stores its nodes in a slot vector rather than in separate
+//! allocations, which changes two costs: [`append`](LinkedList::append) and
+//! [`split_off`](LinkedList::split_off) move the elements they transfer, so they are O(n)
+//! in those elements instead of O(1).
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+
+use crate::doubly_linked_list::DoublyLinkedList;
+use crate::ListWrite;
+
+pub use crate::doubly_linked_list::{Iter, IterMut};
+
+/// A doubly linked list with the `std::collections::LinkedList` interface, backed by a
+/// `DoublyLinkedList`.
+pub struct LinkedList<T> {
+    /// The backing list.
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> LinkedList<T> {
+    /// Creates a new, empty `LinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `LinkedList` instance.
+    pub fn new() -> Self {
+        LinkedList {
+            list: DoublyLinkedList::new(),
+        }
+    }
+
+    /// Moves all elements of `other` to the back of this list, leaving `other` empty.
+    ///
+    /// # Parameters
+    /// - `other`: The list whose elements are moved.
+    pub fn append(&mut self, other: &mut Self) {
+        while let Some(data) = other.pop_front() {
+            self.push_back(data);
+        }
+    }
+
+    /// Returns a double-ended iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Returns a double-ended iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.list = DoublyLinkedList::new();
+    }
+
+    /// Returns `true` if the list contains an element equal to `data`.
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if the list is empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.get_mut(0)
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if the list is empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let last = self.len().checked_sub(1)?;
+        self.list.get_mut(last)
+    }
+
+    /// Prepends an element to the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        self.list.push_front(data);
+    }
+
+    /// Removes and returns the first element, or `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Appends an element to the back of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        self.list.push_back(data);
+    }
+
+    /// Removes and returns the last element, or `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Splits the list in two at the given index.
+    ///
+    /// # Parameters
+    /// - `at`: The index of the first element of the returned list.
+    ///
+    /// # Returns
+    /// - A list holding the elements from `at` on; this list keeps the ones before it.
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than the length, like the standard list.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(at <= len, "cannot split off at a nonexistent index");
+        let mut back = Self::new();
+        for _ in at..len {
+            back.list
+                .push_front(self.list.pop_back().expect("index checked above"));
+        }
+        back
+    }
+
+    /// Consumes the list and returns the backing `DoublyLinkedList`.
+    pub fn into_inner(self) -> DoublyLinkedList<T> {
+        self.list
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<DoublyLinkedList<T>> for LinkedList<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        LinkedList { list }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: Debug> Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// An owning double-ended iterator over the elements of a [`LinkedList`], made by
+/// `into_iter`.
+pub struct IntoIter<T> {
+    /// The elements not yet yielded.
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T: Debug> Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
+    }
+}
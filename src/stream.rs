@@ -0,0 +1,121 @@
+//! Async `Stream` adapters, enabled by the `futures` feature.
+//!
+//! [`iter`] streams the elements of any list that can be iterated by reference. For data
+//! produced while the consumer runs, [`channel`] pairs an [`MsQueue`] with a waker: any
+//! number of [`QueueProducer`]s push into the lock-free queue, and the single
+//! [`QueueStream`] yields elements as they arrive and ends once every producer is gone,
+//! so a consumer can simply loop on `while let Some(x) = stream.next().await`.
+
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::stream::{self, Stream};
+use futures::task::AtomicWaker;
+
+use crate::ms_queue::MsQueue;
+
+/// Returns a stream over references to the elements of `list`, front to back.
+///
+/// # Parameters
+/// - `list`: The list to stream, e.g. `&DynamicLinkedList<T>`.
+///
+/// # Returns
+/// - A stream that is always ready with the next element.
+pub fn iter<'a, L>(list: &'a L) -> stream::Iter<<&'a L as IntoIterator>::IntoIter>
+where
+    &'a L: IntoIterator,
+{
+    stream::iter(list)
+}
+
+/// Creates a queue whose elements are consumed as a `Stream`.
+///
+/// # Returns
+/// - A producer handle, which can be cloned for more producers, and the stream.
+pub fn channel<T>() -> (QueueProducer<T>, QueueStream<T>) {
+    let shared = Arc::new(Shared {
+        queue: MsQueue::new(),
+        waker: AtomicWaker::new(),
+        producers: AtomicUsize::new(1),
+    });
+    (
+        QueueProducer {
+            shared: Arc::clone(&shared),
+        },
+        QueueStream { shared },
+    )
+}
+
+/// The state shared by the producers and the stream.
+struct Shared<T> {
+    /// The queued elements.
+    queue: MsQueue<T>,
+    /// Wakes the stream's task when an element arrives or the last producer leaves.
+    waker: AtomicWaker,
+    /// The number of live producers. The stream ends once this reaches zero.
+    producers: AtomicUsize,
+}
+
+/// The sending half of a [`channel`]. Cloning it adds a producer.
+pub struct QueueProducer<T> {
+    /// The queue and waker shared with the stream.
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> QueueProducer<T> {
+    /// Adds an element to the back of the queue and wakes the stream.
+    ///
+    /// # Parameters
+    /// - `data`: The value to enqueue.
+    pub fn push(&self, data: T) {
+        self.shared.queue.enqueue(data);
+        self.shared.waker.wake();
+    }
+}
+
+impl<T> Clone for QueueProducer<T> {
+    fn clone(&self) -> Self {
+        self.shared.producers.fetch_add(1, Ordering::Relaxed);
+        QueueProducer {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for QueueProducer<T> {
+    /// Leaves the channel, waking the stream so it can end if this was the last producer.
+    fn drop(&mut self) {
+        if self.shared.producers.fetch_sub(1, Ordering::Release) == 1 {
+            self.shared.waker.wake();
+        }
+    }
+}
+
+/// The receiving half of a [`channel`]: a stream of the queued elements in FIFO order.
+pub struct QueueStream<T> {
+    /// The queue and waker shared with the producers.
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Stream for QueueStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let shared = &self.shared;
+        if let Some(data) = shared.queue.try_dequeue() {
+            return Poll::Ready(Some(data));
+        }
+        shared.waker.register(cx.waker());
+        // Check again: an element pushed before the registration would not wake us.
+        if let Some(data) = shared.queue.try_dequeue() {
+            return Poll::Ready(Some(data));
+        }
+        if shared.producers.load(Ordering::Acquire) == 0 {
+            // Every push happened before the last producer left, so this sees them all.
+            return Poll::Ready(shared.queue.try_dequeue());
+        }
+        Poll::Pending
+    }
+}
@@ -0,0 +1,20 @@
+//! Synchronization primitives used by the concurrent types.
+//!
+//! Under `--cfg loom` these come from `loom`, so the loom tests can explore every
+//! interleaving of the atomic operations and lock acquisitions; otherwise they are the
+//! `core` and `std` types. Retry loops call `spin_loop` so that loom knows the thread is
+//! waiting on another one.
+
+#[cfg(loom)]
+pub(crate) use loom::hint::spin_loop;
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(loom)]
+pub(crate) use loom::sync::{Mutex, MutexGuard};
+
+#[cfg(not(loom))]
+pub(crate) use core::hint::spin_loop;
+#[cfg(not(loom))]
+pub(crate) use core::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(not(loom))]
+pub(crate) use std::sync::{Mutex, MutexGuard};
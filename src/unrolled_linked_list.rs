@@ -0,0 +1,467 @@
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+use core::slice;
+
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// `Node` represents a block of up to `B` consecutive elements in the unrolled list.
+#[derive(Debug)]
+struct Node<T, const B: usize> {
+    /// The elements stored in this node. Only the first `len` slots are occupied.
+    items: [Option<T>; B],
+    /// The number of occupied slots in `items`.
+    len: usize,
+    /// A pointer to the next node in the list.
+    next: Option<Box<Node<T, B>>>,
+}
+
+impl<T, const B: usize> Node<T, B> {
+    /// Allocates a new, empty node.
+    fn new() -> Box<Self> {
+        Box::new(Node {
+            items: array_init::array_init(|_| None),
+            len: 0,
+            next: None,
+        })
+    }
+
+    /// Returns `true` if no more elements fit in this node.
+    fn is_full(&self) -> bool {
+        self.len == B
+    }
+
+    /// Inserts `data` at `offset` within this node, shifting later elements right.
+    ///
+    /// The node must not be full and `offset` must be at most `len`.
+    fn insert(&mut self, offset: usize, data: T) {
+        for i in (offset..self.len).rev() {
+            self.items[i + 1] = self.items[i].take();
+        }
+        self.items[offset] = Some(data);
+        self.len += 1;
+    }
+
+    /// Removes and returns the element at `offset`, shifting later elements left.
+    ///
+    /// `offset` must be less than `len`.
+    fn remove(&mut self, offset: usize) -> T {
+        let data = self.items[offset].take().expect("occupied slot");
+        for i in offset..(self.len - 1) {
+            self.items[i] = self.items[i + 1].take();
+        }
+        self.len -= 1;
+        data
+    }
+
+    /// Moves the upper half of this (full) node into a new node linked directly after it.
+    fn split(&mut self) {
+        let keep = B / 2;
+        let mut new_node = Node::new();
+        for i in keep..self.len {
+            new_node.items[i - keep] = self.items[i].take();
+        }
+        new_node.len = self.len - keep;
+        self.len = keep;
+        new_node.next = self.next.take();
+        self.next = Some(new_node);
+    }
+
+    /// Moves every element of the next node into this one and unlinks it, provided they fit.
+    fn merge_next(&mut self) {
+        if let Some(mut next) = self.next.take() {
+            if self.len + next.len <= B {
+                for i in 0..next.len {
+                    self.items[self.len + i] = next.items[i].take();
+                }
+                self.len += next.len;
+                self.next = next.next.take();
+            } else {
+                self.next = Some(next);
+            }
+        }
+    }
+}
+
+/// `UnrolledLinkedList` is a singly linked list where each node stores up to `B` elements.
+///
+/// Packing several elements into each node means far fewer allocations and pointer hops
+/// than one box per element, which makes iteration and positional lookups much more
+/// cache-friendly. Nodes split when an insertion would overflow them and merge with
+/// their successor when deletions leave them less than half full.
+///
+/// `B` must be at least 2.
+#[derive(Debug)]
+pub struct UnrolledLinkedList<T, const B: usize> {
+    /// A pointer to the first node of the list.
+    head: Option<Box<Node<T, B>>>,
+    /// The total number of elements across all nodes.
+    len: usize,
+}
+
+impl<T, const B: usize> UnrolledLinkedList<T, B> {
+    /// Compile-time check that nodes can be split in two.
+    const VALID_NODE_SIZE: () = assert!(B >= 2, "UnrolledLinkedList requires B >= 2");
+
+    /// Creates a new, empty `UnrolledLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `UnrolledLinkedList` instance.
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_NODE_SIZE;
+        UnrolledLinkedList { head: None, len: 0 }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of nodes currently allocated.
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut current = self.head.as_deref();
+        while let Some(node) = current {
+            count += 1;
+            current = node.next.as_deref();
+        }
+        count
+    }
+
+    /// Returns an iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, B> {
+        Iter {
+            node: self.head.as_deref(),
+            offset: 0,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, B> {
+        IterMut {
+            items: Default::default(),
+            next: self.head.as_deref_mut(),
+        }
+    }
+
+    /// Appends an element, starting a new node only when the last one is full.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        let mut link = &mut self.head;
+        while link.as_ref().is_some_and(|node| node.next.is_some()) {
+            link = &mut link.as_mut().unwrap().next;
+        }
+
+        match link {
+            Some(last) if !last.is_full() => {
+                let len = last.len;
+                last.insert(len, data);
+            }
+            Some(last) => {
+                let mut node = Node::new();
+                node.insert(0, data);
+                last.next = Some(node);
+            }
+            None => {
+                let mut node = Node::new();
+                node.insert(0, data);
+                *link = Some(node);
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Returns the node holding logical position `index` together with the offset
+    /// of that element inside the node.
+    fn locate_mut(&mut self, index: usize) -> Option<(&mut Node<T, B>, usize)> {
+        let mut offset = index;
+        let mut current = self.head.as_deref_mut();
+        while let Some(node) = current {
+            if offset < node.len {
+                return Some((node, offset));
+            }
+            offset -= node.len;
+            current = node.next.as_deref_mut();
+        }
+        None
+    }
+
+    /// Removes and returns the element at `index`, which must be less than `len`.
+    ///
+    /// Empty nodes are unlinked, and a node left less than half full is merged with
+    /// its successor when their elements fit in a single node.
+    fn remove_at(&mut self, index: usize) -> T {
+        let mut offset = index;
+        let mut link = &mut self.head;
+        while link.as_ref().is_some_and(|node| offset >= node.len) {
+            offset -= link.as_ref().unwrap().len;
+            link = &mut link.as_mut().unwrap().next;
+        }
+
+        let node = link.as_mut().expect("index must be in bounds");
+        let data = node.remove(offset);
+        if node.len == 0 {
+            *link = node.next.take();
+        } else if node.len < B / 2 {
+            node.merge_next();
+        }
+        self.len -= 1;
+        data
+    }
+}
+
+impl<T, const B: usize> Drop for UnrolledLinkedList<T, B> {
+    /// Unlinks nodes one at a time so long lists don't recurse through `Box` drops.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+impl<T, const B: usize> Default for UnrolledLinkedList<T, B> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const B: usize> ListRead<T> for UnrolledLinkedList<T, B> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// Whole nodes are skipped at a time, so this is O(n / B).
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        let mut offset = index;
+        let mut current = self.head.as_deref();
+        while let Some(node) = current {
+            if offset < node.len {
+                return node.items[offset].as_ref();
+            }
+            offset -= node.len;
+            current = node.next.as_deref();
+        }
+        None
+    }
+}
+
+impl<T, const B: usize> ListWrite<T> for UnrolledLinkedList<T, B> {
+    /// Inserts an element at the end (tail) of the list.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in the list, splitting a full node if needed.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        if index == self.len {
+            self.push_back(data);
+            return Ok(());
+        }
+
+        let (node, offset) = self.locate_mut(index).unwrap();
+        if node.is_full() {
+            node.split();
+            if offset > node.len {
+                let new_offset = offset - node.len;
+                node.next.as_mut().unwrap().insert(new_offset, data);
+                self.len += 1;
+                return Ok(());
+            }
+        }
+        node.insert(offset, data);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.position(data) {
+            Some(index) => {
+                self.remove_at(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        self.remove_at(index);
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (node, offset) = self.locate_mut(index)?;
+        node.items[offset].as_mut()
+    }
+
+    /// Updates the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        match self.locate_mut(index) {
+            Some((node, offset)) => {
+                node.items[offset] = Some(data);
+                Ok(())
+            }
+            None => Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+}
+
+impl<T, const B: usize> LinkedListTrait<T> for UnrolledLinkedList<T, B> {
+    type Iter<'a>
+        = Iter<'a, T, B>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T, B>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        UnrolledLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        UnrolledLinkedList::iter_mut(self)
+    }
+}
+
+/// An iterator over the elements of an `UnrolledLinkedList`.
+pub struct Iter<'a, T, const B: usize> {
+    /// The node currently being read.
+    node: Option<&'a Node<T, B>>,
+    /// The offset of the next element within `node`.
+    offset: usize,
+}
+
+impl<'a, T, const B: usize> Iterator for Iter<'a, T, B> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let node = self.node?;
+            if self.offset < node.len {
+                self.offset += 1;
+                return node.items[self.offset - 1].as_ref();
+            }
+            self.node = node.next.as_deref();
+            self.offset = 0;
+        }
+    }
+}
+
+/// An iterator over mutable references to the elements of an `UnrolledLinkedList`.
+pub struct IterMut<'a, T, const B: usize> {
+    /// The occupied slots of the current node that have not been yielded yet.
+    items: slice::IterMut<'a, Option<T>>,
+    /// The node to read once `items` runs out.
+    next: Option<&'a mut Node<T, B>>,
+}
+
+impl<'a, T, const B: usize> Iterator for IterMut<'a, T, B> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return item.as_mut();
+            }
+            let Node { items, len, next } = self.next.take()?;
+            self.items = items[..*len].iter_mut();
+            self.next = next.as_deref_mut();
+        }
+    }
+}
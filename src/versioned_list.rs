@@ -0,0 +1,395 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::counted::Counted;
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{LinkedListError, ListRead, ListWrite};
+
+/// `Edit` is one recorded mutation of a `VersionedList`, with the data needed to revert it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<T> {
+    /// `data` was inserted at `index`.
+    Inserted { index: usize, data: T },
+    /// `data` was removed from `index`.
+    Deleted { index: usize, data: T },
+    /// The element at `index` was changed from `old` to `new`.
+    Updated { index: usize, old: T, new: T },
+}
+
+/// Returns an id that no other edit or history of any `VersionedList` has, so a snapshot
+/// can only match the history it was taken from.
+fn next_id() -> usize {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// `ListSnapshot` is a checkpoint of a `VersionedList`, taken with
+/// [`snapshot`](VersionedList::snapshot) and rolled back to with
+/// [`restore`](VersionedList::restore).
+///
+/// It names a point in the list's history instead of copying the elements, so taking one
+/// is O(1) and the elements stay shared with the list; restoring undoes or redoes the
+/// edits in between. A snapshot can be restored any number of times, as long as the point
+/// it names is still in the history.
+pub struct ListSnapshot<T> {
+    /// The number of applied edits at the checkpoint.
+    depth: usize,
+    /// The id of the last applied edit at the checkpoint, or of the start of the history
+    /// if there was none.
+    id: usize,
+    /// Ties the snapshot to lists of `T`.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for ListSnapshot<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListSnapshot<T> {}
+
+impl<T> Debug for ListSnapshot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListSnapshot")
+            .field("depth", &self.depth)
+            .finish()
+    }
+}
+
+/// `VersionedList` wraps a list and records every mutation so it can be undone and redone.
+///
+/// Each successful call through `ListWrite` pushes an [`Edit`] onto the history.
+/// [`undo`](Self::undo) reverts the latest edit and makes it available to
+/// [`redo`](Self::redo); any new mutation discards the redo stack, as in a text editor.
+///
+/// Value-based operations (`delete_element`, `update_element`) first look up the element's
+/// index with the wrapped list's `position`, so the edit can be replayed by index.
+/// `get_mut` always returns `None`, since a change through it could not be recorded.
+///
+/// [`snapshot`](Self::snapshot) and [`restore`](Self::restore) checkpoint the list, so a
+/// speculative algorithm can roll back a failed attempt in one call.
+#[derive(Debug)]
+pub struct VersionedList<T, L = DynamicLinkedList<T>> {
+    /// The wrapped list, with its length tracked for index bookkeeping.
+    list: Counted<L>,
+    /// Applied edits, oldest first.
+    undo: Vec<Edit<T>>,
+    /// Undone edits, most recently undone last.
+    redo: Vec<Edit<T>>,
+    /// The ids of the edits in `undo`, which snapshots refer to.
+    undo_ids: Vec<usize>,
+    /// The ids of the edits in `redo`.
+    redo_ids: Vec<usize>,
+    /// The id of the start of the history, which changes when it is cleared.
+    base_id: usize,
+}
+
+impl<T> VersionedList<T> {
+    /// Creates a new, empty `VersionedList` backed by a `DynamicLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `VersionedList` instance.
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: Default> VersionedList<T, L> {
+    /// Creates a new, empty `VersionedList` using any backing list.
+    ///
+    /// # Returns
+    /// - A new empty `VersionedList` instance.
+    pub fn empty() -> Self {
+        VersionedList {
+            list: Counted::default(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            undo_ids: Vec::new(),
+            redo_ids: Vec::new(),
+            base_id: next_id(),
+        }
+    }
+}
+
+impl<T, L> VersionedList<T, L> {
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the applied edits, oldest first.
+    pub fn history(&self) -> &[Edit<T>] {
+        &self.undo
+    }
+
+    /// Returns `true` if there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all recorded edits, keeping the current contents. Snapshots taken before
+    /// can no longer be restored.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.undo_ids.clear();
+        self.redo_ids.clear();
+        self.base_id = next_id();
+    }
+
+    /// Takes a checkpoint of the current contents in O(1).
+    ///
+    /// # Returns
+    /// - A `ListSnapshot` to pass to [`restore`](Self::restore).
+    pub fn snapshot(&self) -> ListSnapshot<T> {
+        ListSnapshot {
+            depth: self.undo.len(),
+            id: self.id_at(self.undo.len()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the id of the state after the first `depth` applied edits.
+    fn id_at(&self, depth: usize) -> usize {
+        match depth {
+            0 => self.base_id,
+            _ => self.undo_ids[depth - 1],
+        }
+    }
+
+    /// Consumes the wrapper and returns the wrapped list.
+    pub fn into_inner(self) -> L {
+        self.list.into_inner()
+    }
+}
+
+impl<T: Clone, L: ListWrite<T>> VersionedList<T, L> {
+    /// Reverts the most recent edit.
+    ///
+    /// # Returns
+    /// - `true` if an edit was undone.
+    /// - `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        self.revert(&edit);
+        self.redo.push(edit);
+        self.redo_ids.extend(self.undo_ids.pop());
+        true
+    }
+
+    /// Re-applies the most recently undone edit.
+    ///
+    /// # Returns
+    /// - `true` if an edit was redone.
+    /// - `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        self.apply(&edit);
+        self.undo.push(edit);
+        self.undo_ids.extend(self.redo_ids.pop());
+        true
+    }
+
+    /// Brings the list back to the contents it had when `snapshot` was taken, undoing the
+    /// edits made since, or redoing them if the snapshot was taken before an undo.
+    ///
+    /// # Parameters
+    /// - `snapshot`: A checkpoint taken from this list.
+    ///
+    /// # Returns
+    /// - `true` if the list was restored.
+    /// - `false` if the snapshot was taken from another list, or its point in the history
+    ///   is gone because the history was cleared or an undone edit was replaced by a new
+    ///   one. The list is unchanged.
+    pub fn restore(&mut self, snapshot: &ListSnapshot<T>) -> bool {
+        let depth = self.undo.len();
+        if snapshot.depth <= depth && self.id_at(snapshot.depth) == snapshot.id {
+            while self.undo.len() > snapshot.depth {
+                self.undo();
+            }
+            return true;
+        }
+        let ahead = snapshot.depth.saturating_sub(depth);
+        if ahead == 0 || ahead > self.redo.len() {
+            return false;
+        }
+        if self.redo_ids[self.redo.len() - ahead] != snapshot.id {
+            return false;
+        }
+        for _ in 0..ahead {
+            self.redo();
+        }
+        true
+    }
+
+    /// Applies `edit` to the wrapped list without recording it.
+    fn apply(&mut self, edit: &Edit<T>) {
+        let result = match edit {
+            Edit::Inserted { index, data } => self.list.insert_at_index(*index, data.clone()),
+            Edit::Deleted { index, .. } => self.list.delete_at_index(*index),
+            Edit::Updated { index, new, .. } => {
+                self.list.update_element_at_index(*index, new.clone())
+            }
+        };
+        result.expect("recorded edits must replay in bounds");
+    }
+
+    /// Applies the inverse of `edit` to the wrapped list without recording it.
+    fn revert(&mut self, edit: &Edit<T>) {
+        let result = match edit {
+            Edit::Inserted { index, .. } => self.list.delete_at_index(*index),
+            Edit::Deleted { index, data } => self.list.insert_at_index(*index, data.clone()),
+            Edit::Updated { index, old, .. } => {
+                self.list.update_element_at_index(*index, old.clone())
+            }
+        };
+        result.expect("recorded edits must revert in bounds");
+    }
+
+    /// Records a successfully applied edit, discarding anything that could be redone.
+    fn record(&mut self, edit: Edit<T>) {
+        self.redo.clear();
+        self.redo_ids.clear();
+        self.undo.push(edit);
+        self.undo_ids.push(next_id());
+    }
+}
+
+impl<T, L: Default> Default for VersionedList<T, L> {
+    /// Provides a default (empty) instance of the list using `empty()`.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, L: ListRead<T>> ListRead<T> for VersionedList<T, L> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.list.position_by(predicate)
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+}
+
+impl<T: Clone, L: ListWrite<T>> ListWrite<T> for VersionedList<T, L> {
+    /// Inserts an element at the end (tail) of the list and records the edit.
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        let index = self.list.len();
+        let _ = self.insert_at_index(index, data);
+    }
+
+    /// Inserts an element at a specific index in the list and records the edit.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError)` with the wrapped list's error otherwise.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        self.list.insert_at_index(index, data.clone())?;
+        self.record(Edit::Inserted { index, data });
+        Ok(())
+    }
+
+    /// Deletes the element at the specified index and records the edit.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        let data = match self.list.get(index) {
+            Some(data) => data.clone(),
+            None => {
+                return Err(LinkedListError::IndexOutOfBounds {
+                    index,
+                    len: self.len(),
+                })
+            }
+        };
+        self.list.delete_at_index(index)?;
+        self.record(Edit::Deleted { index, data });
+        Ok(())
+    }
+
+    /// Always returns `None`: a change made through a mutable reference could not be
+    /// recorded, so it would escape [`undo`](Self::undo). Use `update_element_at_index`
+    /// instead.
+    fn get_mut(&mut self, _index: usize) -> Option<&mut T> {
+        None
+    }
+
+    /// Updates the element at the specified index and records the edit.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        let old = match self.list.get(index) {
+            Some(old) => old.clone(),
+            None => {
+                return Err(LinkedListError::IndexOutOfBounds {
+                    index,
+                    len: self.len(),
+                })
+            }
+        };
+        self.list.update_element_at_index(index, data.clone())?;
+        self.record(Edit::Updated {
+            index,
+            old,
+            new: data,
+        });
+        Ok(())
+    }
+}
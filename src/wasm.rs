@@ -0,0 +1,89 @@
+//! JavaScript bindings through `wasm-bindgen`, enabled by the `wasm` feature.
+//!
+//! [`JsLinkedList`] exposes a `DynamicLinkedList` of numbers to JavaScript as the class
+//! `LinkedList`, with camel-case methods. Positions are plain numbers, a missing element
+//! is `undefined`, and a failed call throws the error message as a string. Build with
+//! `wasm-pack build --features wasm` to get the JavaScript package.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::dynamic_linked_list::DynamicLinkedList;
+use crate::{ListRead, ListWrite};
+
+/// A linked list of numbers, exported to JavaScript as `LinkedList`.
+#[wasm_bindgen(js_name = LinkedList)]
+#[derive(Debug, Default)]
+pub struct JsLinkedList {
+    /// The wrapped list.
+    list: DynamicLinkedList<f64>,
+}
+
+#[wasm_bindgen(js_class = LinkedList)]
+impl JsLinkedList {
+    /// Creates an empty list: `new LinkedList()`.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        JsLinkedList::default()
+    }
+
+    /// Returns the number of elements, as the `length` property.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Appends a value to the end of the list.
+    pub fn insert(&mut self, value: f64) {
+        self.list.insert(value);
+    }
+
+    /// Inserts a value at `index`, shifting the later values back.
+    ///
+    /// # Returns
+    /// - `Err(String)` with the error message, thrown in JavaScript, if `index` is past the
+    ///   end.
+    #[wasm_bindgen(js_name = insertAt)]
+    pub fn insert_at(&mut self, index: usize, value: f64) -> Result<(), String> {
+        self.list
+            .insert_at_index(index, value)
+            .map_err(|error| error.to_string())
+    }
+
+    /// Returns the value at `index`, or `undefined` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<f64> {
+        self.list.get(index).copied()
+    }
+
+    /// Removes the value at `index`.
+    ///
+    /// # Returns
+    /// - `Err(String)` with the error message, thrown in JavaScript, if `index` is out of
+    ///   bounds.
+    #[wasm_bindgen(js_name = deleteAt)]
+    pub fn delete_at(&mut self, index: usize) -> Result<(), String> {
+        self.list
+            .delete_at_index(index)
+            .map_err(|error| error.to_string())
+    }
+
+    /// Removes the first occurrence of `value`, returning whether one was found.
+    #[wasm_bindgen(js_name = deleteValue)]
+    pub fn delete_value(&mut self, value: f64) -> bool {
+        self.list.delete_element(&value)
+    }
+
+    /// Returns the index of the first occurrence of `value`, or `undefined`.
+    #[wasm_bindgen(js_name = indexOf)]
+    pub fn index_of(&self, value: f64) -> Option<usize> {
+        self.list.position(&value)
+    }
+
+    /// Copies the values, front to back, into a `Float64Array`, e.g. to draw the list.
+    #[wasm_bindgen(js_name = toArray)]
+    pub fn to_array(&self) -> Vec<f64> {
+        self.list.iter().copied().collect()
+    }
+}
@@ -0,0 +1,563 @@
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+use core::ptr;
+
+use crate::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+/// `Node` represents a single element in the XOR linked list.
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The address of the previous node XOR the address of the next node.
+    /// A missing neighbour contributes address 0.
+    link: usize,
+}
+
+/// `XorLinkedList` is a doubly traversable list that stores a single link field per node.
+///
+/// Each node keeps `addr(prev) ^ addr(next)`; knowing the address of the node you came
+/// from is enough to recover the one you are going to, in either direction. This halves
+/// the link overhead of a conventional doubly linked list at the cost of only being able
+/// to traverse from the ends.
+///
+/// Node addresses are converted with `expose_provenance` / `with_exposed_provenance_mut`,
+/// which keeps the pointer/integer round-trips sound (and checkable by Miri).
+pub struct XorLinkedList<T> {
+    /// The first node, or null when the list is empty.
+    head: *mut Node<T>,
+    /// The last node, or null when the list is empty.
+    tail: *mut Node<T>,
+    /// The number of elements in the list.
+    len: usize,
+    /// Tells the compiler this type owns boxed nodes containing `T`.
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: the list uniquely owns its nodes, like a `Box` chain; the raw pointers are never
+// shared with another list, so moving it moves ownership of every `T`.
+unsafe impl<T: Send> Send for XorLinkedList<T> {}
+// SAFETY: `&XorLinkedList` only gives out `&T` and never mutates nodes.
+unsafe impl<T: Sync> Sync for XorLinkedList<T> {}
+
+/// Returns the address of `node` as an integer, exposing its provenance (null is 0).
+fn addr<T>(node: *mut Node<T>) -> usize {
+    node.expose_provenance()
+}
+
+/// Recovers a node pointer from an address produced by [`addr`].
+fn from_addr<T>(address: usize) -> *mut Node<T> {
+    ptr::with_exposed_provenance_mut(address)
+}
+
+/// Given a node and the neighbour we arrived from, returns the neighbour on the other side.
+///
+/// # Safety
+/// `node` must point to a live node whose neighbour on one side is `from`.
+unsafe fn step<T>(from: *mut Node<T>, node: *mut Node<T>) -> *mut Node<T> {
+    from_addr((*node).link ^ addr(from))
+}
+
+impl<T> XorLinkedList<T> {
+    /// Creates a new, empty `XorLinkedList`.
+    ///
+    /// # Returns
+    /// - A new empty `XorLinkedList` instance.
+    pub fn new() -> Self {
+        XorLinkedList {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: `head` is either null or a live node owned by this list.
+        unsafe { self.head.as_ref().map(|node| &node.data) }
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: `tail` is either null or a live node owned by this list.
+        unsafe { self.tail.as_ref().map(|node| &node.data) }
+    }
+
+    /// Inserts an element at the front of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_front(&mut self, data: T) {
+        // SAFETY: `head` is null or the current first node, which has no predecessor.
+        unsafe { self.link_between(data, ptr::null_mut(), self.head) };
+    }
+
+    /// Inserts an element at the back of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    pub fn push_back(&mut self, data: T) {
+        // SAFETY: `tail` is null or the current last node, which has no successor.
+        unsafe { self.link_between(data, self.tail, ptr::null_mut()) };
+    }
+
+    /// Removes and returns the first element in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+        // SAFETY: `head` is live and has no predecessor.
+        unsafe {
+            let next = step(ptr::null_mut(), self.head);
+            Some(self.unlink(ptr::null_mut(), self.head, next))
+        }
+    }
+
+    /// Removes and returns the last element in O(1).
+    ///
+    /// # Returns
+    /// - `Some(T)` if the list was not empty.
+    /// - `None` otherwise.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        // SAFETY: `tail` is live and has no successor.
+        unsafe {
+            let prev = step(ptr::null_mut(), self.tail);
+            Some(self.unlink(prev, self.tail, ptr::null_mut()))
+        }
+    }
+
+    /// Returns a double-ended iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: (ptr::null_mut(), self.head),
+            back: (ptr::null_mut(), self.tail),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a double-ended iterator that allows modifying each element, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            front: (ptr::null_mut(), self.head),
+            back: (ptr::null_mut(), self.tail),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns `(prev, node, next)` for the node at logical position `index`, walking
+    /// from whichever end is closer.
+    ///
+    /// `index` must be less than `len`.
+    fn locate(&self, index: usize) -> (*mut Node<T>, *mut Node<T>, *mut Node<T>) {
+        // SAFETY: every step stays within the `len` live nodes of this list.
+        unsafe {
+            if index < self.len / 2 {
+                let (mut prev, mut node) = (ptr::null_mut(), self.head);
+                for _ in 0..index {
+                    let next = step(prev, node);
+                    prev = node;
+                    node = next;
+                }
+                (prev, node, step(prev, node))
+            } else {
+                let (mut next, mut node) = (ptr::null_mut(), self.tail);
+                for _ in index..(self.len - 1) {
+                    let prev = step(next, node);
+                    next = node;
+                    node = prev;
+                }
+                (step(next, node), node, next)
+            }
+        }
+    }
+
+    /// Returns `(prev, node, next)` for the first node whose data equals `data`.
+    fn locate_match<Q>(&self, data: &Q) -> Option<(*mut Node<T>, *mut Node<T>, *mut Node<T>)>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let (mut prev, mut node) = (ptr::null_mut(), self.head);
+        // SAFETY: traversal stops at the null past the tail, and every node before it is live.
+        unsafe {
+            while !node.is_null() {
+                let next = step(prev, node);
+                if (*node).data.borrow() == data {
+                    return Some((prev, node, next));
+                }
+                prev = node;
+                node = next;
+            }
+        }
+        None
+    }
+
+    /// Allocates a node holding `data` and links it between the adjacent nodes `prev`
+    /// and `next` (either of which may be null at an end of the list).
+    ///
+    /// # Safety
+    /// `prev` and `next` must be adjacent in this list, with null standing in for the
+    /// missing neighbour of the head or tail.
+    unsafe fn link_between(&mut self, data: T, prev: *mut Node<T>, next: *mut Node<T>) {
+        let node = Box::into_raw(Box::new(Node {
+            data,
+            link: addr(prev) ^ addr(next),
+        }));
+
+        match prev.as_mut() {
+            Some(prev) => prev.link ^= addr(next) ^ addr(node),
+            None => self.head = node,
+        }
+        match next.as_mut() {
+            Some(next) => next.link ^= addr(prev) ^ addr(node),
+            None => self.tail = node,
+        }
+        self.len += 1;
+    }
+
+    /// Unlinks `node` (whose neighbours are `prev` and `next`), frees it, and returns its data.
+    ///
+    /// # Safety
+    /// `node` must be a live node of this list with neighbours `prev` and `next`.
+    unsafe fn unlink(&mut self, prev: *mut Node<T>, node: *mut Node<T>, next: *mut Node<T>) -> T {
+        match prev.as_mut() {
+            Some(prev) => prev.link ^= addr(node) ^ addr(next),
+            None => self.head = next,
+        }
+        match next.as_mut() {
+            Some(next) => next.link ^= addr(node) ^ addr(prev),
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+        Box::from_raw(node).data
+    }
+}
+
+impl<T> Drop for XorLinkedList<T> {
+    /// Frees every node iteratively.
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Default for XorLinkedList<T> {
+    /// Provides a default (empty) instance of the list using `new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for XorLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> ListRead<T> for XorLinkedList<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index of the first element matching `predicate`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The test applied to each element, front to back.
+    ///
+    /// # Returns
+    /// - `Some(usize)` with the index of the first match.
+    /// - `None` if no element matches.
+    fn position_by(&self, predicate: &mut dyn FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Returns a reference to the data at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        let (_, node, _) = self.locate(index);
+        // SAFETY: `node` is live for as long as `self` is borrowed.
+        unsafe { Some(&(*node).data) }
+    }
+}
+
+impl<T> ListWrite<T> for XorLinkedList<T> {
+    /// Inserts an element at the end (tail) of the list in O(1).
+    ///
+    /// # Parameters
+    /// - `data`: The value to insert.
+    fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    /// Inserts an element at a specific index in the list.
+    ///
+    /// # Parameters
+    /// - `index`: The position to insert at (0-based).
+    /// - `data`: The value to insert.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        if index == self.len {
+            self.push_back(data);
+        } else {
+            let (prev, node, _) = self.locate(index);
+            // SAFETY: `prev` and `node` are adjacent nodes of this list.
+            unsafe { self.link_between(data, prev, node) };
+        }
+        Ok(())
+    }
+
+    /// Deletes the first occurrence of the given value from the list.
+    ///
+    /// # Parameters
+    /// - `data`: A reference to the value to delete.
+    ///
+    /// # Returns
+    /// - `true` if the value was found and removed.
+    /// - `false` if the value was not found.
+    fn delete_element<Q>(&mut self, data: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        match self.locate_match(data) {
+            Some((prev, node, next)) => {
+                // SAFETY: `locate_match` returns a live node with its true neighbours.
+                unsafe { self.unlink(prev, node, next) };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to delete.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let (prev, node, next) = self.locate(index);
+        // SAFETY: `locate` returns a live node with its true neighbours.
+        unsafe { self.unlink(prev, node, next) };
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the element to retrieve.
+    ///
+    /// # Returns
+    /// - `Some(&mut T)` if the index is valid.
+    /// - `None` otherwise.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let (_, node, _) = self.locate(index);
+        // SAFETY: `node` is live and uniquely borrowed through `&mut self`.
+        unsafe { Some(&mut (*node).data) }
+    }
+
+    /// Updates the data of the node at the specified index.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the node to update.
+    /// - `data`: The new value to set.
+    ///
+    /// # Returns
+    /// - `Ok(())` on success.
+    /// - `Err(LinkedListError::IndexOutOfBounds)` if the index is invalid.
+    fn update_element_at_index(&mut self, index: usize, data: T) -> Result<(), LinkedListError> {
+        if index >= self.len {
+            return Err(LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        let (_, node, _) = self.locate(index);
+        // SAFETY: `node` is live and uniquely borrowed through `&mut self`.
+        unsafe { (*node).data = data };
+        Ok(())
+    }
+}
+
+impl<T> LinkedListTrait<T> for XorLinkedList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = IterMut<'a, T>
+    where
+        T: 'a;
+
+    /// Returns an iterator over the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_> {
+        XorLinkedList::iter(self)
+    }
+
+    /// Returns an iterator that allows modifying each element, front to back.
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        XorLinkedList::iter_mut(self)
+    }
+}
+
+/// A double-ended iterator over the elements of an `XorLinkedList`.
+pub struct Iter<'a, T> {
+    /// `(previous, next-to-yield)` when walking from the front.
+    front: (*mut Node<T>, *mut Node<T>),
+    /// `(following, next-to-yield)` when walking from the back.
+    back: (*mut Node<T>, *mut Node<T>),
+    /// The number of elements not yet yielded from either end.
+    remaining: usize,
+    /// Ties the iterator to the borrowed list.
+    _marker: PhantomData<&'a T>,
+}
+
+// SAFETY: the iterator only reads through its pointers, like the `&T`s it yields.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: as above.
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (from, node) = self.front;
+        // SAFETY: `remaining > 0`, so `node` is a live node that outlives `'a`.
+        unsafe {
+            self.front = (node, step(from, node));
+            self.remaining -= 1;
+            Some(&(*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (from, node) = self.back;
+        // SAFETY: `remaining > 0`, so `node` is a live node that outlives `'a`.
+        unsafe {
+            self.back = (node, step(from, node));
+            self.remaining -= 1;
+            Some(&(*node).data)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// A double-ended iterator over mutable references to the elements of an `XorLinkedList`.
+pub struct IterMut<'a, T> {
+    /// `(previous, next-to-yield)` when walking from the front.
+    front: (*mut Node<T>, *mut Node<T>),
+    /// `(following, next-to-yield)` when walking from the back.
+    back: (*mut Node<T>, *mut Node<T>),
+    /// The number of elements not yet yielded from either end.
+    remaining: usize,
+    /// Ties the iterator to the mutably borrowed list.
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator hands out each element once, like the `&mut T`s it yields.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: a shared iterator gives no access to the elements.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (from, node) = self.front;
+        // SAFETY: `remaining > 0`, so `node` is a live node that outlives `'a` and has not
+        // been yielded from either end.
+        unsafe {
+            self.front = (node, step(from, node));
+            self.remaining -= 1;
+            Some(&mut (*node).data)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (from, node) = self.back;
+        // SAFETY: `remaining > 0`, so `node` is a live node that outlives `'a` and has not
+        // been yielded from either end.
+        unsafe {
+            self.back = (node, step(from, node));
+            self.remaining -= 1;
+            Some(&mut (*node).data)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
@@ -0,0 +1,85 @@
+// allocator_test.rs
+// This file contains unit tests for DynamicLinkedList with a custom allocator.
+// It tests that every node comes from and goes back to the allocator, including after splitting a list.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod allocator_tests {
+    use std::alloc::Layout;
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+    use std::rc::Rc;
+
+    use linked_list_impls::allocator::{Alloc, Global};
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// An allocator that counts the blocks it has handed out and not yet had back.
+    #[derive(Clone, Default)]
+    struct Counting {
+        live: Rc<Cell<usize>>,
+    }
+
+    unsafe impl Alloc for Counting {
+        fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+            self.live.set(self.live.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.live.set(self.live.get() - 1);
+            Global.deallocate(ptr, layout);
+        }
+    }
+
+    /// Test that each node is allocated from the allocator and returned when removed or dropped.
+    #[test]
+    fn test_nodes_come_from_allocator() {
+        let alloc = Counting::default();
+        let mut list = DynamicLinkedList::new_in(alloc.clone());
+        for value in 0..5 {
+            list.insert(value);
+        }
+        assert_eq!(alloc.live.get(), 5); // One block per node.
+        list.delete_at_index(2).unwrap();
+        assert!(list.delete_element(&4));
+        assert_eq!(alloc.live.get(), 5); // Removed nodes are pooled for reuse.
+        list.shrink_pool();
+        assert_eq!(alloc.live.get(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 3]);
+        drop(list);
+        assert_eq!(alloc.live.get(), 0); // Dropping the list frees the rest.
+    }
+
+    /// Test that a list split with a cursor frees its nodes through the same allocator.
+    #[test]
+    fn test_split_after_shares_allocator() {
+        let alloc = Counting::default();
+        let mut list = DynamicLinkedList::new_in(alloc.clone());
+        for value in 0..4 {
+            list.insert(value);
+        }
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        let mut rest = cursor.split_after();
+        rest.insert(4); // The split list allocates from a clone.
+        assert_eq!(alloc.live.get(), 5);
+        drop(list);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        drop(rest);
+        assert_eq!(alloc.live.get(), 0);
+    }
+
+    /// Test that a default list uses the default allocator and the global one by default.
+    #[test]
+    fn test_default_allocator() {
+        let mut list: DynamicLinkedList<i32, Counting> = DynamicLinkedList::default();
+        list.insert(1);
+        assert_eq!(list.allocator().live.get(), 1);
+        let mut global = DynamicLinkedList::<i32>::new();
+        global.insert(1);
+        let _: &Global = global.allocator(); // `Global` is the default parameter.
+        assert_eq!(global.iter().count(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+// arena_linked_list_test.rs
+// This file contains unit tests for the ArenaLinkedList implementation.
+// Run with `cargo test --features bumpalo`.
+
+#![cfg(feature = "bumpalo")]
+
+#[cfg(test)]
+mod arena_linked_list_tests {
+    use std::rc::Rc;
+
+    use bumpalo::Bump;
+    use linked_list_impls::arena_linked_list::ArenaLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Test building a list and reading it back.
+    #[test]
+    fn test_push_and_iter() {
+        let bump = Bump::new();
+        let mut list = ArenaLinkedList::new_in(&bump);
+        list.push_back(2);
+        list.push_front(1);
+        list.insert(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+    }
+
+    /// Test the trait operations, including keeping the tail valid after deletions.
+    #[test]
+    fn test_trait_operations() {
+        let bump = Bump::new();
+        let mut list = ArenaLinkedList::new_in(&bump);
+        for value in [1, 2, 4] {
+            list.insert(value);
+        }
+        list.insert_at_index(2, 3).unwrap();
+        assert!(list.insert_at_index(5, 0).is_err());
+        list.delete_at_index(3).unwrap(); // Removes the tail.
+        list.insert(5); // Appending must link after the new tail.
+        assert!(list.delete_element(&1));
+        assert!(list.update_element(&3, 30));
+        list.update_element_at_index(0, 20).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![20, 30, 5]);
+        assert!(list.find(&5));
+        assert_eq!(list.get(3), None);
+    }
+
+    /// Test that element destructors still run on delete, update, and drop.
+    #[test]
+    fn test_elements_are_dropped() {
+        let tracker = Rc::new(());
+        let bump = Bump::new();
+        {
+            let mut list = ArenaLinkedList::new_in(&bump);
+            for _ in 0..4 {
+                list.push_back(Rc::clone(&tracker));
+            }
+            list.delete_at_index(0).unwrap();
+            list.update_element_at_index(0, Rc::new(())).unwrap();
+            assert_eq!(Rc::strong_count(&tracker), 3);
+        }
+        assert_eq!(Rc::strong_count(&tracker), 1); // Remaining clones released on drop.
+    }
+
+    /// Test building and dropping a large list.
+    #[test]
+    fn test_large_list() {
+        let bump = Bump::new();
+        let mut list = ArenaLinkedList::new_in(&bump);
+        for value in 0..1_000_000u32 {
+            list.push_back(value);
+        }
+        assert_eq!(list.len(), 1_000_000);
+        drop(list); // O(1): `u32` has no destructor.
+    }
+}
@@ -0,0 +1,92 @@
+// bench_cli_test.rs
+// This file contains tests for the `bench` subcommand of the binary.
+// It checks the CSV it prints and how it rejects bad options.
+
+#[cfg(test)]
+mod bench_cli_tests {
+    use std::process::{Command, Output};
+
+    /// Runs `linked_list_impls bench` with `args`.
+    fn bench(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_linked_list_impls"))
+            .arg("bench")
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    /// Test that each implementation and run gets a CSV row with the parameters.
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri cannot spawn the binary.
+    fn test_csv_rows() {
+        let output = bench(&[
+            "--size",
+            "50",
+            "--ops",
+            "200",
+            "--impl",
+            "dynamic,static,skip",
+            "--runs",
+            "2",
+        ]);
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(
+            lines[0],
+            "implementation,run,size,ops,insert,get,delete,total_ns,ns_per_op"
+        );
+        assert_eq!(lines.len(), 7); // A header and 3 implementations x 2 runs.
+        assert!(lines[4].starts_with("static,2,50,200,30,50,20,"));
+        assert!(lines[1..].iter().all(|line| line.split(',').count() == 9));
+    }
+
+    /// Test a custom operation mix, including operations left out of it.
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri cannot spawn the binary.
+    fn test_custom_mix() {
+        let output = bench(&[
+            "--size",
+            "0",
+            "--ops",
+            "100",
+            "--mix",
+            "delete=1,get=1",
+            "--impl",
+            "doubly",
+        ]);
+        assert!(output.status.success()); // Deletes from an empty list become inserts.
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("doubly,1,0,100,0,1,1,"));
+    }
+
+    /// Test that bad options and oversized static workloads are rejected with a message.
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri cannot spawn the binary.
+    fn test_invalid_arguments() {
+        for (args, message) in [
+            (&["--impl", "vec"][..], "unknown implementation `vec`"),
+            (&["--mix", "insert=0"][..], "at least one nonzero weight"),
+            (
+                &["--mix", "insert=4294967295,get=1"][..],
+                "add up to more than",
+            ),
+            (&["--size"][..], "missing value for --size"),
+            (
+                &["--size", "20000", "--impl", "static"][..],
+                "exceeds the static list's capacity",
+            ),
+        ] {
+            let output = bench(args);
+            assert_eq!(output.status.code(), Some(2));
+            assert!(
+                String::from_utf8(output.stderr).unwrap().contains(message),
+                "{args:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// borrow_lookup_test.rs
+// This file contains unit tests for looking up elements by a borrowed form.
+// It tests &str lookups in lists of Strings, calls through a boxed trait object, and undo after a derived update.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod borrow_lookup_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::versioned_list::VersionedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Test that a list of `String`s can be searched and edited with `&str`.
+    #[test]
+    fn test_str_lookup_in_string_list() {
+        let mut list = DynamicLinkedList::new();
+        for name in ["ada", "grace", "alan"] {
+            list.insert(name.to_string());
+        }
+        assert!(list.find("grace")); // No temporary `String` needed.
+        assert_eq!(list.position("alan"), Some(2));
+        assert!(list.update_element("ada", "barbara".to_string()));
+        assert!(list.delete_element("grace"));
+        assert!(!list.delete_element("grace"));
+        assert_eq!(list.get(0).map(String::as_str), Some("barbara"));
+        assert_eq!(list.get(1).map(String::as_str), Some("alan"));
+    }
+
+    /// Test that the generic lookups can be called on a boxed trait object.
+    #[test]
+    fn test_lookup_through_box_dyn() {
+        let mut list: Box<dyn ListWrite<String>> = Box::new(DoublyLinkedList::new());
+        list.insert("x".to_string());
+        list.insert("y".to_string());
+        assert!(list.find("y"));
+        assert!(list.update_element("x", "z".to_string()));
+        assert!(list.delete_element("y"));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.get(0).map(String::as_str), Some("z"));
+    }
+
+    /// Test that the default `update_element` and `delete_element` are recorded by an
+    /// adapter that only intercepts the index-based operations.
+    #[test]
+    fn test_derived_edits_are_recorded() {
+        let mut list: VersionedList<String> = VersionedList::new();
+        list.insert("a".to_string());
+        assert!(list.update_element("a", "b".to_string()));
+        assert!(list.delete_element("b"));
+        assert!(list.is_empty());
+        assert!(list.undo()); // Restores the deleted element.
+        assert!(list.undo()); // Reverts the update.
+        assert_eq!(list.get(0).map(String::as_str), Some("a"));
+    }
+}
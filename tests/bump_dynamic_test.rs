@@ -0,0 +1,78 @@
+// bump_dynamic_test.rs
+// This file contains unit tests for DynamicLinkedList with its nodes in a bumpalo arena.
+// Run with `cargo test --features bumpalo`.
+
+#![cfg(feature = "bumpalo")]
+
+#[cfg(test)]
+mod bump_dynamic_tests {
+    use std::rc::Rc;
+
+    use bumpalo::Bump;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that the nodes are allocated in the arena.
+    #[test]
+    fn test_nodes_live_in_arena() {
+        let bump = Bump::new();
+        let before = bump.allocated_bytes();
+        let mut list = DynamicLinkedList::new_in(&bump);
+        for value in 0..100u64 {
+            list.insert(value);
+        }
+        assert!(bump.allocated_bytes() > before); // The arena grew to hold the nodes.
+        list.delete_at_index(0).unwrap();
+        assert_eq!(list.iter().sum::<u64>(), 4950);
+    }
+
+    /// Test that elements in an arena list are still dropped, whether deleted or dropped with the list.
+    #[test]
+    fn test_elements_are_dropped() {
+        let bump = Bump::new();
+        let shared = Rc::new(());
+        let mut list = DynamicLinkedList::new_in(&bump);
+        for _ in 0..3 {
+            list.insert(Rc::clone(&shared));
+        }
+        list.delete_at_index(1).unwrap();
+        assert_eq!(Rc::strong_count(&shared), 3);
+        drop(list);
+        assert_eq!(Rc::strong_count(&shared), 1); // Dropping the list drops the rest.
+    }
+
+    /// Test that an arena list supports the usual edits, splits, and teardown.
+    #[test]
+    fn test_arena_list_operations() {
+        let bump = Bump::new();
+        let mut list = DynamicLinkedList::new_in(&bump);
+        for value in 1..=4 {
+            list.insert(value);
+        }
+        list.insert_at_index(0, 0).unwrap();
+        assert!(list.delete_element(&2));
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        let rest = cursor.split_after();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), vec![3, 4]);
+        drop(list);
+        drop(rest); // Nothing to free: the arena owns the memory.
+    }
+
+    /// Test that an arena list laid out in traversal order links each node to its neighbour.
+    #[test]
+    fn test_arena_optimize_layout() {
+        let bump = Bump::with_capacity(1024); // One chunk holds every node.
+        let mut list = DynamicLinkedList::new_in(&bump);
+        for value in 0..20u64 {
+            list.insert(value);
+        }
+        let (_, after) = list.optimize_layout();
+        assert_eq!(after.adjacent, 19); // The arena packed the nodes side by side.
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            (0..20).collect::<Vec<_>>()
+        );
+    }
+}
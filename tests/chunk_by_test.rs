@@ -0,0 +1,51 @@
+// chunk_by_test.rs
+// This file contains unit tests for the chunk_by iterator of LinkedListTrait.
+// It tests grouping runs of equal and ascending elements, and empty and single lists.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod chunk_by_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListTrait, ListWrite};
+
+    /// Test grouping a dynamic list into runs of equal elements.
+    #[test]
+    fn test_equal_runs() {
+        let mut list = DynamicLinkedList::new();
+        for value in [1, 1, 2, 3, 3, 3, 1] {
+            list.insert(value);
+        }
+        let runs: Vec<Vec<&i32>> = list.chunk_by(|a, b| a == b).collect();
+        assert_eq!(
+            runs,
+            vec![vec![&1, &1], vec![&2], vec![&3, &3, &3], vec![&1]]
+        ); // The last 1 starts a new run.
+    }
+
+    /// Test grouping a static list into ascending runs.
+    #[test]
+    fn test_ascending_runs() {
+        let mut list: StaticLinkedList<i32, 8> = StaticLinkedList::new();
+        for value in [1, 2, 5, 3, 4, 0] {
+            list.insert(value);
+        }
+        let lengths: Vec<usize> = list.chunk_by(|a, b| a <= b).map(|run| run.len()).collect();
+        assert_eq!(lengths, vec![3, 2, 1]);
+    }
+
+    /// Test that an empty list has no runs and a single element has one.
+    #[test]
+    fn test_empty_and_single() {
+        let mut list = DoublyLinkedList::new();
+        assert_eq!(list.chunk_by(|a: &i32, b| a == b).next(), None);
+        list.insert(7);
+        let mut runs = list.chunk_by(|a, b| a == b);
+        assert_eq!(runs.size_hint(), (1, Some(1)));
+        assert_eq!(runs.next(), Some(vec![&7]));
+        assert_eq!(runs.next(), None);
+        assert_eq!(runs.next(), None); // Stays exhausted.
+    }
+}
@@ -0,0 +1,50 @@
+// chunks_test.rs
+// This file contains unit tests for the chunks iterator of LinkedListTrait.
+// It tests even and uneven groupings, empty lists, and the zero size panic.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod chunks_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListTrait, ListWrite};
+
+    /// Test grouping a dynamic list whose length does not divide evenly.
+    #[test]
+    fn test_uneven_chunks() {
+        let mut list = DynamicLinkedList::new();
+        for value in 1..=7 {
+            list.insert(value);
+        }
+        let chunks: Vec<Vec<&i32>> = list.chunks(3).collect();
+        assert_eq!(chunks, vec![vec![&1, &2, &3], vec![&4, &5, &6], vec![&7]]);
+        let sums: Vec<i32> = list
+            .chunks(2)
+            .map(|chunk| chunk.into_iter().sum())
+            .collect();
+        assert_eq!(sums, vec![3, 7, 11, 7]); // Batch by batch.
+    }
+
+    /// Test grouping other lists, including an empty one.
+    #[test]
+    fn test_other_lists() {
+        let mut fixed: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        for value in [4, 3, 2, 1] {
+            fixed.insert(value);
+        }
+        assert_eq!(fixed.chunks(2).count(), 2);
+        assert_eq!(fixed.chunks(4).next(), Some(vec![&4, &3, &2, &1]));
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(empty.chunks(3).next(), None);
+    }
+
+    /// Test that a chunk size of zero is rejected.
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_zero_size_panics() {
+        let list: DynamicLinkedList<i32> = DynamicLinkedList::new();
+        let _ = list.chunks(0);
+    }
+}
@@ -0,0 +1,98 @@
+// circular_doubly_linked_list_test.rs
+// This file contains unit tests for the CircularDoublyLinkedList implementation.
+// It tests the end operations, O(1) splicing, and that nodes are dropped correctly.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod circular_doubly_linked_list_tests {
+    use std::rc::Rc;
+
+    use linked_list_impls::circular_doubly_linked_list::CircularDoublyLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    use crate::common::list_of;
+
+    /// Collects the list's contents front to back.
+    fn contents(list: &CircularDoublyLinkedList<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    /// Test pushing and popping at both ends.
+    #[test]
+    fn test_push_pop_both_ends() {
+        let mut list = CircularDoublyLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None); // List is now empty.
+        assert_eq!(list.front(), None);
+    }
+
+    /// Test appending and prepending whole lists.
+    #[test]
+    fn test_append_and_prepend() {
+        let mut list: CircularDoublyLinkedList<i32> = list_of(&[3, 4]);
+        let mut back: CircularDoublyLinkedList<i32> = list_of(&[5, 6]);
+        let mut front: CircularDoublyLinkedList<i32> = list_of(&[1, 2]);
+        list.append(&mut back);
+        list.prepend(&mut front);
+        assert_eq!(contents(&list), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 6);
+        assert!(back.is_empty()); // Spliced lists are left empty but usable.
+        back.push_back(7);
+        assert_eq!(contents(&back), vec![7]);
+    }
+
+    /// Test splicing into the middle, and rejecting out-of-bounds positions.
+    #[test]
+    fn test_splice_at() {
+        let mut list: CircularDoublyLinkedList<i32> = list_of(&[1, 4]);
+        let mut middle: CircularDoublyLinkedList<i32> = list_of(&[2, 3]);
+        assert!(list.splice_at(3, &mut middle).is_err());
+        list.splice_at(1, &mut middle).unwrap();
+        assert_eq!(contents(&list), vec![1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+
+        let mut empty = CircularDoublyLinkedList::new();
+        list.splice_at(2, &mut empty).unwrap(); // Splicing an empty list is a no-op.
+        assert_eq!(list.len(), 4);
+    }
+
+    /// Test the trait operations by index and by value.
+    #[test]
+    fn test_trait_operations() {
+        let mut list: CircularDoublyLinkedList<i32> = list_of(&[1, 2, 3]);
+        list.insert_at_index(3, 4).unwrap();
+        assert!(list.insert_at_index(5, 0).is_err());
+        list.delete_at_index(0).unwrap();
+        assert!(list.delete_element(&3));
+        assert!(list.update_element(&4, 40));
+        list.update_element_at_index(0, 20).unwrap();
+        assert_eq!(contents(&list), vec![20, 40]);
+        assert!(list.find(&40));
+        assert_eq!(list.get(2), None);
+    }
+
+    /// Test that dropping the list drops every element exactly once.
+    #[test]
+    fn test_drop_releases_elements() {
+        let tracker = Rc::new(());
+        {
+            let mut list = CircularDoublyLinkedList::new();
+            for _ in 0..5 {
+                list.push_back(Rc::clone(&tracker));
+            }
+            list.pop_front();
+            assert_eq!(Rc::strong_count(&tracker), 5);
+        }
+        assert_eq!(Rc::strong_count(&tracker), 1); // All clones were released.
+    }
+}
@@ -0,0 +1,82 @@
+// circular_linked_list_test.rs
+// This file contains unit tests for the CircularLinkedList implementation.
+// It tests the trait operations, rotation, and the cycling iterator.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod circular_linked_list_tests {
+    use linked_list_impls::circular_linked_list::CircularLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    use crate::common::list_of;
+
+    /// Test pushing at both ends and popping from the front.
+    #[test]
+    fn test_push_and_pop() {
+        let mut list = CircularLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None); // List is now empty.
+        assert!(list.is_empty());
+    }
+
+    /// Test that rotating moves the head to the tail, round-robin style.
+    #[test]
+    fn test_rotate() {
+        let mut list: CircularLinkedList<i32> = list_of(&[1, 2, 3]);
+        list.rotate();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 1]);
+        list.rotate();
+        list.rotate();
+        assert_eq!(list.front(), Some(&1)); // Full turn returns to the start.
+
+        let mut empty: CircularLinkedList<i32> = CircularLinkedList::new();
+        empty.rotate(); // Rotating an empty list is a no-op.
+        assert!(empty.is_empty());
+    }
+
+    /// Test that the cycling iterator wraps around, and is empty for an empty list.
+    #[test]
+    fn test_cycle() {
+        let list: CircularLinkedList<i32> = list_of(&[1, 2]);
+        assert_eq!(list.cycle().take(5).copied().collect::<Vec<_>>(), vec![1, 2, 1, 2, 1]);
+
+        let empty: CircularLinkedList<i32> = CircularLinkedList::new();
+        assert_eq!(empty.cycle().next(), None);
+    }
+
+    /// Test inserting and deleting by index, including at the tail.
+    #[test]
+    fn test_insert_and_delete_at_index() {
+        let mut list: CircularLinkedList<i32> = list_of(&[1, 3]);
+        list.insert_at_index(1, 2).unwrap();
+        list.insert_at_index(0, 0).unwrap();
+        assert!(list.insert_at_index(9, 9).is_err());
+        list.delete_at_index(3).unwrap(); // Removes the tail.
+        assert_eq!(list.back(), Some(&2));
+        list.push_back(4); // The new tail must still link back to the head.
+        assert_eq!(list.cycle().take(5).copied().collect::<Vec<_>>(), vec![0, 1, 2, 4, 0]);
+    }
+
+    /// Test deleting, updating, and finding by value.
+    #[test]
+    fn test_by_value_operations() {
+        let mut list: CircularLinkedList<i32> = list_of(&[1, 2, 3]);
+        assert!(list.delete_element(&3)); // Deleting the tail by value.
+        assert!(!list.delete_element(&3));
+        assert!(list.update_element(&1, 10));
+        list.update_element_at_index(1, 20).unwrap();
+        assert!(list.find(&10));
+        assert_eq!(list.get(1), Some(&20));
+        assert_eq!(list.get(2), None);
+    }
+}
@@ -0,0 +1,10 @@
+// common/mod.rs
+// This file contains helpers shared by the integration tests.
+// A test file pulls them in with `mod common;`.
+
+/// Builds a list of type `L` containing `values` in order.
+pub fn list_of<T: Copy, L: Default + Extend<T>>(values: &[T]) -> L {
+    let mut list = L::default();
+    list.extend(values.iter().copied());
+    list
+}
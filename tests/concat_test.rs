@@ -0,0 +1,57 @@
+// concat_test.rs
+// This file contains unit tests for DynamicLinkedList::concat.
+// It tests the order of the joined elements, empty inputs, and that nodes are reused.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod concat_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::{dynamic_list, ListRead, ListWrite};
+
+    /// Test that the lists are joined in order, skipping empty ones.
+    #[test]
+    fn test_join_in_order() {
+        let shards = vec![
+            dynamic_list![1, 2],
+            dynamic_list![],
+            dynamic_list![3],
+            dynamic_list![4, 5],
+        ];
+        let mut joined = DynamicLinkedList::concat(shards);
+        assert_eq!(
+            joined.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+        joined.insert(6); // The tail is the last node of the last list.
+        assert_eq!(joined.get(5), Some(&6));
+    }
+
+    /// Test that joining no lists, or only empty ones, gives an empty list.
+    #[test]
+    fn test_empty_inputs() {
+        let none = DynamicLinkedList::<i32>::concat(Vec::new());
+        assert!(none.is_empty());
+        let mut empty = DynamicLinkedList::concat([dynamic_list![], dynamic_list![]]);
+        assert!(empty.is_empty());
+        empty.insert(1);
+        assert_eq!(empty.iter().copied().collect::<Vec<_>>(), vec![1]);
+    }
+
+    /// Test that the elements stay where they were instead of being moved.
+    #[test]
+    fn test_nodes_are_reused() {
+        let first = dynamic_list![1];
+        let second = dynamic_list![2, 3];
+        let addresses = [
+            first.get(0).unwrap() as *const i32,
+            second.get(1).unwrap() as *const i32,
+        ];
+        let joined = DynamicLinkedList::concat((0..4).map(|shard| dynamic_list![shard * 10]));
+        assert_eq!(joined.len(), 4);
+        let joined = DynamicLinkedList::concat([first, second, joined]);
+        assert_eq!(joined.get(0).unwrap() as *const i32, addresses[0]);
+        assert_eq!(joined.get(2).unwrap() as *const i32, addresses[1]);
+        assert_eq!(joined.get(6), Some(&30));
+    }
+}
@@ -0,0 +1,105 @@
+// concurrent_linked_list_test.rs
+// This file contains unit tests for the ConcurrentLinkedList implementation.
+// It tests the `&self` API, the ListRead and ListWrite impls of the list and its write guard,
+// and sharing the list between threads.
+
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod concurrent_linked_list_tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use linked_list_impls::concurrent_linked_list::ConcurrentLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListError, ListRead, ListWrite};
+
+    /// Test inserting, updating and deleting through a shared reference.
+    #[test]
+    fn test_shared_reference_operations() {
+        let list = ConcurrentLinkedList::new();
+        list.insert(1);
+        list.insert(3);
+        assert!(list.insert_at_index(1, 2).is_ok());
+        assert!(list.update_element(&3, 4));
+        assert!(list.delete_element(&1));
+        assert_eq!(list.to_vec(), vec![2, 4]);
+        assert!(list.find(&4));
+        assert_eq!(
+            list.delete_at_index(5),
+            Err(LinkedListError::IndexOutOfBounds { index: 5, len: 2 })
+        );
+    }
+
+    /// Test that an element from `get` outlives its removal, and is freed with the last `Arc`.
+    #[test]
+    fn test_get_survives_removal() {
+        let list = ConcurrentLinkedList::new();
+        list.insert(String::from("kept alive"));
+        let first = list.get(0).unwrap();
+        assert!(list.delete_at_index(0).is_ok());
+        assert_eq!(*first, "kept alive");
+        assert_eq!(Arc::strong_count(&first), 1); // The list no longer holds it.
+        assert_eq!(list.get(0), None);
+    }
+
+    /// Test the trait impls of the write guard over a bounded backend.
+    #[test]
+    fn test_trait_with_static_backend() {
+        let list = ConcurrentLinkedList::<i32, StaticLinkedList<Arc<i32>, 2>>::empty();
+        let mut guard = list.write();
+        guard.insert(1);
+        assert!(guard.insert_at_index(1, 2).is_ok());
+        assert!(guard.insert_at_index(2, 3).is_err()); // Backend is full.
+        assert!(guard.update_element_at_index(0, 5).is_ok());
+        assert_eq!(ListRead::get(&guard, 0), Some(&5));
+        drop(guard);
+        let shared = list.get(0);
+        assert_eq!(shared, Some(Arc::new(5)));
+        assert!(list.write().get_mut(0).is_none()); // `shared` still points to it.
+        drop(shared);
+        *list.write().get_mut(0).unwrap() += 1;
+        assert_eq!(format!("{:?}", list), "[6, 2]");
+    }
+
+    /// Test the list's own trait impls, including a trait `get` that outlives a removal.
+    #[test]
+    fn test_trait_on_list() {
+        fn fill<L: ListWrite<i32>>(list: &mut L) {
+            list.insert(1);
+            list.insert(2);
+        }
+        let mut list = ConcurrentLinkedList::new();
+        fill(&mut list);
+        *list.get_mut(1).unwrap() = 3;
+        assert_eq!(ListRead::len(&list), 2);
+        assert_eq!(list.position(&3), Some(1));
+        let first = ListRead::get(&list, 0).unwrap();
+        assert!(list.delete_at_index(0).is_ok()); // The `&self` method, under the lock.
+        assert_eq!(*first, 1); // Still alive: the list pinned it for the trait `get`.
+        assert!(ListWrite::delete_element(&mut list, &3));
+        assert!(ListRead::is_empty(&list));
+    }
+
+    /// Test that inserts from several threads are all kept.
+    #[test]
+    fn test_concurrent_inserts() {
+        let list = Arc::new(ConcurrentLinkedList::new());
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    for i in 0..100 {
+                        list.insert(t * 100 + i);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let mut all = list.to_vec();
+        all.sort_unstable();
+        assert_eq!(all, (0..400).collect::<Vec<_>>()); // No insert was lost.
+    }
+}
@@ -0,0 +1,107 @@
+// conformance_test.rs
+// This file runs the conformance battery from `linked_list_trait_tests!` against every list in the crate.
+// Each module below holds one test per check for one list type.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod conformance_tests {
+    mod dynamic {
+        use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(DynamicLinkedList<i32>);
+    }
+
+    mod static_list {
+        use linked_list_impls::static_linked_list::StaticLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(StaticLinkedList<i32, 6>); // Exactly the required capacity.
+    }
+
+    mod doubly {
+        use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(DoublyLinkedList<i32>);
+    }
+
+    mod circular {
+        use linked_list_impls::circular_linked_list::CircularLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(CircularLinkedList<i32>);
+    }
+
+    mod circular_doubly {
+        use linked_list_impls::circular_doubly_linked_list::CircularDoublyLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(CircularDoublyLinkedList<i32>);
+    }
+
+    mod xor {
+        use linked_list_impls::xor_linked_list::XorLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(XorLinkedList<i32>);
+    }
+
+    mod skip {
+        use linked_list_impls::skip_list::IndexedSkipList;
+
+        linked_list_impls::linked_list_trait_tests!(IndexedSkipList<i32>);
+    }
+
+    mod slab {
+        use linked_list_impls::slab_linked_list::SlabLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(SlabLinkedList<i32>);
+    }
+
+    mod small {
+        use linked_list_impls::small_list::SmallList;
+
+        linked_list_impls::linked_list_trait_tests!(SmallList<i32, 2>); // Spills to the heap.
+    }
+
+    mod unrolled {
+        use linked_list_impls::unrolled_linked_list::UnrolledLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(UnrolledLinkedList<i32, 2>);
+    }
+
+    mod counted {
+        use linked_list_impls::counted::Counted;
+        use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+
+        linked_list_impls::linked_list_trait_tests!(
+            Counted<DynamicLinkedList<i32>>,
+            Counted::new(DynamicLinkedList::new())
+        );
+    }
+
+    mod bounded {
+        use linked_list_impls::bounded_list::BoundedList;
+        use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+        use linked_list_impls::list_builder::ListBuilder;
+
+        linked_list_impls::linked_list_trait_tests!(
+            BoundedList<i32, DynamicLinkedList<i32>>,
+            ListBuilder::new().max_len(6).build().unwrap()
+        );
+    }
+
+    mod observed {
+        use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+        use linked_list_impls::observed::Observed;
+
+        linked_list_impls::linked_list_trait_tests!(Observed<i32, DynamicLinkedList<i32>>);
+    }
+
+    /// Test an arena list, which borrows its arena, through the harness function.
+    #[cfg(feature = "bumpalo")]
+    #[test]
+    fn test_arena_run_all() {
+        use bumpalo::Bump;
+        use linked_list_impls::arena_linked_list::ArenaLinkedList;
+
+        let bump = Bump::new();
+        linked_list_impls::conformance::run_all(|| ArenaLinkedList::new_in(&bump));
+    }
+}
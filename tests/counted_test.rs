@@ -0,0 +1,53 @@
+// counted_test.rs
+// This file contains unit tests for the Counted adapter.
+// It tests that the length counter follows every mutating call.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod counted_tests {
+    use linked_list_impls::counted::Counted;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Test that the counter follows insertions and deletions.
+    #[test]
+    fn test_len_tracks_mutations() {
+        let mut list: Counted<DynamicLinkedList<i32>> = Counted::default();
+        assert!(list.is_empty());
+        list.insert(1);
+        list.insert(2);
+        list.insert_at_index(0, 0).unwrap();
+        assert_eq!(list.len(), 3);
+        assert!(list.delete_element(&1));
+        assert!(!list.delete_element(&1)); // Not counted twice.
+        list.delete_at_index(0).unwrap();
+        assert!(list.delete_at_index(5).is_err());
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.get(0), Some(&2));
+    }
+
+    /// Test wrapping a list that already holds elements.
+    #[test]
+    fn test_wraps_existing_list() {
+        let mut inner = DynamicLinkedList::new();
+        for value in 0..4 {
+            inner.insert(value);
+        }
+        let list = Counted::new(inner);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.into_inner().get(3), Some(&3));
+    }
+
+    /// Test that rejected insertions into a full list are not counted.
+    #[test]
+    fn test_full_list_is_not_overcounted() {
+        let mut list: Counted<StaticLinkedList<i32, 2>> = Counted::default();
+        for value in 0..3 {
+            list.insert(value);
+        }
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.inner().iter().count(), 2);
+    }
+}
@@ -0,0 +1,118 @@
+// cursor_mut_test.rs
+// This file contains unit tests for the mutable cursors of DynamicLinkedList, StaticLinkedList
+// and DoublyLinkedList.
+// It tests inserting, removing, and splitting at the cursor position.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod cursor_mut_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{dynamic_list, static_list, ListWrite};
+
+    /// Test inserting around the cursor in a dynamic list, including past the end.
+    #[test]
+    fn test_dynamic_insert_before_and_after() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(2);
+        let mut cursor = list.cursor_mut();
+        cursor.insert_before(1);
+        assert_eq!(cursor.index(), 1); // Still on 2.
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.insert_after(3);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None); // Past the end.
+        cursor.insert_after(4); // Appends.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    /// Test removing at the cursor and splitting a dynamic list.
+    #[test]
+    fn test_dynamic_remove_and_split() {
+        let mut list = dynamic_list![0, 1, 2, 3, 4, 5];
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2)); // Moved onto the next element.
+        *cursor.current().unwrap() = 20;
+        let tail = cursor.split_after();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 20]);
+        assert_eq!(tail.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    }
+
+    /// Test inserting and removing at the cursor in a static list until it is full.
+    #[test]
+    fn test_static_insert_and_remove() {
+        let mut list: StaticLinkedList<i32, 3> = StaticLinkedList::new();
+        list.insert(3);
+        let mut cursor = list.cursor_mut();
+        assert!(cursor.insert_before(1).is_ok());
+        assert!(cursor.insert_after(4).is_ok());
+        assert!(cursor.insert_after(5).is_err()); // No free slot left.
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert!(cursor.insert_before(2).is_ok()); // Reuses the freed slot.
+        assert_eq!(cursor.index(), 2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4]);
+    }
+
+    /// Test splitting a static list, at an element and past the end.
+    #[test]
+    fn test_static_split_after() {
+        let mut list = static_list![i32; 4 => 1, 2, 3, 4];
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        let mut tail = cursor.split_after();
+        assert!(cursor.insert_after(5).is_ok()); // The split freed slots.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 5]);
+        assert_eq!(tail.iter().copied().collect::<Vec<_>>(), vec![3, 4]);
+        let mut cursor = tail.cursor_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.split_after().iter().count(), 0); // Nothing after the end.
+    }
+
+    /// Test inserting around the cursor in a doubly linked list, including past the end.
+    #[test]
+    fn test_doubly_insert_before_and_after() {
+        let mut list = DoublyLinkedList::new();
+        list.insert(2);
+        let mut cursor = list.cursor_mut();
+        cursor.insert_before(1);
+        assert_eq!(cursor.index(), 1); // Still on 2.
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.insert_after(3);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None); // Past the end.
+        cursor.insert_after(4); // Appends.
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 4)); // Back onto the new last element.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        let reversed: Vec<i32> = list.iter().rev().copied().collect();
+        assert_eq!(reversed, vec![4, 3, 2, 1]); // The `prev` links were kept up to date.
+    }
+
+    /// Test removing at the cursor in a doubly linked list keeps both directions linked.
+    #[test]
+    fn test_doubly_remove_current() {
+        let mut list = DoublyLinkedList::new();
+        for value in 0..4 {
+            list.insert(value);
+        }
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.remove_current(), Some(0)); // Removes the head.
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 3)); // Moved onto the next element.
+        assert_eq!(cursor.remove_current(), Some(3)); // Removes the tail.
+        assert_eq!(cursor.remove_current(), None); // Past the end.
+        cursor.move_prev();
+        cursor.insert_before(0); // Becomes the new head.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(list.back(), Some(&1));
+        assert_eq!(list.len(), 2);
+    }
+}
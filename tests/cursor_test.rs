@@ -0,0 +1,71 @@
+// cursor_test.rs
+// This file contains unit tests for the cursors of DynamicLinkedList and StaticLinkedList.
+// It tests stepping, peeking, and seeking forward and backward.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod cursor_tests {
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{dynamic_list, static_list, ListWrite};
+
+    /// Test stepping a dynamic list cursor from the front to past the end.
+    #[test]
+    fn test_dynamic_cursor_move_next() {
+        let list = dynamic_list![10, 20, 30];
+        let mut cursor = list.cursor();
+        let mut seen = Vec::new();
+        while let Some(value) = cursor.peek() {
+            seen.push((cursor.index(), *value));
+            cursor.move_next();
+        }
+        assert_eq!(seen, vec![(0, 10), (1, 20), (2, 30)]);
+        assert_eq!(cursor.index(), 3); // Past the end equals the length.
+        cursor.move_next();
+        assert_eq!(cursor.index(), 3); // Stays at the end.
+    }
+
+    /// Test seeking a dynamic list cursor forward, backward, and past the end.
+    #[test]
+    fn test_dynamic_cursor_seek() {
+        let list = dynamic_list![0, 1, 2, 3, 4];
+        let mut cursor = list.cursor();
+        assert_eq!(cursor.seek(3), Some(&3));
+        assert_eq!(cursor.seek(1), Some(&1)); // Restarts from the front.
+        let saved = cursor.clone();
+        assert_eq!(cursor.seek(9), None);
+        assert_eq!(cursor.index(), 5); // Clamped to the end.
+        assert_eq!(saved.peek(), Some(&1)); // The clone kept its position.
+    }
+
+    /// Test stepping a static list cursor, including over a reused slot.
+    #[test]
+    fn test_static_cursor_move_next() {
+        let mut list = static_list![i32; 4 => 1, 2, 3];
+        list.delete_at_index(0).unwrap();
+        list.insert(4); // Lands in the freed first slot.
+        let mut cursor = list.cursor();
+        let mut seen = Vec::new();
+        while let Some(value) = cursor.peek() {
+            seen.push(*value);
+            cursor.move_next();
+        }
+        assert_eq!(seen, vec![2, 3, 4]); // List order, not slot order.
+        assert_eq!(cursor.index(), 3);
+    }
+
+    /// Test seeking a static list cursor and on an empty list.
+    #[test]
+    fn test_static_cursor_seek() {
+        let mut list: StaticLinkedList<char, 3> = StaticLinkedList::new();
+        assert_eq!(list.cursor().peek(), None); // Empty list.
+        for value in ['a', 'b', 'c'] {
+            list.insert(value);
+        }
+        let mut cursor = list.cursor();
+        assert_eq!(cursor.seek(2), Some(&'c'));
+        assert_eq!(cursor.seek(0), Some(&'a'));
+        assert_eq!(cursor.seek(4), None);
+        assert_eq!(cursor.index(), 3);
+    }
+}
@@ -0,0 +1,119 @@
+// default_methods_test.rs
+// This file contains unit tests for the default methods of ListRead and ListWrite.
+// It tests a minimal list that only writes the required methods, and the overrides of the built-in lists.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod default_methods_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::versioned_list::VersionedList;
+    use linked_list_impls::{LinkedListError, ListRead, ListWrite};
+
+    /// A list that implements only the required methods, on top of a `Vec`.
+    #[derive(Default)]
+    struct MinimalList(Vec<i32>);
+
+    impl MinimalList {
+        /// Builds the error for an index past the end.
+        fn out_of_bounds(&self, index: usize) -> LinkedListError {
+            LinkedListError::IndexOutOfBounds {
+                index,
+                len: self.0.len(),
+            }
+        }
+    }
+
+    impl ListRead<i32> for MinimalList {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn get(&self, index: usize) -> Option<&i32> {
+            self.0.get(index)
+        }
+    }
+
+    impl ListWrite<i32> for MinimalList {
+        fn insert(&mut self, data: i32) {
+            self.0.push(data);
+        }
+
+        fn insert_at_index(&mut self, index: usize, data: i32) -> Result<(), LinkedListError> {
+            if index > self.0.len() {
+                return Err(self.out_of_bounds(index));
+            }
+            self.0.insert(index, data);
+            Ok(())
+        }
+
+        fn delete_at_index(&mut self, index: usize) -> Result<(), LinkedListError> {
+            if index >= self.0.len() {
+                return Err(self.out_of_bounds(index));
+            }
+            self.0.remove(index);
+            Ok(())
+        }
+
+        fn get_mut(&mut self, index: usize) -> Option<&mut i32> {
+            self.0.get_mut(index)
+        }
+
+        fn update_element_at_index(
+            &mut self,
+            index: usize,
+            data: i32,
+        ) -> Result<(), LinkedListError> {
+            if index >= self.0.len() {
+                return Err(self.out_of_bounds(index));
+            }
+            self.0[index] = data;
+            Ok(())
+        }
+    }
+
+    /// Test that the derived reading methods work from `len` and `get` alone.
+    #[test]
+    fn test_derived_reads() {
+        let mut list = MinimalList::default();
+        assert!(list.is_empty());
+        list.insert(4);
+        list.insert(7);
+        assert!(!list.is_empty());
+        assert_eq!(list.position(&7), Some(1));
+        assert!(list.find(&4));
+        assert!(!list.find(&5)); // Not in the list.
+    }
+
+    /// Test that the default `update_element` goes through `position` and `update_element_at_index`.
+    #[test]
+    fn test_derived_update() {
+        let mut list = MinimalList::default();
+        list.insert(1);
+        list.insert(2);
+        list.insert(2);
+        assert!(list.update_element(&2, 9));
+        assert_eq!(list.0, vec![1, 9, 2]); // Only the first match changes.
+        assert!(!list.update_element(&5, 0));
+    }
+
+    /// Test that the built-in lists keep the same behavior through their overrides.
+    #[test]
+    fn test_builtin_overrides() {
+        let mut list = DynamicLinkedList::new();
+        for value in [3, 1, 3] {
+            list.insert(value);
+        }
+        assert_eq!(ListRead::len(&list), 3);
+        assert_eq!(list.position(&3), Some(0));
+        *list.get_mut(1).unwrap() = 5;
+        assert_eq!(list.get(1), Some(&5));
+
+        let mut versioned = VersionedList::new();
+        versioned.insert(1);
+        assert_eq!(versioned.get_mut(0), None); // A change here could not be undone.
+        assert!(versioned.update_element(&1, 2));
+        assert!(versioned.undo());
+        assert_eq!(versioned.get(0), Some(&1));
+    }
+}
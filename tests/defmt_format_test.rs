@@ -0,0 +1,48 @@
+// defmt_format_test.rs
+// This file contains compile-time checks of which types implement `defmt::Format`.
+// Run with `cargo test --features defmt`; writing a frame needs a defmt logger, so nothing is logged here.
+
+#![cfg(feature = "defmt")]
+
+#[cfg(test)]
+mod defmt_format_tests {
+    use defmt::Format;
+    use linked_list_impls::allocator::Global;
+    use linked_list_impls::counted::Counted;
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::heapless_compat::{Deque, Vec};
+    use linked_list_impls::stack::StackOverflow;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::LinkedListError;
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+    /// A type that can only be printed with `core::fmt`.
+    #[derive(Debug)]
+    struct DebugOnly;
+
+    /// Test that the heap-free lists implement `Format` for formattable elements.
+    #[test]
+    fn test_static_lists_are_format() {
+        assert_impl_all!(StaticLinkedList<u32, 4>: Format);
+        assert_impl_all!(Vec<u8, 8>: Format);
+        assert_impl_all!(Deque<i16, 2>: Format);
+        assert_impl_all!(Counted<StaticLinkedList<u32, 4>>: Format); // Forwards to the list.
+    }
+
+    /// Test that the heap lists implement `Format` with any allocator.
+    #[test]
+    fn test_heap_lists_are_format() {
+        assert_impl_all!(DynamicLinkedList<u32>: Format);
+        assert_impl_all!(DynamicLinkedList<u32, Global>: Format);
+        assert_impl_all!(DoublyLinkedList<bool>: Format);
+    }
+
+    /// Test that the errors implement `Format`, and that a list needs formattable elements.
+    #[test]
+    fn test_errors_and_element_bound() {
+        assert_impl_all!(LinkedListError: Format);
+        assert_impl_all!(StackOverflow<u8>: Format);
+        assert_not_impl_any!(StaticLinkedList<DebugOnly, 4>: Format); // `T: Format` is required.
+    }
+}
@@ -0,0 +1,53 @@
+// deque_test.rs
+// This file contains unit tests for the Deque adapter.
+// It tests the VecDeque-style API on each of the double-ended backends.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod deque_tests {
+    use linked_list_impls::circular_doubly_linked_list::CircularDoublyLinkedList;
+    use linked_list_impls::deque::{Deque, DequeBackend};
+    use linked_list_impls::xor_linked_list::XorLinkedList;
+
+    /// Runs the same sequence of operations against any backend.
+    fn exercise<B: DequeBackend<i32>>(mut deque: Deque<i32, B>) {
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+        assert_eq!(deque.get(1), Some(&2));
+        assert_eq!(deque.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_back(), Some(2));
+        assert_eq!(deque.pop_front(), None); // The deque is empty.
+    }
+
+    /// Test the default doubly linked backend.
+    #[test]
+    fn test_doubly_backend() {
+        exercise(Deque::new());
+    }
+
+    /// Test the circular doubly linked and XOR backends.
+    #[test]
+    fn test_other_backends() {
+        exercise(Deque::<i32, CircularDoublyLinkedList<i32>>::empty());
+        exercise(Deque::<i32, XorLinkedList<i32>>::empty());
+    }
+
+    /// Test collecting, extending, and clearing.
+    #[test]
+    fn test_collect_and_clear() {
+        let mut deque: Deque<i32> = (1..=3).collect();
+        deque.extend([4, 5]);
+        assert!(deque.contains(&4));
+        assert_eq!(format!("{:?}", deque), "[1, 2, 3, 4, 5]");
+        assert_eq!((&deque).into_iter().len(), 5);
+        deque.clear();
+        assert!(deque.is_empty());
+    }
+}
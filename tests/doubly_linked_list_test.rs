@@ -0,0 +1,91 @@
+// doubly_linked_list_test.rs
+// This file contains unit tests for the DoublyLinkedList implementation.
+// It tests the trait operations as well as the O(1) end operations and reverse traversal.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod doubly_linked_list_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    use crate::common::list_of;
+
+    /// Test pushing and popping at both ends.
+    #[test]
+    fn test_push_pop_both_ends() {
+        let mut list = DoublyLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None); // List is now empty.
+        assert!(list.is_empty());
+    }
+
+    /// Test iterating forwards and backwards.
+    #[test]
+    fn test_iter_and_reverse() {
+        let list: DoublyLinkedList<i32> = list_of(&[1, 2, 3]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), None); // Both ends met in the middle.
+    }
+
+    /// Test inserting at the front, middle, and end by index.
+    #[test]
+    fn test_insert_at_index() {
+        let mut list: DoublyLinkedList<i32> = list_of(&[2, 4]);
+        list.insert_at_index(0, 1).unwrap();
+        list.insert_at_index(2, 3).unwrap();
+        list.insert_at_index(4, 5).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert!(list.insert_at_index(7, 9).is_err()); // Past the end.
+    }
+
+    /// Test deleting by index and value keeps both directions consistent.
+    #[test]
+    fn test_delete_keeps_links_consistent() {
+        let mut list: DoublyLinkedList<i32> = list_of(&[1, 2, 3, 4]);
+        list.delete_at_index(3).unwrap(); // Removes the tail.
+        assert!(list.delete_element(&2));
+        assert!(!list.delete_element(&7));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 1]);
+        assert!(list.delete_at_index(2).is_err());
+    }
+
+    /// Test that freed slots are reused by later insertions.
+    #[test]
+    fn test_slot_reuse() {
+        let mut list: DoublyLinkedList<i32> = list_of(&[1, 2, 3]);
+        list.delete_at_index(1).unwrap();
+        list.insert_at_index(1, 9).unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.get(1), Some(&9));
+        assert_eq!(list.get(2), Some(&3));
+    }
+
+    /// Test updating by value and by index, including the back half of the list.
+    #[test]
+    fn test_update() {
+        let mut list: DoublyLinkedList<i32> = list_of(&[1, 2, 3, 4, 5]);
+        assert!(list.update_element(&2, 20));
+        list.update_element_at_index(4, 50).unwrap();
+        assert!(list.update_element_at_index(5, 0).is_err());
+        assert!(list.find(&20));
+        assert_eq!(list.get(4), Some(&50));
+        assert_eq!(list.get(5), None);
+    }
+}
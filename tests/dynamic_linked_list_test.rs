@@ -2,10 +2,12 @@
 // This file contains unit tests for the DynamicLinkedList implementation.
 // It tests various list operations such as insertion, deletion, updating, and getting elements.
 
+#![cfg(feature = "alloc")]
+
 #[cfg(test)]
 mod dynamic_linked_list_tests {
     use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
-    use linked_list_impls::LinkedListTrait;
+    use linked_list_impls::{ListRead, ListWrite};
 
     // Mock data type for testing. This will be used to test the linked list functionality.
     #[derive(Debug, PartialEq, Eq, Clone)]
@@ -45,8 +47,8 @@ mod dynamic_linked_list_tests {
         let mut list: DynamicLinkedList<TestData> = DynamicLinkedList::new();
         list.insert(TestData { value: 1 });
         list.insert(TestData { value: 2 });
-        assert_eq!(list.delete_element(TestData { value: 1 }), true); // Ensure deletion is successful.
-        assert_eq!(list.find(&TestData { value: 1 }), false); // Ensure element is removed.
+        assert!(list.delete_element(&TestData { value: 1 })); // Ensure deletion is successful.
+        assert!(!list.find(&TestData { value: 1 })); // Ensure element is removed.
         assert_eq!(list.get(0).unwrap().value, 2); // Ensure list still contains remaining elements.
     }
 
@@ -55,7 +57,7 @@ mod dynamic_linked_list_tests {
     fn test_delete_element_not_found() {
         let mut list: DynamicLinkedList<TestData> = DynamicLinkedList::new();
         list.insert(TestData { value: 1 });
-        assert_eq!(list.delete_element(TestData { value: 2 }), false); // Ensure deletion fails for non-existent element.
+        assert!(!list.delete_element(&TestData { value: 2 })); // Ensure deletion fails for non-existent element.
     }
 
     /// Test deleting an element at a specific index.
@@ -65,7 +67,7 @@ mod dynamic_linked_list_tests {
         list.insert(TestData { value: 1 });
         list.insert(TestData { value: 2 });
         list.delete_at_index(0).unwrap();
-        assert_eq!(list.find(&TestData { value: 1 }), false); // Ensure the first element is removed.
+        assert!(!list.find(&TestData { value: 1 })); // Ensure the first element is removed.
         assert_eq!(list.get(0).unwrap().value, 2); // Ensure the second element is now the first.
     }
 
@@ -82,7 +84,7 @@ mod dynamic_linked_list_tests {
     fn test_update_element() {
         let mut list: DynamicLinkedList<TestData> = DynamicLinkedList::new();
         list.insert(TestData { value: 1 });
-        list.update_element(TestData { value: 1 }, TestData { value: 2 });
+        list.update_element(&TestData { value: 1 }, TestData { value: 2 });
         assert_eq!(list.get(0).unwrap().value, 2); // Ensure the element is updated to 2.
     }
 
@@ -91,7 +93,8 @@ mod dynamic_linked_list_tests {
     fn test_update_element_not_found() {
         let mut list: DynamicLinkedList<TestData> = DynamicLinkedList::new();
         list.insert(TestData { value: 1 });
-        assert_eq!(list.update_element(TestData { value: 2 }, TestData { value: 3 }), false); // Ensure update fails for non-existent element.
+        // Ensure update fails for non-existent element.
+        assert!(!list.update_element(&TestData { value: 2 }, TestData { value: 3 }));
     }
 
     /// Test updating an element at a specific index.
@@ -116,8 +119,8 @@ mod dynamic_linked_list_tests {
     fn test_find() {
         let mut list: DynamicLinkedList<TestData> = DynamicLinkedList::new();
         list.insert(TestData { value: 1 });
-        assert_eq!(list.find(&TestData { value: 1 }), true); // Ensure element is found.
-        assert_eq!(list.find(&TestData { value: 2 }), false); // Ensure element is not found.
+        assert!(list.find(&TestData { value: 1 })); // Ensure element is found.
+        assert!(!list.find(&TestData { value: 2 })); // Ensure element is not found.
     }
 
     /// Test getting an element at a specific index.
@@ -128,4 +131,76 @@ mod dynamic_linked_list_tests {
         assert_eq!(list.get(0).unwrap().value, 1); // Ensure correct value is retrieved.
         assert_eq!(list.get(1), None); // Ensure out-of-bounds index returns None.
     }
+
+    /// Test that dropping a million-element list does not overflow the stack.
+    #[test]
+    fn test_drop_long_list() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..1_000_000 {
+            list.insert_at_index(0, value).unwrap(); // Pushing at the head is O(1).
+        }
+        drop(list);
+    }
+
+    /// Collects the list's values front to back.
+    fn values(list: &DynamicLinkedList<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    /// Test that deleting the last element moves the tail back, so later appends land at the end.
+    #[test]
+    fn test_append_after_deleting_tail() {
+        let mut list = DynamicLinkedList::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        assert!(list.delete_element(&3)); // Deleting the tail by value.
+        list.insert(4);
+        assert_eq!(values(&list), vec![1, 2, 4]);
+        list.delete_at_index(2).unwrap(); // Deleting the tail by index.
+        list.insert(5);
+        assert_eq!(values(&list), vec![1, 2, 5]);
+        for _ in 0..3 {
+            list.delete_at_index(0).unwrap();
+        }
+        list.insert(6); // The tail of an emptied list is reset.
+        assert_eq!(values(&list), vec![6]);
+    }
+
+    /// Test that cursor edits and splicing at the end keep the tail up to date.
+    #[test]
+    fn test_append_after_tail_edits() {
+        let mut list = DynamicLinkedList::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(3)); // Removing the tail with a cursor.
+        list.insert(4);
+        assert_eq!(values(&list), vec![1, 2, 4]);
+
+        let mut cursor = list.cursor_mut();
+        let mut rest = cursor.split_after(); // The first node becomes the tail.
+        list.insert(5);
+        rest.insert(6);
+        assert_eq!(values(&list), vec![1, 5]);
+        assert_eq!(values(&rest), vec![2, 4, 6]);
+
+        let removed = list.splice(1.., rest).unwrap();
+        list.insert(7); // The tail is the last node spliced in.
+        assert_eq!(values(&list), vec![1, 2, 4, 6, 7]);
+        assert_eq!(values(&removed), vec![5]);
+    }
+
+    /// Test that appending is O(1), so building a long list with `insert` is fast.
+    #[test]
+    fn test_insert_many() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..100_000 {
+            list.insert(value);
+        }
+        assert_eq!(list.get(99_999), Some(&99_999));
+    }
 }
@@ -0,0 +1,67 @@
+// error_test.rs
+// This file contains unit tests for LinkedListError.
+// It tests the errors reported by the lists and their messages.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod error_tests {
+    use std::error::Error;
+
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::{dynamic_list, static_list, LinkedListError, ListWrite};
+
+    /// Test that out-of-bounds indices report the index and the length.
+    #[test]
+    fn test_index_out_of_bounds() {
+        let mut list = dynamic_list![1, 2, 3];
+        assert_eq!(
+            list.insert_at_index(5, 9),
+            Err(LinkedListError::IndexOutOfBounds { index: 5, len: 3 })
+        );
+        assert_eq!(
+            list.delete_at_index(3),
+            Err(LinkedListError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            list.update_element_at_index(7, 0),
+            Err(LinkedListError::IndexOutOfBounds { index: 7, len: 3 })
+        );
+    }
+
+    /// Test that a full static list reports its capacity.
+    #[test]
+    fn test_capacity_exceeded() {
+        let mut list = static_list![i32; 2 => 1, 2];
+        assert_eq!(
+            list.insert_at_index(0, 0),
+            Err(LinkedListError::CapacityExceeded { capacity: 2 })
+        );
+        assert_eq!(
+            list.insert_at_index(3, 0),
+            Err(LinkedListError::IndexOutOfBounds { index: 3, len: 2 })
+        );
+    }
+
+    /// Test that a removed element's key is reported as stale.
+    #[test]
+    fn test_stale_key() {
+        let mut list = SlabLinkedList::new();
+        let key = list.push_back(1);
+        list.remove(key);
+        assert_eq!(list.insert_after(key, 2), Err(LinkedListError::StaleKey));
+    }
+
+    /// Test the error messages and the `Error` impl.
+    #[test]
+    fn test_display() {
+        let error = LinkedListError::IndexOutOfBounds { index: 4, len: 2 };
+        assert_eq!(
+            error.to_string(),
+            "index 4 is out of bounds for a list of length 2"
+        );
+        let error: Box<dyn Error> = Box::new(LinkedListError::CapacityExceeded { capacity: 8 });
+        assert_eq!(error.to_string(), "the list is full (capacity 8)"); // Usable as a trait object.
+        assert!(error.source().is_none());
+    }
+}
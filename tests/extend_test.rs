@@ -0,0 +1,46 @@
+// extend_test.rs
+// This file contains unit tests for the Extend implementations of the lists.
+// It tests extending by value and by reference, and a static list running out of room.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod extend_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test extending a dynamic list by value and then by reference.
+    #[test]
+    fn test_extend_dynamic() {
+        let mut list = DynamicLinkedList::new();
+        list.extend(vec![1, 2]);
+        let more = [3, 4];
+        list.extend(more.iter()); // No `.copied()` needed.
+        list.extend(&vec![5]);
+        list.insert(6); // The tail follows the extended elements.
+        assert_eq!(list, [1, 2, 3, 4, 5, 6]);
+    }
+
+    /// Test extending a doubly linked list from another list's iterator.
+    #[test]
+    fn test_extend_from_list() {
+        let mut source = DoublyLinkedList::new();
+        source.extend(1..=3);
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        list.extend(source.iter());
+        list.extend(source.iter().rev());
+        assert_eq!(list, [1, 2, 3, 3, 2, 1]);
+    }
+
+    /// Test that a static list keeps the elements that fit.
+    #[test]
+    fn test_extend_static_full() {
+        let mut list: StaticLinkedList<i32, 3> = StaticLinkedList::new();
+        list.extend(&[1, 2]);
+        list.extend([3, 4, 5]);
+        assert!(list.is_full());
+        assert_eq!(list, [1, 2, 3]); // The rest were dropped.
+    }
+}
@@ -0,0 +1,60 @@
+// ffi_test.rs
+// This file contains unit tests for the C interface to DynamicLinkedList.
+// Run with `cargo test --features ffi`; the functions are called as C would call them.
+
+#![cfg(feature = "ffi")]
+
+#[cfg(test)]
+mod ffi_tests {
+    use std::ptr;
+
+    use linked_list_impls::ffi::{
+        ll_delete_at, ll_free, ll_get, ll_insert, ll_len, ll_new, LlStatus,
+    };
+
+    /// Test inserting, reading and deleting through a handle.
+    #[test]
+    fn test_handle_round_trip() {
+        let list = ll_new();
+        unsafe {
+            for value in [10, 20, 30] {
+                assert_eq!(ll_insert(list, value), LlStatus::Ok);
+            }
+            assert_eq!(ll_delete_at(list, 1), LlStatus::Ok);
+            let mut out = 0;
+            assert_eq!(ll_get(list, 1, &mut out), LlStatus::Ok);
+            assert_eq!(out, 30); // The later value moved up.
+            assert_eq!(ll_len(list), 2);
+            ll_free(list);
+        }
+    }
+
+    /// Test that bad indices are reported and leave the output untouched.
+    #[test]
+    fn test_index_out_of_bounds() {
+        let list = ll_new();
+        unsafe {
+            ll_insert(list, 1);
+            let mut out = -1;
+            assert_eq!(ll_get(list, 1, &mut out), LlStatus::IndexOutOfBounds);
+            assert_eq!(out, -1);
+            assert_eq!(ll_delete_at(list, 5), LlStatus::IndexOutOfBounds);
+            assert_eq!(ll_len(list), 1);
+            ll_free(list);
+        }
+    }
+
+    /// Test that null pointers are rejected instead of dereferenced.
+    #[test]
+    fn test_null_pointers() {
+        let list = ll_new();
+        unsafe {
+            assert_eq!(ll_insert(ptr::null_mut(), 1), LlStatus::NullPointer);
+            assert_eq!(ll_get(list, 0, ptr::null_mut()), LlStatus::NullPointer);
+            assert_eq!(ll_delete_at(ptr::null_mut(), 0), LlStatus::NullPointer);
+            assert_eq!(ll_len(ptr::null()), 0);
+            ll_free(ptr::null_mut()); // Freeing null is a no-op, as with `free`.
+            ll_free(list);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// finger_cache_test.rs
+// This file contains unit tests for the cached lookup position of DynamicLinkedList.
+// It tests sequential reads, reads after structural changes, and reads behind the cached position.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod finger_cache_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Test that reading every index in order is fast enough for a long list.
+    #[test]
+    fn test_sequential_get() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..100_000 {
+            list.insert(value);
+        }
+        let sum: u64 = (0..100_000)
+            .map(|index| *list.get(index).unwrap() as u64)
+            .sum();
+        assert_eq!(sum, 4_999_950_000); // Each `get` walks one step from the last one.
+    }
+
+    /// Test that inserting or deleting before the cached position does not return stale elements.
+    #[test]
+    fn test_get_after_structural_changes() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..6 {
+            list.insert(value);
+        }
+        assert_eq!(list.get(4), Some(&4));
+        list.insert_at_index(1, 10).unwrap();
+        assert_eq!(list.get(4), Some(&3)); // Shifted by the insert.
+        assert_eq!(list.get(1), Some(&10));
+        list.delete_at_index(1).unwrap();
+        assert_eq!(list.get(1), Some(&1));
+        assert!(list.delete_element(&2));
+        assert_eq!(list.get(2), Some(&3));
+        list.delete_at_index(4).unwrap(); // Deleting the cached node's successor.
+        assert_eq!(list.get(4), None);
+    }
+
+    /// Test that reading an index before the cached position walks from the head.
+    #[test]
+    fn test_get_behind_finger() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..5 {
+            list.insert(value);
+        }
+        assert_eq!(list.get(3), Some(&3));
+        assert_eq!(list.get(1), Some(&1));
+        *list.get_mut(4).unwrap() = 40;
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(4), Some(&40));
+        assert_eq!(list.get(5), None); // Past the end leaves the finger in place.
+        assert_eq!(list.get(2), Some(&2));
+    }
+}
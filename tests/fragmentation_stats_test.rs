@@ -0,0 +1,58 @@
+// fragmentation_stats_test.rs
+// This file contains unit tests for the fragmentation stats of StaticLinkedList.
+// It tests the free runs and displaced elements before and after churn and compaction.
+
+#[cfg(test)]
+mod fragmentation_stats_tests {
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that a list filled in order is not fragmented.
+    #[test]
+    fn test_fresh_list() {
+        let mut list: StaticLinkedList<i32, 6> = StaticLinkedList::new();
+        for value in 0..4 {
+            list.insert(value);
+        }
+        let stats = list.fragmentation_stats();
+        assert_eq!((stats.occupied, stats.free), (4, 2));
+        assert_eq!((stats.free_runs, stats.longest_free_run), (1, 2)); // Slots 4 and 5.
+        assert_eq!(stats.displaced, 0);
+        assert_eq!(stats.locality.adjacent, 3);
+    }
+
+    /// Test that deletions in the middle split the free slots into runs.
+    #[test]
+    fn test_churned_list() {
+        let mut list: StaticLinkedList<i32, 8> = StaticLinkedList::new();
+        for value in 0..8 {
+            list.insert(value);
+        }
+        list.delete_element(&1);
+        list.delete_element(&4);
+        list.delete_element(&5);
+        list.insert_at_index(0, 9).unwrap(); // Takes slot 5, the last freed.
+        let stats = list.fragmentation_stats();
+        assert_eq!((stats.occupied, stats.free), (6, 2));
+        assert_eq!((stats.free_runs, stats.longest_free_run), (2, 1)); // Slots 1 and 4.
+        assert_eq!(stats.displaced, 4); // Only 2 and 3 still sit in the slot of their index.
+        assert_eq!(stats.locality.links, 5);
+    }
+
+    /// Test that compaction leaves one free run at the end and nothing displaced.
+    #[test]
+    fn test_compacted_list() {
+        let mut list: StaticLinkedList<i32, 8> = StaticLinkedList::new();
+        for value in 0..8 {
+            list.insert_at_index(0, value).unwrap();
+        }
+        list.delete_at_index(2).unwrap();
+        list.delete_at_index(5).unwrap();
+        assert!(list.fragmentation_stats().displaced > 0);
+        list.compact();
+        let stats = list.fragmentation_stats();
+        assert_eq!((stats.free_runs, stats.longest_free_run), (1, 2));
+        assert_eq!(stats.displaced, 0);
+        assert_eq!(stats.locality.adjacent, stats.locality.links);
+    }
+}
@@ -0,0 +1,59 @@
+// get_many_mut_test.rs
+// This file contains unit tests for get_many_mut on the dynamic and static lists.
+// It tests swapping through disjoint references and the rejected index sets.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod get_many_mut_tests {
+    use std::mem;
+
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test swapping two elements of a dynamic list in place.
+    #[test]
+    fn test_dynamic_swap() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..5 {
+            list.insert(value);
+        }
+        let [a, b] = list.get_many_mut([3, 1]).unwrap();
+        assert_eq!((*a, *b), (3, 1)); // In the order of the indices.
+        mem::swap(a, b);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 3, 2, 1, 4]
+        );
+    }
+
+    /// Test updating several elements of a static list at once.
+    #[test]
+    fn test_static_pairwise_update() {
+        let mut list: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        for value in [10, 20, 30] {
+            list.insert_at_index(0, value).unwrap(); // Slots no longer match indices.
+        }
+        let [first, last, middle] = list.get_many_mut([0, 2, 1]).unwrap();
+        *middle += *first + *last;
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![30, 60, 10]);
+        assert_eq!(list.get_many_mut([]), Some([]));
+    }
+
+    /// Test that repeated or out-of-bounds indices are rejected.
+    #[test]
+    fn test_invalid_indices() {
+        let mut dynamic = DynamicLinkedList::new();
+        let mut fixed: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        for value in 0..3 {
+            dynamic.insert(value);
+            fixed.insert(value);
+        }
+        assert!(dynamic.get_many_mut([1, 1]).is_none()); // Would alias.
+        assert!(dynamic.get_many_mut([0, 3]).is_none());
+        assert!(fixed.get_many_mut([2, 0, 2]).is_none());
+        assert!(fixed.get_many_mut([5]).is_none());
+        assert_eq!(fixed.get_many_mut([2]), Some([&mut 2]));
+    }
+}
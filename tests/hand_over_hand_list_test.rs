@@ -0,0 +1,105 @@
+// hand_over_hand_list_test.rs
+// This file contains unit tests for the HandOverHandList implementation.
+// It tests the positional and value-based operations and concurrent use from many threads.
+
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod hand_over_hand_list_tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use linked_list_impls::hand_over_hand_list::HandOverHandList;
+    use linked_list_impls::LinkedListError;
+
+    /// Test inserting at both ends and at an index.
+    #[test]
+    fn test_insert_positions() {
+        let list = HandOverHandList::new();
+        assert!(list.is_empty());
+        list.insert(2);
+        list.push_front(0);
+        assert!(list.insert_at_index(1, 1).is_ok());
+        assert!(list.insert_at_index(3, 3).is_ok()); // Index equal to length appends.
+        assert_eq!(
+            list.insert_at_index(9, 9),
+            Err(LinkedListError::IndexOutOfBounds { index: 9, len: 4 })
+        );
+        assert_eq!(list.to_vec(), vec![0, 1, 2, 3]);
+        assert_eq!(list.len(), 4);
+    }
+
+    /// Test removing, updating and looking up elements.
+    #[test]
+    fn test_remove_update_find() {
+        let list = HandOverHandList::new();
+        for value in 1..=4 {
+            list.insert(value);
+        }
+        assert_eq!(list.remove(0), Some(1));
+        assert!(list.delete_element(&3));
+        assert!(!list.delete_element(&3)); // Already removed.
+        assert!(list.update_element(&4, 40));
+        assert!(list.update_element_at_index(0, 20).is_ok());
+        assert_eq!(list.get_cloned(1), Some(40));
+        assert!(list.find(&20));
+        assert_eq!(format!("{:?}", list), "[20, 40]");
+        assert_eq!(
+            list.delete_at_index(2),
+            Err(LinkedListError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+    }
+
+    /// Test that value-based operations take a borrowed form of the element.
+    #[test]
+    fn test_borrowed_value_operations() {
+        let list = HandOverHandList::new();
+        list.insert(String::from("a"));
+        list.insert(String::from("b"));
+        assert!(list.find("b")); // No `String` built just to compare.
+        assert!(list.update_element("a", String::from("c")));
+        assert!(list.delete_element("b"));
+        assert_eq!(list.to_vec(), vec![String::from("c")]);
+    }
+
+    /// Test threads appending while others remove from the front.
+    #[test]
+    fn test_concurrent_insert_and_remove() {
+        let list = Arc::new(HandOverHandList::new());
+        let writers: Vec<_> = (0..4)
+            .map(|t| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    for i in 0..200 {
+                        list.insert(t * 200 + i);
+                    }
+                })
+            })
+            .collect();
+        let readers: Vec<_> = (0..2)
+            .map(|_| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    let mut taken = Vec::new();
+                    while taken.len() < 100 {
+                        if let Some(value) = list.remove(0) {
+                            taken.push(value);
+                        }
+                    }
+                    taken
+                })
+            })
+            .collect();
+
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        let mut all: Vec<_> = readers
+            .into_iter()
+            .flat_map(|r| r.join().unwrap())
+            .collect();
+        all.extend(list.to_vec());
+        all.sort_unstable();
+        assert_eq!(all, (0..800).collect::<Vec<_>>()); // Nothing lost or duplicated.
+    }
+}
@@ -0,0 +1,86 @@
+// handle_ops_test.rs
+// This file contains unit tests for the handle-based operations of the dynamic and static lists.
+// It tests editing around remembered elements and the errors for stale handles.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod handle_ops_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListError, ListWrite};
+
+    /// Test inserting around and removing remembered elements of a dynamic list.
+    #[test]
+    fn test_dynamic_edits() {
+        let mut list = DynamicLinkedList::new();
+        let first = list.insert_with_handle(1);
+        let last = list.insert_with_handle(4);
+        let three = list.insert_before_handle(last, 3).unwrap();
+        list.insert_after_handle(first, 2).unwrap();
+        list.insert_before_handle(first, 0).unwrap(); // Becomes the head.
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        *list.get_mut_by_handle(three).unwrap() *= 10;
+        assert_eq!(list.remove_by_handle(last), Some(4));
+        list.insert(5); // The tail moved back to 30.
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 30, 5]
+        );
+        assert_eq!(list.get_by_handle(first), Some(&1));
+    }
+
+    /// Test inserting around and removing remembered elements of a static list.
+    #[test]
+    fn test_static_edits() {
+        let mut list: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        let middle = list.insert_with_handle(2).unwrap();
+        let front = list.insert_before_handle(middle, 1).unwrap();
+        let back = list.insert_after_handle(middle, 3).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.remove_by_handle(front), Some(1));
+        assert_eq!(list.remove_by_handle(back), Some(3));
+        list.insert(4);
+        *list.get_mut_by_handle(middle).unwrap() += 20;
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![22, 4]);
+        list.insert_after_handle(middle, 5).unwrap();
+        list.insert_before_handle(middle, 6).unwrap();
+        assert_eq!(
+            list.insert_after_handle(middle, 7),
+            Err(LinkedListError::CapacityExceeded { capacity: 4 })
+        );
+    }
+
+    /// Test that every operation rejects a stale handle without changing the list.
+    #[test]
+    fn test_stale_handles() {
+        let mut dynamic = DynamicLinkedList::new();
+        let gone = dynamic.insert_with_handle(1);
+        dynamic.insert(2);
+        assert_eq!(dynamic.remove_by_handle(gone), Some(1));
+        assert_eq!(dynamic.remove_by_handle(gone), None);
+        assert_eq!(dynamic.get_by_handle(gone), None);
+        assert_eq!(
+            dynamic.insert_after_handle(gone, 3),
+            Err(LinkedListError::StaleKey)
+        );
+        assert_eq!(
+            dynamic.insert_before_handle(gone, 3),
+            Err(LinkedListError::StaleKey)
+        );
+        assert_eq!(dynamic.iter().copied().collect::<Vec<_>>(), vec![2]);
+
+        let mut fixed: StaticLinkedList<i32, 2> = StaticLinkedList::new();
+        let gone = fixed.insert_with_handle(1).unwrap();
+        fixed.delete_at_index(0).unwrap();
+        assert_eq!(fixed.get_by_handle(gone), None);
+        assert_eq!(
+            fixed.insert_before_handle(gone, 3),
+            Err(LinkedListError::StaleKey)
+        );
+        assert!(fixed.is_empty());
+    }
+}
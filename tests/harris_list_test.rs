@@ -0,0 +1,118 @@
+// harris_list_test.rs
+// This file contains unit tests for the HarrisList implementation.
+// It tests sorted set semantics on one thread and concurrent inserts and removals.
+
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod harris_list_tests {
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    use linked_list_impls::harris_list::HarrisList;
+
+    /// Test that elements are kept sorted and duplicates are rejected.
+    #[test]
+    fn test_insert_sorted_without_duplicates() {
+        let list = HarrisList::new();
+        assert!(list.is_empty());
+        assert!(list.insert(3));
+        assert!(list.insert(1));
+        assert!(list.insert(2));
+        assert!(!list.insert(2)); // Already present.
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(format!("{:?}", list), "{1, 2, 3}");
+    }
+
+    /// Test removing and looking up elements.
+    #[test]
+    fn test_remove_and_contains() {
+        let list = HarrisList::new();
+        for value in [5, 1, 3] {
+            list.insert(value);
+        }
+        assert!(list.contains(&3));
+        assert!(list.remove(&3));
+        assert!(!list.contains(&3)); // Removed.
+        assert!(!list.remove(&3)); // Already gone.
+        assert!(!list.remove(&4)); // Never present.
+        assert_eq!(list.to_vec(), vec![1, 5]);
+    }
+
+    /// Test that threads inserting disjoint keys and then racing to remove shared ones agree.
+    #[test]
+    fn test_concurrent_insert_remove() {
+        const THREADS: usize = 4;
+        const PER_THREAD: usize = 500;
+        let list = Arc::new(HarrisList::new());
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let list = Arc::clone(&list);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    for i in 0..PER_THREAD {
+                        list.insert(i * THREADS + t);
+                    }
+                    barrier.wait();
+                    // Every thread races to remove the same odd keys; each goes exactly once.
+                    (0..THREADS * PER_THREAD)
+                        .filter(|key| key % 2 == 1)
+                        .filter(|key| list.remove(key))
+                        .count()
+                })
+            })
+            .collect();
+        let removed: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+
+        let remaining = list.to_vec();
+        assert_eq!(
+            remaining,
+            (0..THREADS * PER_THREAD)
+                .filter(|k| k % 2 == 0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(removed, THREADS * PER_THREAD / 2); // Each odd key removed exactly once.
+    }
+
+    /// Test that elements are dropped with the list, including removed ones.
+    #[test]
+    fn test_drop_releases_elements() {
+        let marker = Arc::new(());
+        {
+            let list = HarrisList::new();
+            for key in 0..3 {
+                list.insert((key, Arc::clone(&marker)));
+            }
+            list.remove(&(1, Arc::clone(&marker)));
+        }
+        // With epoch reclamation the removed element is freed once the epoch advances.
+        for _ in 0..1000 {
+            if Arc::strong_count(&marker) == 1 {
+                break;
+            }
+            crossbeam_epoch::pin().flush();
+        }
+        assert_eq!(Arc::strong_count(&marker), 1); // No element was leaked.
+    }
+
+    /// Test that removed elements are freed while the list is still in use.
+    #[test]
+    fn test_removed_nodes_are_reclaimed() {
+        let marker = Arc::new(());
+        let list = HarrisList::new();
+        for key in 0..100 {
+            list.insert((key, Arc::clone(&marker)));
+            list.remove(&(key, Arc::clone(&marker)));
+        }
+        for _ in 0..1000 {
+            if Arc::strong_count(&marker) == 1 {
+                break;
+            }
+            crossbeam_epoch::pin().flush();
+        }
+        assert_eq!(Arc::strong_count(&marker), 1); // Freed before the list is dropped.
+        assert!(list.is_empty());
+    }
+}
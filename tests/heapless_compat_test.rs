@@ -0,0 +1,69 @@
+// heapless_compat_test.rs
+// This file contains unit tests for the heapless-style Vec and Deque fronts of StaticLinkedList.
+// It tests that full pushes hand the element back, positional edits, and both ends of the queue.
+
+#[cfg(test)]
+mod heapless_compat_tests {
+    use linked_list_impls::heapless_compat::{Deque, Vec};
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+
+    /// Test that pushing onto a full vector hands the element back.
+    #[test]
+    fn test_vec_push_pop() {
+        let mut vec = Vec::<u8, 3>::new();
+        assert_eq!(vec.capacity(), 3);
+        for value in 1..=3 {
+            vec.push(value).unwrap();
+        }
+        assert_eq!(vec.push(4), Err(4)); // The rejected element comes back.
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(vec.last(), Some(&2));
+        vec.push(5).unwrap();
+        assert_eq!(
+            vec.iter().copied().collect::<std::vec::Vec<_>>(),
+            vec![1, 2, 5]
+        );
+    }
+
+    /// Test inserting, removing, retaining and truncating by position.
+    #[test]
+    fn test_vec_positional_edits() {
+        let mut vec = Vec::<i32, 5>::new();
+        vec.push(1).unwrap();
+        vec.push(3).unwrap();
+        vec.insert(1, 2).unwrap();
+        vec.insert(0, 0).unwrap();
+        assert_eq!(vec.remove(3), 3);
+        assert!(vec.contains(&2));
+        vec.retain(|&x| x != 1);
+        assert_eq!(
+            vec.iter().copied().collect::<std::vec::Vec<_>>(),
+            vec![0, 2]
+        );
+        vec.truncate(1);
+        assert_eq!(vec.first(), Some(&0));
+        vec.clear();
+        assert!(vec.is_empty());
+    }
+
+    /// Test a queue filled from both ends and wrapped around an existing list.
+    #[test]
+    fn test_deque_both_ends() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.push_back(2).unwrap();
+        let mut deque = Deque::from(list); // The head becomes the front.
+        deque.push_back(3).unwrap();
+        deque.push_front(1).unwrap();
+        deque.push_front(0).unwrap();
+        assert_eq!(deque.push_front(-1), Err(-1));
+        assert_eq!(deque.front(), Some(&0));
+        assert_eq!(deque.back(), Some(&3));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(0));
+        let list = deque.into_inner();
+        assert_eq!(
+            list.iter().copied().collect::<std::vec::Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,57 @@
+// insert_many_test.rs
+// This file contains unit tests for ListWrite::insert_many_at.
+// It tests bulk insertion into the dynamic and static lists, the default method, and the error cases.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod insert_many_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListError, ListWrite};
+
+    /// Test inserting a run of elements at the front, middle, and end of a dynamic list.
+    #[test]
+    fn test_dynamic_insert_many_at() {
+        let mut list = DynamicLinkedList::new();
+        list.insert_many_at(0, [1, 5]).unwrap();
+        list.insert_many_at(1, 2..5).unwrap();
+        list.insert_many_at(5, vec![6, 7]).unwrap(); // At the end.
+        list.insert(8); // The tail follows the last inserted element.
+        let values: Vec<i32> = list.iter().copied().collect();
+        assert_eq!(values, (1..=8).collect::<Vec<_>>());
+        assert_eq!(
+            list.insert_many_at(10, [0]),
+            Err(LinkedListError::IndexOutOfBounds { index: 10, len: 8 })
+        );
+        assert_eq!(list.len(), 8); // Nothing was inserted.
+    }
+
+    /// Test that a static list keeps the elements that fit and reports the overflow.
+    #[test]
+    fn test_static_insert_many_at() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.insert(1);
+        list.insert(4);
+        list.insert_many_at(1, [2, 3]).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        list.delete_at_index(3).unwrap();
+        assert_eq!(
+            list.insert_many_at(3, [4, 5]),
+            Err(LinkedListError::CapacityExceeded { capacity: 4 })
+        );
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]); // `4` fit.
+    }
+
+    /// Test the default method through a list that does not override it.
+    #[test]
+    fn test_default_insert_many_at() {
+        let mut list = DoublyLinkedList::new();
+        list.insert(1);
+        list.insert(4);
+        list.insert_many_at(1, [2, 3]).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert!(list.insert_many_at(5, [0]).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+// integrity_test.rs
+// This file contains unit tests for the structural integrity checks.
+// It tests that validate() finds no violations after churn and how reports are printed.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod integrity_tests {
+    use linked_list_impls::integrity::{IntegrityReport, Violation};
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that a static list stays valid through insertions, deletions and compaction.
+    #[test]
+    fn test_static_list_stays_valid() {
+        let mut list: StaticLinkedList<u32, 8> = StaticLinkedList::new();
+        assert!(list.validate().is_ok());
+        for step in 0..40u32 {
+            if step % 3 == 2 {
+                let _ = list.delete_at_index((step as usize * 7) % (list.len() + 1));
+            } else {
+                let _ = list.insert_at_index((step as usize * 5) % (list.len() + 1), step);
+            }
+            let report = list.validate();
+            assert!(report.is_ok(), "after step {step}: {report}");
+            assert_eq!(report.chain_len + report.free_len, 8); // Every slot is accounted for.
+        }
+        list.compact();
+        assert!(list.validate().is_ok());
+    }
+
+    /// Test that a slab list, whose free list runs through its slots, stays valid.
+    #[test]
+    fn test_slab_list_stays_valid() {
+        let mut list = SlabLinkedList::new();
+        let keys: Vec<_> = (0..6).map(|value| list.push_back(value)).collect();
+        list.remove(keys[1]);
+        list.remove(keys[4]);
+        list.push_front(9);
+        list.delete_at_index(2).unwrap();
+        let report = list.validate();
+        assert!(report.is_ok(), "{report}");
+        assert_eq!((report.chain_len, report.free_len), (4, 2));
+    }
+
+    /// Test that a report lists each violation on its own line.
+    #[test]
+    fn test_report_display() {
+        let report = IntegrityReport {
+            chain_len: 2,
+            free_len: 1,
+            violations: vec![
+                Violation::Cycle { slot: 3 },
+                Violation::LengthMismatch { chain: 2, len: 3 },
+            ],
+        };
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.to_string(),
+            "2 linked, 1 free, 2 violations\n\
+             - the chain passes slot 3 twice\n\
+             - the chain has 2 nodes, but the length is 3"
+        );
+    }
+}
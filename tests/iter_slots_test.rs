@@ -0,0 +1,55 @@
+// iter_slots_test.rs
+// This file contains unit tests for the slot-aware iterator of StaticLinkedList.
+// It tests that slots follow the elements through insertions, deletions and compaction.
+
+#[cfg(test)]
+mod iter_slots_tests {
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that the slots are yielded in traversal order, not in slot order.
+    #[test]
+    fn test_slots_in_traversal_order() {
+        let mut list: StaticLinkedList<char, 4> = StaticLinkedList::new();
+        list.insert('a');
+        list.insert('b');
+        list.insert_at_index(0, 'c').unwrap();
+        assert_eq!(
+            list.iter_slots().collect::<Vec<_>>(),
+            vec![(2, &'c'), (0, &'a'), (1, &'b')]
+        );
+    }
+
+    /// Test that a stored slot can be checked against the current contents.
+    #[test]
+    fn test_reconcile_stored_slots() {
+        let mut list: StaticLinkedList<u32, 4> = StaticLinkedList::new();
+        for value in [10, 20, 30] {
+            list.insert(value);
+        }
+        let slot_of_20 = list
+            .iter_slots()
+            .find(|&(_, &value)| value == 20)
+            .unwrap()
+            .0;
+        list.delete_element(&20);
+        list.insert(40); // Reuses the freed slot.
+        let now = list.iter_slots().find(|&(slot, _)| slot == slot_of_20);
+        assert_eq!(now, Some((slot_of_20, &40))); // The slot holds another element now.
+    }
+
+    /// Test that compaction moves the elements into the slots of their indices.
+    #[test]
+    fn test_slots_after_compact() {
+        let mut list: StaticLinkedList<u32, 4> = StaticLinkedList::new();
+        for value in 0..4 {
+            list.insert_at_index(0, value).unwrap();
+        }
+        list.compact();
+        assert!(list
+            .iter_slots()
+            .enumerate()
+            .all(|(index, (slot, _))| index == slot));
+        assert_eq!(list.iter_slots().count(), list.len());
+    }
+}
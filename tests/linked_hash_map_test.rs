@@ -0,0 +1,67 @@
+// linked_hash_map_test.rs
+// This file contains unit tests for the LinkedHashMap and LinkedHashSet implementations.
+// It tests that insertion order is preserved across updates, moves, and removals.
+
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod linked_hash_map_tests {
+    use linked_list_impls::linked_hash_map::{LinkedHashMap, LinkedHashSet};
+
+    /// Test that iteration follows insertion order and re-insertion keeps position.
+    #[test]
+    fn test_insertion_order() {
+        let mut map = LinkedHashMap::new();
+        map.insert("b", 2);
+        map.insert("a", 1);
+        map.insert("c", 3);
+        assert_eq!(map.insert("b", 20), Some(2)); // Replaced in place.
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec!["b", "a", "c"]);
+        assert_eq!(map.get(&"b"), Some(&20));
+        assert_eq!(map.len(), 3);
+    }
+
+    /// Test removal and mutable access.
+    #[test]
+    fn test_remove_and_get_mut() {
+        let mut map = LinkedHashMap::new();
+        for (key, value) in [(1, "one"), (2, "two"), (3, "three")] {
+            map.insert(key, value);
+        }
+        assert_eq!(map.remove(&2), Some("two"));
+        assert_eq!(map.remove(&2), None);
+        *map.get_mut(&3).unwrap() = "THREE";
+        assert_eq!(format!("{:?}", map), r#"{1: "one", 3: "THREE"}"#);
+    }
+
+    /// Test move_to_back and pop_front as used by an LRU cache.
+    #[test]
+    fn test_move_to_back_and_pop_front() {
+        let mut map = LinkedHashMap::new();
+        for key in 0..3 {
+            map.insert(key, key * 10);
+        }
+        assert!(map.move_to_back(&0)); // 0 becomes the most recently used.
+        assert!(!map.move_to_back(&9));
+        assert_eq!(map.front(), Some((&1, &10)));
+        assert_eq!(map.pop_front(), Some((1, 10)));
+        assert!(!map.contains_key(&1));
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![20, 0]);
+    }
+
+    /// Test the ordered set.
+    #[test]
+    fn test_linked_hash_set() {
+        let mut set = LinkedHashSet::new();
+        assert!(set.insert('x'));
+        assert!(set.insert('y'));
+        assert!(!set.insert('x'));
+        set.insert('z');
+        set.move_to_back(&'x');
+        assert!(set.remove(&'y'));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec!['z', 'x']);
+        assert_eq!(set.pop_front(), Some('z'));
+        assert_eq!(set.front(), Some(&'x'));
+        assert!(set.contains(&'x'));
+    }
+}
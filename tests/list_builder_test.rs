@@ -0,0 +1,88 @@
+// list_builder_test.rs
+// This file contains unit tests for ListBuilder and the BoundedList it builds.
+// It tests initial elements, both overflow policies, and choosing a backing store.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod list_builder_tests {
+    use linked_list_impls::bounded_list::OverflowPolicy;
+    use linked_list_impls::list_builder::ListBuilder;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that a default builder produces an unbounded dynamic list with the elements.
+    #[test]
+    fn test_build_unbounded() {
+        let mut list = ListBuilder::new()
+            .elements([1, 2])
+            .elements([3])
+            .build()
+            .unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.max_len(), None);
+        list.insert(4);
+        assert_eq!(
+            list.inner().iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    /// Test that the reject policy refuses insertions past the maximum length.
+    #[test]
+    fn test_reject_policy() {
+        let mut list = ListBuilder::new()
+            .elements([1, 2])
+            .max_len(2)
+            .build()
+            .unwrap();
+        list.insert(3); // Silently refused.
+        assert!(list.insert_at_index(0, 0).is_err()); // The list is full.
+        list.delete_at_index(0).unwrap();
+        assert!(list.insert_at_index(0, 0).is_ok()); // Room again.
+        assert_eq!(list.inner().iter().copied().collect::<Vec<_>>(), vec![0, 2]);
+        assert!(ListBuilder::new()
+            .elements([1, 2, 3])
+            .max_len(2)
+            .build()
+            .is_err());
+    }
+
+    /// Test that the evict-oldest policy keeps the newest elements.
+    #[test]
+    fn test_evict_oldest_policy() {
+        let mut list = ListBuilder::new()
+            .elements(1..=5)
+            .max_len(3)
+            .overflow(OverflowPolicy::EvictOldest)
+            .build()
+            .unwrap();
+        assert_eq!(
+            list.inner().iter().copied().collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        list.insert(6);
+        assert_eq!(
+            list.inner().iter().copied().collect::<Vec<_>>(),
+            vec![4, 5, 6]
+        );
+        assert!(list.insert_at_index(4, 7).is_err()); // Invalid index evicts nothing.
+        assert_eq!(list.len(), 3);
+    }
+
+    /// Test building on a static backing store.
+    #[test]
+    fn test_static_backing_store() {
+        let mut list = ListBuilder::new()
+            .elements(['a', 'b', 'c', 'd'])
+            .max_len(2)
+            .overflow(OverflowPolicy::EvictOldest)
+            .backing_store::<StaticLinkedList<char, 2>>()
+            .build()
+            .unwrap();
+        list.insert('e');
+        assert_eq!(list.policy(), OverflowPolicy::EvictOldest);
+        let inner: StaticLinkedList<char, 2> = list.into_inner();
+        assert_eq!(inner.iter().copied().collect::<Vec<_>>(), vec!['d', 'e']);
+    }
+}
@@ -0,0 +1,69 @@
+// list_channel_test.rs
+// This file contains unit tests for the list_channel collector.
+// It tests ordering from one sender and completeness with many worker threads.
+
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod list_channel_tests {
+    use std::thread;
+
+    use linked_list_impls::list_channel;
+    use linked_list_impls::ListRead;
+
+    /// Test that elements from a single sender keep their order.
+    #[test]
+    fn test_single_sender_keeps_order() {
+        let (sender, collector) = list_channel::channel();
+        for value in 1..=5 {
+            assert!(sender.send(value).is_ok());
+        }
+        drop(sender);
+        let list = collector.finish();
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+    }
+
+    /// Test that every element sent by several workers ends up in the list exactly once,
+    /// with each worker's elements in the order it sent them.
+    #[test]
+    fn test_many_workers() {
+        let (sender, collector) = list_channel::channel();
+        let workers: Vec<_> = (0..4)
+            .map(|worker| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    for i in 0..250 {
+                        sender.send((worker, i)).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(sender);
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let list = collector.finish();
+        for worker in 0..4 {
+            let sent: Vec<i32> = list
+                .iter()
+                .filter(|(w, _)| *w == worker)
+                .map(|&(_, i)| i)
+                .collect();
+            assert_eq!(sent, (0..250).collect::<Vec<_>>()); // Per-worker order is kept.
+        }
+        assert_eq!(list.get(1000), None);
+        assert!(list.get(999).is_some()); // All 1000 elements arrived.
+    }
+
+    /// Test that an empty channel produces an empty list.
+    #[test]
+    fn test_no_elements() {
+        let (sender, collector) = list_channel::channel::<i32>();
+        drop(sender);
+        assert_eq!(collector.finish().get(0), None);
+    }
+}
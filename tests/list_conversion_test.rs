@@ -0,0 +1,66 @@
+// list_conversion_test.rs
+// This file contains unit tests for converting between StaticLinkedList and DynamicLinkedList.
+// It tests both directions, resizing a static list, the capacity checks, and that the converted lists keep working.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod list_conversion_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that a static list moves into a dynamic one in order and can grow past `N`.
+    #[test]
+    fn test_static_into_dynamic() {
+        let mut list = StaticLinkedList::<String, 2>::new();
+        list.insert("a".to_string());
+        list.insert("b".to_string());
+        list.delete_at_index(0).unwrap();
+        list.insert_at_index(0, "z".to_string()).unwrap(); // Out of slot order.
+        let mut dynamic = list.into_dynamic();
+        dynamic.insert("c".to_string()); // No capacity limit anymore.
+        let values: Vec<&str> = dynamic.iter().map(String::as_str).collect();
+        assert_eq!(values, vec!["z", "b", "c"]);
+    }
+
+    /// Test that a dynamic list that fits moves into a static one in order.
+    #[test]
+    fn test_dynamic_into_static() {
+        let mut dynamic = DynamicLinkedList::new();
+        for value in 1..=3 {
+            dynamic.insert(value);
+        }
+        let mut list = dynamic.try_into_static::<3>().unwrap();
+        assert!(list.is_full()); // Exactly `N` elements fit.
+        list.delete_at_index(1).unwrap();
+        list.insert(4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3, 4]);
+    }
+
+    /// Test that a dynamic list too long for the capacity is handed back unchanged.
+    #[test]
+    fn test_dynamic_into_static_too_long() {
+        let mut dynamic = DynamicLinkedList::new();
+        for value in 1..=3 {
+            dynamic.insert(value);
+        }
+        let dynamic = dynamic.try_into_static::<2>().unwrap_err();
+        assert_eq!(dynamic.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    /// Test moving a static list to a larger capacity, and back to a smaller one.
+    #[test]
+    fn test_resize_static() {
+        let mut list = StaticLinkedList::<i32, 2>::new();
+        list.insert(1);
+        list.insert(2);
+        let mut grown = list.resize::<4>().unwrap();
+        grown.insert(3); // Room to grow.
+        assert_eq!(grown.remaining_capacity(), 1);
+        let grown = grown.resize::<2>().unwrap_err(); // Three elements do not fit in two.
+        assert_eq!(grown.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        let shrunk = grown.resize::<3>().unwrap();
+        assert!(shrunk.is_full());
+    }
+}
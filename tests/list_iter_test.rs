@@ -0,0 +1,97 @@
+// list_iter_test.rs
+// This file contains unit tests for the iterator types of LinkedListTrait.
+// It tests generic iteration, generic mutation, and forwarding through adapters.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod list_iter_tests {
+    use linked_list_impls::circular_linked_list::CircularLinkedList;
+    use linked_list_impls::counted::Counted;
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::skip_list::IndexedSkipList;
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::small_list::SmallList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::unrolled_linked_list::UnrolledLinkedList;
+    use linked_list_impls::xor_linked_list::XorLinkedList;
+    use linked_list_impls::{LinkedListTrait, ListWrite};
+
+    /// Fills any list with `1..=5`, doubles every element, and collects the result.
+    fn double_all<L: LinkedListTrait<i32>>(mut list: L) -> Vec<i32> {
+        for value in 1..=5 {
+            list.insert(value);
+        }
+        for value in list.iter_mut() {
+            *value *= 2;
+        }
+        list.iter().copied().collect()
+    }
+
+    /// Test that generic code can iterate and mutate every backend in order.
+    #[test]
+    fn test_generic_iteration() {
+        let expected = vec![2, 4, 6, 8, 10];
+        assert_eq!(double_all(DynamicLinkedList::new()), expected);
+        assert_eq!(double_all(StaticLinkedList::<i32, 8>::new()), expected);
+        assert_eq!(double_all(DoublyLinkedList::new()), expected);
+        assert_eq!(double_all(XorLinkedList::new()), expected);
+        assert_eq!(double_all(UnrolledLinkedList::<i32, 2>::new()), expected); // Spans nodes.
+        assert_eq!(double_all(IndexedSkipList::new()), expected);
+        assert_eq!(double_all(SlabLinkedList::new()), expected);
+        assert_eq!(double_all(SmallList::<i32, 2>::new()), expected); // Spills to the heap.
+    }
+
+    /// Test that slot-based lists yield elements in list order, not storage order.
+    #[test]
+    fn test_iter_mut_follows_links() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.insert(1);
+        list.insert(3);
+        list.delete_at_index(0).unwrap();
+        list.insert_at_index(0, 2).unwrap(); // Reuses the first slot.
+        list.insert_at_index(0, 1).unwrap();
+        for (offset, value) in list.iter_mut().enumerate() {
+            *value += offset as i32 * 10;
+        }
+        let values: Vec<i32> = list.iter().copied().collect();
+        assert_eq!(values, vec![1, 12, 23]);
+    }
+
+    /// Test that the slot lists' mutable iterator can be drained from both ends at once.
+    #[test]
+    fn test_iter_mut_both_ends() {
+        let mut doubly = DoublyLinkedList::new();
+        let mut circular = CircularLinkedList::new();
+        let mut skip = IndexedSkipList::new();
+        for value in 1..=5 {
+            doubly.insert(value);
+            circular.insert(value);
+            skip.insert(value);
+        }
+        let drain = |iter: &mut dyn DoubleEndedIterator<Item = &mut i32>| {
+            let mut order = Vec::new();
+            while let (Some(front), back) = (iter.next(), iter.next_back()) {
+                order.push(*front);
+                order.extend(back.map(|value| *value));
+            }
+            order
+        };
+        assert_eq!(drain(&mut doubly.iter_mut()), vec![1, 5, 2, 4, 3]); // Follows `prev` links.
+        assert_eq!(drain(&mut circular.iter_mut()), vec![1, 5, 2, 4, 3]); // Walks from the front.
+        assert_eq!(drain(&mut skip.iter_mut()), vec![1, 5, 2, 4, 3]);
+        assert_eq!(skip.iter_mut().rev().len(), 5);
+    }
+
+    /// Test that adapters forward iteration to the wrapped list.
+    #[test]
+    fn test_adapter_forwards_iteration() {
+        let mut list = Counted::new(DoublyLinkedList::new());
+        list.insert(1);
+        list.insert(2);
+        list.iter_mut().for_each(|value| *value += 1);
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&3, &2]); // Double-ended.
+        assert_eq!(list.len(), 2);
+    }
+}
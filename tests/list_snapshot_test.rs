@@ -0,0 +1,70 @@
+// list_snapshot_test.rs
+// This file contains unit tests for snapshots of a VersionedList.
+// It tests rolling back to a checkpoint, rolling forward again, and stale checkpoints.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod list_snapshot_tests {
+    use linked_list_impls::versioned_list::VersionedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Collects the list's contents front to back.
+    fn contents(list: &VersionedList<i32>) -> Vec<i32> {
+        (0..list.len())
+            .map(|index| *list.get(index).unwrap())
+            .collect()
+    }
+
+    /// Test that a failed attempt can be rolled back to the checkpoint, more than once.
+    #[test]
+    fn test_restore_rolls_back() {
+        let mut list = VersionedList::new();
+        list.insert(1);
+        list.insert(2);
+        let checkpoint = list.snapshot();
+        for attempt in 0..2 {
+            list.insert_at_index(0, 10 + attempt).unwrap();
+            list.delete_element(&2);
+            list.update_element_at_index(1, 7).unwrap();
+            assert!(list.restore(&checkpoint));
+            assert_eq!(contents(&list), vec![1, 2]);
+        }
+        assert_eq!(list.history().len(), 2); // The attempts were undone.
+    }
+
+    /// Test that a snapshot taken before an undo is restored by redoing.
+    #[test]
+    fn test_restore_rolls_forward() {
+        let mut list = VersionedList::new();
+        let empty = list.snapshot();
+        list.insert(1);
+        list.insert(2);
+        let full = list.snapshot();
+        assert!(list.restore(&empty));
+        assert!(list.is_empty());
+        assert!(list.restore(&full));
+        assert_eq!(contents(&list), vec![1, 2]);
+    }
+
+    /// Test that a snapshot whose point in the history is gone is refused.
+    #[test]
+    fn test_stale_snapshots_are_refused() {
+        let mut list = VersionedList::new();
+        list.insert(1);
+        let one = list.snapshot();
+        list.undo();
+        list.insert(2); // Replaces the undone edit.
+        assert!(!list.restore(&one));
+        assert_eq!(contents(&list), vec![2]);
+
+        let two = list.snapshot();
+        list.clear_history();
+        assert!(!list.restore(&two)); // The same contents, but the history is gone.
+
+        let other: VersionedList<i32> = VersionedList::new();
+        let mut list = VersionedList::new();
+        assert!(!list.restore(&other.snapshot())); // Taken from another list.
+        assert!(list.restore(&list.snapshot()));
+    }
+}
@@ -0,0 +1,49 @@
+// list_stats_test.rs
+// This file contains unit tests for the ListStats extension trait.
+// It tests the sum, min, max and mean of non-empty and empty lists of several kinds.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod list_stats_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{ListStats, ListWrite};
+
+    /// Test the aggregates of a dynamic list of integers.
+    #[test]
+    fn test_integer_stats() {
+        let mut list = DynamicLinkedList::new();
+        list.extend([4, -2, 9, 1]);
+        assert_eq!(list.sum(), 12);
+        assert_eq!(list.min(), Some(&-2));
+        assert_eq!(list.max(), Some(&9));
+        assert_eq!(list.mean(), Some(3.0));
+    }
+
+    /// Test the aggregates of an empty list.
+    #[test]
+    fn test_empty_stats() {
+        let list: StaticLinkedList<u8, 4> = StaticLinkedList::new();
+        assert_eq!(list.sum(), 0); // The sum of nothing is zero.
+        assert_eq!(list.min(), None);
+        assert_eq!(list.max(), None);
+        assert_eq!(list.mean(), None);
+    }
+
+    /// Test the sum and mean of floats, and min and max of strings.
+    #[test]
+    fn test_other_element_types() {
+        let mut floats = DoublyLinkedList::new();
+        floats.extend([0.5, 1.5, 4.0]);
+        assert_eq!(floats.sum(), 6.0);
+        assert_eq!(floats.mean(), Some(2.0));
+        let mut words = DoublyLinkedList::new();
+        words.insert("pear");
+        words.insert("apple");
+        words.insert("plum");
+        assert_eq!(words.min(), Some(&"apple"));
+        assert_eq!(words.max(), Some(&"plum"));
+    }
+}
@@ -0,0 +1,71 @@
+// list_traits_test.rs
+// This file contains unit tests for the ListRead and ListWrite trait objects.
+// It tests swapping backends behind a Box<dyn ListWrite>, reading through &dyn ListRead, and the error path.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod list_traits_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListError, ListRead, ListWrite};
+
+    /// Builds a backend chosen at runtime.
+    fn backend(bounded: bool) -> Box<dyn ListWrite<i32>> {
+        if bounded {
+            Box::new(StaticLinkedList::<i32, 4>::new())
+        } else {
+            Box::new(DynamicLinkedList::new())
+        }
+    }
+
+    /// Sums the elements of any list through the read-only interface.
+    fn sum(list: &dyn ListRead<i32>) -> i32 {
+        (0..).map_while(|index| list.get(index)).sum()
+    }
+
+    /// Test that a boxed list can be swapped for another implementation at runtime.
+    #[test]
+    fn test_swap_backend_at_runtime() {
+        for bounded in [false, true] {
+            let mut list = backend(bounded);
+            list.insert(1);
+            list.insert(3);
+            assert!(list.insert_at_index(1, 2).is_ok());
+            assert_eq!(list.get(1), Some(&2)); // Same behavior for both backends.
+            assert!(list.delete_element(&1));
+            assert!(list.find(&3));
+        }
+    }
+
+    /// Test that a list can be read through a `&dyn ListRead`.
+    #[test]
+    fn test_read_through_dyn() {
+        let mut dynamic = DynamicLinkedList::new();
+        let mut doubly = DoublyLinkedList::new();
+        for value in 1..=3 {
+            dynamic.insert(value);
+            doubly.insert(value);
+        }
+        assert_eq!(sum(&dynamic), 6);
+        assert_eq!(sum(&doubly), 6);
+    }
+
+    /// Test that a full bounded backend reports its error through the trait object.
+    #[test]
+    fn test_trait_object_reports_errors() {
+        let mut list = backend(true);
+        for value in 0..4 {
+            assert_eq!(list.try_insert(value), Ok(()));
+        }
+        assert_eq!(
+            list.try_insert(4),
+            Err(LinkedListError::CapacityExceeded { capacity: 4 })
+        ); // The static backend is full.
+        assert_eq!(
+            list.delete_at_index(7),
+            Err(LinkedListError::IndexOutOfBounds { index: 7, len: 4 })
+        );
+    }
+}
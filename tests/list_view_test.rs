@@ -0,0 +1,70 @@
+// list_view_test.rs
+// This file contains unit tests for ListView, the borrowed view over part of a list.
+// It tests iteration, indexing and searching inside the range, and invalid ranges.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod list_view_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListError, LinkedListTrait, ListRead, ListWrite};
+
+    /// Sums the elements of any readable list, to run on a view.
+    fn sum(list: &impl ListRead<i32>) -> i32 {
+        (0..list.len()).filter_map(|index| list.get(index)).sum()
+    }
+
+    /// Test that a view iterates and indexes only its range.
+    #[test]
+    fn test_iterate_and_get() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..6 {
+            list.insert(value);
+        }
+        let view = list.view(2..5).unwrap();
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!((view.start(), view.len()), (2, 3));
+        assert_eq!(view.get(0), Some(&2)); // Indices start at the view.
+        assert_eq!(view.get(3), None); // 5 is outside the view.
+        assert_eq!(sum(&view), 9);
+        assert_eq!(format!("{view:?}"), "[2, 3, 4]");
+    }
+
+    /// Test that searches only see the elements inside the view.
+    #[test]
+    fn test_search() {
+        let mut list: StaticLinkedList<i32, 8> = StaticLinkedList::new();
+        for value in [7, 1, 7, 2, 3] {
+            list.insert(value);
+        }
+        let view = list.view(1..=3).unwrap();
+        assert_eq!(view.position(&7), Some(1)); // Skips the 7 before the view.
+        assert!(!view.find(&3)); // After the view.
+        assert!(view.find(&2));
+        let tail = list.view(3..).unwrap();
+        assert_eq!(tail.into_iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    /// Test that ranges outside the list are rejected.
+    #[test]
+    fn test_invalid_ranges() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..3 {
+            list.insert(value);
+        }
+        assert_eq!(
+            list.view(1..4).unwrap_err(),
+            LinkedListError::IndexOutOfBounds { index: 4, len: 3 }
+        );
+        assert!(list.view(4..).is_err()); // Starts past the end.
+        let overflow = LinkedListError::IndexOutOfBounds {
+            index: usize::MAX,
+            len: 3,
+        };
+        assert_eq!(list.view(..=usize::MAX).err(), Some(overflow)); // No overflow.
+        let empty = list.view(3..).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().next(), None);
+    }
+}
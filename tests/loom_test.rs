@@ -0,0 +1,105 @@
+// loom_test.rs
+// This file contains loom model-checking tests for lists shared between threads.
+// loom explores every legal interleaving of the spawned threads, so lost updates and
+// data races show up deterministically instead of only under lucky scheduling.
+//
+// Run with:
+//     RUSTFLAGS="--cfg loom" cargo test --test loom_test --release
+
+#![cfg(loom)]
+
+mod loom_tests {
+    use linked_list_impls::hand_over_hand_list::HandOverHandList;
+    use linked_list_impls::harris_list::HarrisList;
+    use linked_list_impls::ms_queue::MsQueue;
+    use loom::sync::Arc;
+    use loom::thread;
+
+    /// Test that concurrent enqueues on the lock-free queue are all dequeued exactly once.
+    ///
+    /// The retry loops make the full state space too large to exhaust, so preemptions
+    /// are bounded; bugs in lock-free code almost always need only a few.
+    #[test]
+    fn test_ms_queue_concurrent_enqueue_dequeue() {
+        let mut builder = loom::model::Builder::new();
+        builder.preemption_bound = Some(3);
+        builder.check(|| {
+            let queue = Arc::new(MsQueue::new());
+
+            let producers: Vec<_> = (1..=2)
+                .map(|value| {
+                    let queue = Arc::clone(&queue);
+                    thread::spawn(move || queue.enqueue(value))
+                })
+                .collect();
+            let consumer = {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || queue.try_dequeue())
+            };
+
+            for producer in producers {
+                producer.join().unwrap();
+            }
+            let mut seen: Vec<i32> = consumer.join().unwrap().into_iter().collect();
+            while let Some(value) = queue.try_dequeue() {
+                seen.push(value);
+            }
+            seen.sort_unstable();
+            assert_eq!(seen, vec![1, 2]); // Nothing lost or duplicated.
+        });
+    }
+
+    /// Test that an insert racing with the removal of a neighbouring key loses neither.
+    ///
+    /// Removing 2 unlinks it from behind 1 while the insert of 3 may be linking after it,
+    /// the case the deletion mark exists to catch. Preemptions are bounded as above.
+    #[test]
+    fn test_harris_list_insert_remove_neighbours() {
+        let mut builder = loom::model::Builder::new();
+        builder.preemption_bound = Some(3);
+        builder.check(|| {
+            let list = Arc::new(HarrisList::new());
+            list.insert(1);
+            list.insert(2);
+
+            let inserter = {
+                let list = Arc::clone(&list);
+                thread::spawn(move || list.insert(3))
+            };
+            let remover = {
+                let list = Arc::clone(&list);
+                thread::spawn(move || list.remove(&2))
+            };
+
+            assert!(inserter.join().unwrap());
+            assert!(remover.join().unwrap());
+            assert_eq!(list.to_vec(), vec![1, 3]);
+        });
+    }
+
+    /// Test that removing a node while another thread inserts right after it keeps the insert.
+    ///
+    /// The inserter must lock the removed node's link before the remover can take it, or
+    /// the new node would be dropped along with it.
+    #[test]
+    fn test_hand_over_hand_remove_and_insert_after() {
+        loom::model(|| {
+            let list = Arc::new(HandOverHandList::new());
+            list.insert(1);
+            list.insert(2);
+
+            let remover = {
+                let list = Arc::clone(&list);
+                thread::spawn(move || list.remove(0))
+            };
+            let inserter = {
+                let list = Arc::clone(&list);
+                thread::spawn(move || list.insert(3))
+            };
+
+            assert_eq!(remover.join().unwrap(), Some(1));
+            inserter.join().unwrap();
+            assert_eq!(list.to_vec(), vec![2, 3]);
+        });
+    }
+}
@@ -0,0 +1,40 @@
+// macros_test.rs
+// This file contains unit tests for the dynamic_list! and static_list! macros.
+// It tests building lists from literal elements, including empty lists.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod macros_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::{dynamic_list, static_list, ListRead, ListWrite};
+
+    /// Test that dynamic_list! keeps the elements in order.
+    #[test]
+    fn test_dynamic_list_macro() {
+        let list = dynamic_list!["a".to_string(), "b".to_string(),];
+        assert_eq!(list.get(0).map(String::as_str), Some("a"));
+        assert_eq!(list.get(1).map(String::as_str), Some("b"));
+        assert_eq!(list.get(2), None);
+        let empty: DynamicLinkedList<i32> = dynamic_list![];
+        assert_eq!(empty.get(0), None); // No elements.
+    }
+
+    /// Test that static_list! keeps the elements in order and leaves the rest free.
+    #[test]
+    fn test_static_list_macro() {
+        let mut list = static_list![i32; 3 => 1, 2];
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        list.insert(3); // One slot was left free.
+        assert_eq!(list.get(2), Some(&3));
+    }
+
+    /// Test building an empty static list and filling it to capacity.
+    #[test]
+    fn test_static_list_macro_empty_and_full() {
+        let empty = static_list![u8; 2];
+        assert_eq!(empty.iter().count(), 0);
+        let full = static_list![u8; 2 => 7, 8];
+        assert_eq!(full.cursor().seek(1), Some(&8));
+    }
+}
@@ -0,0 +1,71 @@
+// memory_footprint_test.rs
+// This file contains unit tests for the memory_footprint methods of the lists.
+// It tests node counts, per-node overhead, and total bytes for the pointer-based and slot-based lists.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod memory_footprint_tests {
+    use std::mem;
+
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that a dynamic list grows by one node per element and shrinks on delete.
+    #[test]
+    fn test_dynamic_footprint() {
+        let mut list = DynamicLinkedList::new();
+        let empty = list.memory_footprint();
+        assert_eq!(empty.nodes, 0);
+        assert_eq!(empty.total_bytes, mem::size_of::<DynamicLinkedList<u64>>());
+        for value in 0..4u64 {
+            list.insert(value);
+        }
+        let full = list.memory_footprint();
+        assert_eq!(full.nodes, 4);
+        assert_eq!(full.node_overhead, 16); // A `next` pointer and a handle key per node.
+        assert_eq!(full.total_bytes, empty.total_bytes + 4 * 24);
+        list.delete_at_index(0).unwrap();
+        assert_eq!(list.memory_footprint().total_bytes, full.total_bytes); // The node is pooled.
+        list.shrink_pool();
+        let shrunk = list.memory_footprint();
+        assert_eq!(shrunk.total_bytes, empty.total_bytes + 3 * 24);
+    }
+
+    /// Test that a static list always accounts for its whole array and free vector.
+    #[test]
+    fn test_static_footprint() {
+        let mut list = StaticLinkedList::<u64, 8>::new();
+        let empty = list.memory_footprint();
+        assert!(empty.total_bytes >= 8 * mem::size_of::<u64>() + 8 * mem::size_of::<usize>());
+        list.insert(1);
+        list.insert(2);
+        let used = list.memory_footprint();
+        assert_eq!(used.nodes, 2);
+        assert_eq!(used.total_bytes, empty.total_bytes); // The storage is fixed up front.
+        assert!(used.node_overhead > 0);
+    }
+
+    /// Test that the slot-based lists count their spare slots and report their overhead.
+    #[test]
+    fn test_slot_list_footprint() {
+        let mut doubly = DoublyLinkedList::new();
+        let mut slab = SlabLinkedList::new();
+        for value in 0..10u64 {
+            doubly.insert(value);
+            slab.insert(value);
+        }
+        for _ in 0..5 {
+            doubly.delete_at_index(0).unwrap();
+            slab.delete_at_index(0).unwrap();
+        }
+        assert_eq!(doubly.memory_footprint().nodes, 5);
+        assert_eq!(slab.memory_footprint().nodes, 5);
+        assert!(doubly.memory_footprint().total_bytes >= 10 * mem::size_of::<u64>()); // Freed slots are kept.
+        let slab_overhead = slab.memory_footprint().node_overhead;
+        assert!(slab_overhead > doubly.memory_footprint().node_overhead); // Slots carry a generation.
+    }
+}
@@ -0,0 +1,88 @@
+// ms_queue_test.rs
+// This file contains unit tests for the MsQueue implementation.
+// It tests FIFO order on one thread and exactly-once delivery across many threads.
+
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod ms_queue_tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use linked_list_impls::ms_queue::MsQueue;
+
+    /// Test FIFO order on a single thread.
+    #[test]
+    fn test_fifo_order() {
+        let queue = MsQueue::new();
+        assert!(queue.is_empty());
+        for value in 1..=3 {
+            queue.enqueue(value);
+        }
+        assert!(!queue.is_empty());
+        assert_eq!(queue.try_dequeue(), Some(1));
+        assert_eq!(queue.try_dequeue(), Some(2));
+        queue.enqueue(4);
+        assert_eq!(queue.try_dequeue(), Some(3));
+        assert_eq!(queue.try_dequeue(), Some(4));
+        assert_eq!(queue.try_dequeue(), None); // The queue is empty.
+    }
+
+    /// Test that every element produced by several threads is consumed exactly once.
+    #[test]
+    fn test_multi_producer_multi_consumer() {
+        const PRODUCERS: usize = 4;
+        const PER_PRODUCER: usize = 2000;
+        let queue = Arc::new(MsQueue::new());
+
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|p| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        queue.enqueue(p * PER_PRODUCER + i);
+                    }
+                })
+            })
+            .collect();
+        let consumers: Vec<_> = (0..PRODUCERS)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || {
+                    let mut taken = Vec::new();
+                    while taken.len() < PER_PRODUCER {
+                        if let Some(value) = queue.try_dequeue() {
+                            taken.push(value);
+                        }
+                    }
+                    taken
+                })
+            })
+            .collect();
+
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        let mut all: Vec<usize> = consumers
+            .into_iter()
+            .flat_map(|consumer| consumer.join().unwrap())
+            .collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..PRODUCERS * PER_PRODUCER).collect::<Vec<_>>());
+        assert!(queue.is_empty());
+    }
+
+    /// Test that elements still queued are dropped with the queue.
+    #[test]
+    fn test_drop_releases_elements() {
+        let marker = Arc::new(());
+        {
+            let queue = MsQueue::new();
+            for _ in 0..3 {
+                queue.enqueue(Arc::clone(&marker));
+            }
+            drop(queue.try_dequeue());
+        }
+        assert_eq!(Arc::strong_count(&marker), 1); // No element was leaked.
+    }
+}
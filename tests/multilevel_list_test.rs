@@ -0,0 +1,60 @@
+// multilevel_list_test.rs
+// This file contains unit tests for the MultilevelList implementation.
+// It tests building nested levels, depth-first iteration, and flattening.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod multilevel_list_tests {
+    use linked_list_impls::multilevel_list::MultilevelList;
+
+    /// Builds a list from `values`.
+    fn level(values: &[i32]) -> MultilevelList<i32> {
+        let mut list = MultilevelList::new();
+        for &value in values {
+            list.push_back(value);
+        }
+        list
+    }
+
+    /// Builds 1 - 2 - 3 with [4 - 5] under 2 and [6] under 4.
+    fn nested() -> MultilevelList<i32> {
+        let mut list = level(&[1, 2, 3]);
+        list.set_child(1, level(&[4, 5])).unwrap();
+        list.child_mut(1).unwrap().set_child(0, level(&[6])).unwrap();
+        list
+    }
+
+    /// Test depth-first iteration over the nested structure.
+    #[test]
+    fn test_depth_first_iteration() {
+        let list = nested();
+        let visited: Vec<(usize, i32)> = list.iter_depth_first().map(|(d, &v)| (d, v)).collect();
+        assert_eq!(visited, vec![(0, 1), (0, 2), (1, 4), (2, 6), (1, 5), (0, 3)]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.total_len(), 6);
+        assert_eq!(format!("{:?}", list), "[1, 2, [4, [6], 5], 3]");
+    }
+
+    /// Test that flatten splices children in depth-first order.
+    #[test]
+    fn test_flatten() {
+        let mut list = nested();
+        list.flatten();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4, 6, 5, 3]);
+        assert!(list.child(1).is_none()); // No children remain.
+        assert_eq!(list.total_len(), list.len());
+    }
+
+    /// Test child replacement and bounds checks.
+    #[test]
+    fn test_set_child() {
+        let mut list = level(&[1]);
+        assert!(list.set_child(1, level(&[9])).is_err());
+        assert!(list.set_child(0, level(&[2])).unwrap().is_none());
+        let previous = list.set_child(0, MultilevelList::new()).unwrap();
+        assert_eq!(previous.unwrap().iter().next(), Some(&2));
+        list.flatten(); // An empty child contributes nothing.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1]);
+    }
+}
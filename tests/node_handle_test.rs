@@ -0,0 +1,64 @@
+// node_handle_test.rs
+// This file contains unit tests for the node handles of the dynamic and static lists.
+// It tests that handles survive unrelated changes and go stale with their element.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod node_handle_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that a dynamic list's handles outlive unrelated insertions and deletions.
+    #[test]
+    fn test_dynamic_handles() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(1);
+        let two = list.insert_with_handle(2);
+        let three = list.insert_with_handle(3);
+        list.insert_at_index(0, 0).unwrap();
+        list.delete_at_index(3).unwrap(); // Removes 3.
+        assert!(list.contains_handle(two));
+        assert!(!list.contains_handle(three));
+        list.insert(4); // Reuses the node 3 was in.
+        assert!(!list.contains_handle(three)); // Still stale.
+        assert!(!DynamicLinkedList::new().contains_handle(two)); // Another list's handle.
+    }
+
+    /// Test that a static list's handles go stale when their slot is freed or reused.
+    #[test]
+    fn test_static_handles() {
+        let mut list: StaticLinkedList<i32, 3> = StaticLinkedList::new();
+        let first = list.insert_with_handle(10).unwrap();
+        let second = list.insert_with_handle(20).unwrap();
+        list.delete_element(&10);
+        list.insert(30); // Takes the slot 10 was in.
+        assert!(!list.contains_handle(first));
+        assert!(list.contains_handle(second));
+        list.insert(40);
+        assert_eq!(list.insert_with_handle(50), Err(50)); // The list is full.
+    }
+
+    /// Test that moving elements between nodes or slots makes their handles stale.
+    #[test]
+    fn test_moves_invalidate() {
+        let mut fixed: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        fixed.insert_at_index(0, 1).unwrap();
+        let moved = fixed.insert_with_handle(2).unwrap();
+        let front = fixed.insert_with_handle(3).unwrap();
+        fixed.delete_at_index(0).unwrap();
+        fixed.compact(); // Moves 2 and 3 down one slot.
+        assert!(!fixed.contains_handle(moved));
+        assert!(!fixed.contains_handle(front));
+
+        let mut dynamic = DynamicLinkedList::new();
+        let kept = dynamic.insert_with_handle(1);
+        let swapped = dynamic.insert_with_handle(2);
+        assert_eq!(dynamic.swap_remove_at(1), Ok(2)); // 1 moves into 2's node.
+        assert!(!dynamic.contains_handle(kept) && !dynamic.contains_handle(swapped));
+        let last = dynamic.insert_with_handle(3);
+        dynamic.optimize_layout(); // Moves elements between nodes, so every handle goes stale.
+        assert!(!dynamic.contains_handle(last));
+    }
+}
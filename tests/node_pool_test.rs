@@ -0,0 +1,82 @@
+// node_pool_test.rs
+// This file contains unit tests for the node pool of DynamicLinkedList.
+// It tests that removed nodes are reused by later insertions, that shrink_pool releases them, and that pooled elements are dropped.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod node_pool_tests {
+    use std::alloc::Layout;
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+    use std::rc::Rc;
+
+    use linked_list_impls::allocator::{Alloc, Global};
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// An allocator that counts how many blocks it has handed out in total.
+    #[derive(Clone, Default)]
+    struct Counting {
+        allocations: Rc<Cell<usize>>,
+    }
+
+    unsafe impl Alloc for Counting {
+        fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+            self.allocations.set(self.allocations.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            Global.deallocate(ptr, layout);
+        }
+    }
+
+    /// Test that delete/insert churn reuses the removed nodes instead of allocating.
+    #[test]
+    fn test_churn_reuses_nodes() {
+        let alloc = Counting::default();
+        let mut list = DynamicLinkedList::new_in(alloc.clone());
+        for value in 0..3 {
+            list.insert(value);
+        }
+        for value in 3..1000 {
+            list.delete_at_index(0).unwrap();
+            list.insert(value);
+        }
+        assert_eq!(alloc.allocations.get(), 3); // Every later insert took a pooled node.
+        let values: Vec<i32> = list.iter().copied().collect();
+        assert_eq!(values, vec![997, 998, 999]);
+    }
+
+    /// Test that shrinking the pool makes the next insertions allocate again.
+    #[test]
+    fn test_shrink_pool() {
+        let alloc = Counting::default();
+        let mut list = DynamicLinkedList::new_in(alloc.clone());
+        list.insert(1);
+        list.insert(2);
+        while list.delete_at_index(0).is_ok() {}
+        list.shrink_pool();
+        list.shrink_pool(); // Shrinking an empty pool is a no-op.
+        list.insert(3);
+        assert_eq!(alloc.allocations.get(), 3);
+        assert_eq!(list.memory_footprint().nodes, 1);
+    }
+
+    /// Test that elements are dropped when their node is pooled, not when it is reused.
+    #[test]
+    fn test_pooled_elements_are_dropped() {
+        let shared = Rc::new(());
+        let mut list = DynamicLinkedList::new();
+        list.insert(Rc::clone(&shared));
+        list.insert(Rc::clone(&shared));
+        list.delete_at_index(0).unwrap();
+        assert_eq!(Rc::strong_count(&shared), 2); // The pooled node holds no element.
+        let mut cursor = list.cursor_mut();
+        assert!(cursor.remove_current().is_some());
+        list.insert(Rc::clone(&shared));
+        drop(list);
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+}
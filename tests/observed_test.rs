@@ -0,0 +1,72 @@
+// observed_test.rs
+// This file contains unit tests for the Observed adapter.
+// It tests that the insert, remove and update observers see each change made through the list.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod observed_tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::observed::Observed;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Wraps `list` with observers that log every change to the returned log.
+    fn logged<L: ListRead<i32>>(list: L) -> (Observed<i32, L>, Rc<RefCell<Vec<String>>>) {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut observed = Observed::new(list);
+        let (inserts, removes, updates) = (log.clone(), log.clone(), log.clone());
+        observed
+            .on_insert(move |index, data| inserts.borrow_mut().push(format!("+{index}:{data}")))
+            .on_remove(move |index, data| removes.borrow_mut().push(format!("-{index}:{data}")))
+            .on_update(move |index, old, new| {
+                updates.borrow_mut().push(format!("~{index}:{old}->{new}"))
+            });
+        (observed, log)
+    }
+
+    /// Test that insertions, removals and updates are reported with their indices.
+    #[test]
+    fn test_observers_see_changes() {
+        let (mut list, log) = logged(DynamicLinkedList::new());
+        list.insert(1);
+        list.insert(3);
+        list.insert_at_index(1, 2).unwrap();
+        list.update_element_at_index(0, 10).unwrap();
+        list.delete_at_index(2).unwrap();
+        assert_eq!(*log.borrow(), ["+0:1", "+1:3", "+1:2", "~0:1->10", "-2:3"]);
+        assert_eq!(list.inner().iter().copied().collect::<Vec<_>>(), [10, 2]);
+    }
+
+    /// Test that the value-based operations are reported through the index-based ones.
+    #[test]
+    fn test_value_based_operations() {
+        let (mut list, log) = logged(DynamicLinkedList::new());
+        for value in [4, 5, 4] {
+            list.insert(value);
+        }
+        log.borrow_mut().clear();
+        assert!(list.update_element(&5, 6));
+        assert!(list.delete_element(&4)); // The first 4 only.
+        assert!(!list.delete_element(&7));
+        assert_eq!(*log.borrow(), ["~1:5->6", "-0:4"]);
+    }
+
+    /// Test that failed operations are not reported.
+    #[test]
+    fn test_failures_are_not_observed() {
+        let (mut list, log) = logged(StaticLinkedList::<i32, 1>::new());
+        list.insert(1);
+        list.insert(2); // The list is full.
+        assert!(list.try_insert(3).is_err());
+        assert!(list.insert_at_index(5, 4).is_err());
+        assert!(list.delete_at_index(1).is_err());
+        assert!(list.update_element_at_index(1, 5).is_err());
+        *list.get_mut(0).unwrap() = 9; // In-place changes are not observed.
+        assert_eq!(*log.borrow(), ["+0:1"]);
+        assert_eq!(list.len(), 1);
+    }
+}
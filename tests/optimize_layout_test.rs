@@ -0,0 +1,66 @@
+// optimize_layout_test.rs
+// This file contains unit tests for optimize_layout and the locality stats.
+// It tests that the static and dynamic lists keep their order while their nodes move into traversal order.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod optimize_layout_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::footprint::LocalityStats;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that a churned static list ends up with every link to the adjacent slot.
+    #[test]
+    fn test_static_optimize_layout() {
+        let mut list = StaticLinkedList::<i32, 5>::new();
+        for value in 1..=5 {
+            list.insert(value);
+        }
+        list.delete_at_index(0).unwrap();
+        list.insert(6); // Lands in slot 0, behind the rest.
+        let (before, after) = list.optimize_layout();
+        assert_eq!((before.links, before.forward, before.adjacent), (4, 3, 3));
+        assert_eq!((after.links, after.forward, after.adjacent), (4, 4, 4)); // All adjacent.
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![2, 3, 4, 5, 6]
+        );
+    }
+
+    /// Test that a dynamic list walks its nodes in address order afterwards.
+    #[test]
+    fn test_dynamic_optimize_layout() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..50 {
+            list.insert_at_index(0, value).unwrap(); // Reverse order.
+        }
+        let (_, after) = list.optimize_layout();
+        assert_eq!(after.links, 49);
+        assert_eq!(after.forward, 49); // Every link leads to a higher address.
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            (0..50).rev().collect::<Vec<_>>()
+        );
+        list.insert(-1); // The tail is still right.
+        assert_eq!(list.get_mut(3), Some(&mut 46)); // The cached position was reset.
+        assert_eq!(list.len(), 51);
+    }
+
+    /// Test the stats of lists too short to have links.
+    #[test]
+    fn test_locality_of_short_lists() {
+        let mut list = DynamicLinkedList::new();
+        let empty = LocalityStats {
+            links: 0,
+            forward: 0,
+            adjacent: 0,
+        };
+        assert_eq!(list.optimize_layout(), (empty, empty));
+        list.insert(String::from("only"));
+        assert_eq!(list.locality(), empty);
+        let list = StaticLinkedList::<u8, 2>::new();
+        assert_eq!(list.locality(), empty);
+    }
+}
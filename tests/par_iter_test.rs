@@ -0,0 +1,54 @@
+// par_iter_test.rs
+// This file contains unit tests for parallel iteration over the lists with rayon.
+// Run with `cargo test --features rayon`.
+
+#![cfg(feature = "rayon")]
+
+#[cfg(test)]
+mod par_iter_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::unrolled_linked_list::UnrolledLinkedList;
+    use linked_list_impls::ListWrite;
+    use rayon::prelude::*;
+
+    /// Test that a parallel map over a large list matches the sequential one, in order.
+    #[test]
+    fn test_par_map_preserves_order() {
+        let mut list = UnrolledLinkedList::<u64, 16>::new();
+        for value in 0..10_000 {
+            list.insert(value);
+        }
+        let squares: Vec<u64> = list.par_iter().map(|x| x * x).collect();
+        let expected: Vec<u64> = list.iter().map(|x| x * x).collect();
+        assert_eq!(squares, expected);
+    }
+
+    /// Test parallel reductions and searches on a dynamic list.
+    #[test]
+    fn test_par_reductions() {
+        let mut list = DynamicLinkedList::new();
+        for value in 1..=1000 {
+            list.insert(value);
+        }
+        assert_eq!(list.par_iter().sum::<i32>(), 500_500);
+        assert_eq!(list.par_iter().len(), 1000); // Indexed, so the length is known.
+        assert_eq!(list.par_iter().position_first(|&x| x == 42), Some(41));
+        assert!(list.par_iter().any(|&x| x == 1000));
+    }
+
+    /// Test that empty and bounded lists work too.
+    #[test]
+    fn test_empty_and_static_lists() {
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(empty.par_iter().count(), 0);
+
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        for value in [4, 3, 2] {
+            list.insert(value);
+        }
+        let doubled: Vec<i32> = list.par_iter().map(|x| x * 2).collect();
+        assert_eq!(doubled, vec![8, 6, 4]);
+    }
+}
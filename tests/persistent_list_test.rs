@@ -0,0 +1,104 @@
+// persistent_list_test.rs
+// This file contains unit tests for the PersistentList implementation.
+// It tests that operations return new lists and leave earlier versions untouched.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod persistent_list_tests {
+    use std::thread;
+
+    use linked_list_impls::persistent_list::{PersistentList, SyncPersistentList};
+
+    /// Collects the list's contents front to back.
+    fn contents(list: &PersistentList<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    /// Test that `push_front` and `tail` leave the original list unchanged.
+    #[test]
+    fn test_push_front_and_tail() {
+        let base = PersistentList::new().push_front(3).push_front(2);
+        let pushed = base.push_front(1);
+        assert_eq!(contents(&pushed), vec![1, 2, 3]);
+        assert_eq!(contents(&base), vec![2, 3]); // Original is untouched.
+        assert!(pushed.tail().ptr_eq(&base)); // The tail is the very same nodes.
+        assert!(PersistentList::<i32>::new().tail().is_empty());
+    }
+
+    /// Test positional edits return new versions.
+    #[test]
+    fn test_insert_remove_update() {
+        let base = PersistentList::new()
+            .push_front(4)
+            .push_front(2)
+            .push_front(1);
+        let inserted = base.insert(2, 3).unwrap();
+        let removed = inserted.remove(0).unwrap();
+        let updated = removed.update(2, 40).unwrap();
+        assert_eq!(contents(&inserted), vec![1, 2, 3, 4]);
+        assert_eq!(contents(&removed), vec![2, 3, 4]);
+        assert_eq!(contents(&updated), vec![2, 3, 40]);
+        assert_eq!(contents(&base), vec![1, 2, 4]);
+        assert!(base.insert(4, 0).is_err());
+        assert!(base.remove(3).is_err());
+        assert!(base.update(3, 0).is_err());
+    }
+
+    /// Test that edits share the unchanged suffix.
+    #[test]
+    fn test_edits_share_suffix() {
+        let base = PersistentList::new()
+            .push_front(4)
+            .push_front(3)
+            .push_front(2)
+            .push_front(1);
+        let updated = base.update(1, 20).unwrap();
+        assert!(updated.tail().tail().ptr_eq(&base.tail().tail())); // [3, 4] is shared.
+        assert!(!updated.ptr_eq(&base));
+        assert!(base.clone().ptr_eq(&base)); // Cloning is a snapshot.
+    }
+
+    /// Test lookups.
+    #[test]
+    fn test_get_and_find() {
+        let list = PersistentList::new().push_front(6).push_front(5);
+        assert_eq!(list.front(), Some(&5));
+        assert_eq!(list.get(1), Some(&6));
+        assert_eq!(list.get(2), None);
+        assert!(list.find(&6));
+        assert!(!list.find(&7));
+        assert_eq!(list.len(), 2);
+    }
+
+    /// Test that dropping a long list does not overflow the stack.
+    #[test]
+    fn test_drop_long_list() {
+        let mut list = PersistentList::new();
+        for value in 0..200_000 {
+            list = list.push_front(value);
+        }
+        let snapshot = list.tail();
+        drop(list);
+        assert_eq!(snapshot.len(), 199_999); // The shared suffix survives.
+    }
+
+    /// Test that the `Arc`-backed variant can be shared and extended across threads.
+    #[test]
+    fn test_sync_list_across_threads() {
+        let base: SyncPersistentList<i32> = SyncPersistentList::empty().push_front(2).push_front(1);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let base = base.clone();
+                thread::spawn(move || base.push_front(i * 10))
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            let extended = handle.join().unwrap();
+            assert_eq!(extended.front(), Some(&(i as i32 * 10)));
+            assert!(extended.tail().ptr_eq(&base)); // Every thread shares the base nodes.
+        }
+        assert_eq!(base.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,72 @@
+// priority_list_test.rs
+// This file contains unit tests for the PriorityList implementation.
+// It tests priority ordering on the dynamic and bounded (static) variants.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod priority_list_tests {
+    use std::cmp::Reverse;
+
+    use linked_list_impls::priority_list::{BoundedPriorityList, PriorityList};
+
+    /// Test that elements pop in descending priority order.
+    #[test]
+    fn test_pop_in_priority_order() {
+        let mut queue = PriorityList::new();
+        for value in [3, 7, 1, 5] {
+            queue.push(value).unwrap();
+        }
+        assert_eq!(queue.peek(), Some(&7));
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![7, 5, 3, 1]);
+        assert_eq!(queue.pop(), Some(7));
+        assert_eq!(queue.pop(), Some(5));
+        assert_eq!(queue.len(), 2);
+    }
+
+    /// A task ordered by its priority alone, ignoring its name.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Task(u8, char);
+
+    impl PartialOrd for Task {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Task {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    /// Test that equal priorities are popped first in, first out.
+    #[test]
+    fn test_equal_priorities_are_fifo() {
+        let mut queue = PriorityList::new();
+        for task in [Task(1, 'a'), Task(2, 'b'), Task(1, 'c')] {
+            queue.push(task).unwrap();
+        }
+        let order: Vec<char> = std::iter::from_fn(|| queue.pop()).map(|task| task.1).collect();
+        assert_eq!(order, vec!['b', 'a', 'c']); // 'a' was pushed before 'c'.
+
+        let mut min_queue = PriorityList::new();
+        for value in [Reverse(2), Reverse(1), Reverse(3)] {
+            min_queue.push(value).unwrap();
+        }
+        assert_eq!(min_queue.pop(), Some(Reverse(1))); // `Reverse` turns it into a min-queue.
+    }
+
+    /// Test the fixed-capacity variant.
+    #[test]
+    fn test_bounded_priority_list() {
+        let mut queue = BoundedPriorityList::<i32, 2>::empty();
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        assert!(queue.push(3).is_err()); // No free slots left.
+        assert_eq!(queue.pop(), Some(2));
+        queue.push(3).unwrap();
+        assert_eq!(format!("{:?}", queue), "[3, 1]");
+        assert!(queue.pop().is_some() && queue.pop().is_some() && queue.is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+// property_test.rs
+// This file contains property-based tests for the linked list implementations.
+// Random sequences of operations are applied to a list and to a `Vec<i32>` reference
+// model, and the two are checked to agree after every step.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod property_tests {
+    use linked_list_impls::circular_doubly_linked_list::CircularDoublyLinkedList;
+    use linked_list_impls::circular_linked_list::CircularLinkedList;
+    #[cfg(feature = "std")]
+    use linked_list_impls::concurrent_linked_list::ConcurrentLinkedList;
+    use linked_list_impls::counted::Counted;
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::skip_list::IndexedSkipList;
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::small_list::SmallList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::unrolled_linked_list::UnrolledLinkedList;
+    use linked_list_impls::xor_linked_list::XorLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+    use proptest::prelude::*;
+
+    /// Capacity used for the static list under test. Kept small so that
+    /// sequences regularly hit the "list is full" paths.
+    const CAPACITY: usize = 8;
+
+    /// A single operation from the `ListWrite` interface.
+    #[derive(Debug, Clone)]
+    enum Op {
+        Insert(i32),
+        InsertAt(usize, i32),
+        DeleteElement(i32),
+        DeleteAt(usize),
+        UpdateElement(i32, i32),
+        UpdateAt(usize, i32),
+        Find(i32),
+        Get(usize),
+    }
+
+    /// Generates operations with small values and indices so that duplicates,
+    /// boundary indices, and out-of-bounds indices are all common.
+    fn op_strategy() -> impl Strategy<Value = Op> {
+        let value = 0..6i32;
+        let index = 0..(CAPACITY + 3);
+        prop_oneof![
+            value.clone().prop_map(Op::Insert),
+            (index.clone(), value.clone()).prop_map(|(i, v)| Op::InsertAt(i, v)),
+            value.clone().prop_map(Op::DeleteElement),
+            index.clone().prop_map(Op::DeleteAt),
+            (value.clone(), value.clone()).prop_map(|(o, n)| Op::UpdateElement(o, n)),
+            (index.clone(), value.clone()).prop_map(|(i, v)| Op::UpdateAt(i, v)),
+            value.prop_map(Op::Find),
+            index.prop_map(Op::Get),
+        ]
+    }
+
+    /// Applies `ops` to both `list` and a `Vec` model, asserting that every result
+    /// and the full list contents match the model after each operation.
+    ///
+    /// `capacity` is `Some(n)` for bounded lists, which silently drop tail inserts
+    /// and reject `insert_at_index` once `n` elements are stored.
+    fn check_against_model<L: ListWrite<i32>>(
+        list: &mut L,
+        capacity: Option<usize>,
+        ops: &[Op],
+    ) -> Result<(), TestCaseError> {
+        let mut model: Vec<i32> = Vec::new();
+        let is_full = |model: &Vec<i32>| capacity.is_some_and(|c| model.len() >= c);
+
+        for op in ops {
+            match *op {
+                Op::Insert(v) => {
+                    list.insert(v);
+                    if !is_full(&model) {
+                        model.push(v);
+                    }
+                }
+                Op::InsertAt(i, v) => {
+                    let result = list.insert_at_index(i, v);
+                    if i <= model.len() && !is_full(&model) {
+                        prop_assert!(result.is_ok(), "{:?} failed: {:?}", op, result);
+                        model.insert(i, v);
+                    } else {
+                        prop_assert!(result.is_err(), "{:?} should have failed", op);
+                    }
+                }
+                Op::DeleteElement(v) => {
+                    let expected = match model.iter().position(|&x| x == v) {
+                        Some(pos) => {
+                            model.remove(pos);
+                            true
+                        }
+                        None => false,
+                    };
+                    prop_assert_eq!(list.delete_element(&v), expected, "{:?}", op);
+                }
+                Op::DeleteAt(i) => {
+                    let result = list.delete_at_index(i);
+                    if i < model.len() {
+                        prop_assert!(result.is_ok(), "{:?} failed: {:?}", op, result);
+                        model.remove(i);
+                    } else {
+                        prop_assert!(result.is_err(), "{:?} should have failed", op);
+                    }
+                }
+                Op::UpdateElement(old, new) => {
+                    let expected = match model.iter_mut().find(|x| **x == old) {
+                        Some(slot) => {
+                            *slot = new;
+                            true
+                        }
+                        None => false,
+                    };
+                    prop_assert_eq!(list.update_element(&old, new), expected, "{:?}", op);
+                }
+                Op::UpdateAt(i, v) => {
+                    let result = list.update_element_at_index(i, v);
+                    if i < model.len() {
+                        prop_assert!(result.is_ok(), "{:?} failed: {:?}", op, result);
+                        model[i] = v;
+                    } else {
+                        prop_assert!(result.is_err(), "{:?} should have failed", op);
+                    }
+                }
+                Op::Find(v) => {
+                    prop_assert_eq!(list.find(&v), model.contains(&v), "{:?}", op);
+                }
+                Op::Get(i) => {
+                    prop_assert_eq!(list.get(i), model.get(i), "{:?}", op);
+                }
+            }
+
+            // The whole list must match the model, and nothing may exist past its end.
+            for (i, expected) in model.iter().enumerate() {
+                prop_assert_eq!(list.get(i), Some(expected), "after {:?}", op);
+            }
+            prop_assert_eq!(list.get(model.len()), None, "after {:?}", op);
+        }
+
+        Ok(())
+    }
+
+    proptest! {
+        // Miri runs each case orders of magnitude slower, so it checks only a few, and it
+        // cannot write the regression files.
+        #![proptest_config(ProptestConfig {
+            cases: if cfg!(miri) { 4 } else { ProptestConfig::default().cases },
+            failure_persistence: if cfg!(miri) {
+                None
+            } else {
+                ProptestConfig::default().failure_persistence
+            },
+            ..ProptestConfig::default()
+        })]
+
+        /// The dynamic list behaves like an unbounded `Vec`.
+        #[test]
+        fn dynamic_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: DynamicLinkedList<i32> = DynamicLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The static list behaves like a `Vec` bounded to `CAPACITY` elements.
+        #[test]
+        fn static_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: StaticLinkedList<i32, CAPACITY> = StaticLinkedList::new();
+            check_against_model(&mut list, Some(CAPACITY), &ops)?;
+        }
+
+        /// `Counted` forwards to its list faithfully and its `len` tracks the model's.
+        #[test]
+        fn counted_static_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: Counted<StaticLinkedList<i32, CAPACITY>> = Counted::default();
+            check_against_model(&mut list, Some(CAPACITY), &ops)?;
+            let mut len = 0;
+            while ListRead::get(&list, len).is_some() {
+                len += 1;
+            }
+            prop_assert_eq!(list.len(), len);
+        }
+
+        /// The doubly linked list behaves like an unbounded `Vec`.
+        #[test]
+        fn doubly_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The circular linked list behaves like an unbounded `Vec`.
+        #[test]
+        fn circular_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: CircularLinkedList<i32> = CircularLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The circular doubly linked list behaves like an unbounded `Vec`.
+        #[test]
+        fn circular_doubly_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: CircularDoublyLinkedList<i32> = CircularDoublyLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The unrolled linked list behaves like an unbounded `Vec`. A tiny node size
+        /// forces frequent node splits and merges.
+        #[test]
+        fn unrolled_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: UnrolledLinkedList<i32, 3> = UnrolledLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The XOR linked list behaves like an unbounded `Vec`.
+        #[test]
+        fn xor_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: XorLinkedList<i32> = XorLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The indexed skip list behaves like an unbounded `Vec`.
+        #[test]
+        fn skip_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: IndexedSkipList<i32> = IndexedSkipList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The slab-backed linked list behaves like an unbounded `Vec`.
+        #[test]
+        fn slab_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: SlabLinkedList<i32> = SlabLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The small list behaves like an unbounded `Vec`, before and after spilling.
+        #[test]
+        fn small_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: SmallList<i32, 4> = SmallList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+
+        /// The lock-guarded concurrent list behaves like an unbounded `Vec`.
+        #[test]
+        #[cfg(feature = "std")]
+        fn concurrent_list_matches_vec_model(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            let mut list: ConcurrentLinkedList<i32> = ConcurrentLinkedList::new();
+            check_against_model(&mut list, None, &ops)?;
+        }
+    }
+}
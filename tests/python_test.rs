@@ -0,0 +1,71 @@
+// python_test.rs
+// This file contains unit tests for the Python LinkedList class behind the `python` feature.
+// Run with `cargo test --features python`; the class is driven from an embedded interpreter.
+
+#![cfg(feature = "python")]
+
+#[cfg(test)]
+mod python_tests {
+    use linked_list_impls::python::PyLinkedList;
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+
+    /// Runs `code` with `LinkedList` in scope and returns the value of `result`.
+    fn run<T: for<'a, 'py> FromPyObject<'a, 'py>>(code: &str) -> T {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+            globals
+                .set_item("LinkedList", py.get_type::<PyLinkedList>())
+                .unwrap();
+            py.run(&std::ffi::CString::new(code).unwrap(), Some(&globals), None)
+                .unwrap();
+            globals
+                .get_item("result")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .map_err(Into::into)
+                .unwrap()
+        })
+    }
+
+    /// Test inserting, indexing and `len` from Python.
+    #[test]
+    fn test_insert_getitem_len() {
+        let result: (usize, i64, String, i64) = run("l = LinkedList()\n\
+             l.insert(1)\n\
+             l.insert('two')\n\
+             l.insert(3)\n\
+             result = (len(l), l[0], l[1], l[-1])");
+        assert_eq!(result, (3, 1, "two".to_string(), 3)); // Any Python object can be stored.
+    }
+
+    /// Test iteration, membership, deletion and `repr`.
+    #[test]
+    fn test_iteration_and_deletion() {
+        let result: (Vec<i64>, bool, String) = run("l = LinkedList(range(5))\n\
+             del l[1]\n\
+             del l[-1]\n\
+             result = ([x * 10 for x in l], 3 in l, repr(l))");
+        assert_eq!(result.0, vec![0, 20, 30]);
+        assert!(result.1);
+        assert_eq!(result.2, "LinkedList([0, 2, 3])");
+    }
+
+    /// Test that out-of-range indices raise `IndexError`.
+    #[test]
+    fn test_index_error() {
+        let result: (bool, bool) = run("l = LinkedList([1])\n\
+             def raises(f):\n\
+             \x20   try:\n\
+             \x20       f()\n\
+             \x20   except IndexError:\n\
+             \x20       return True\n\
+             \x20   return False\n\
+             def delete():\n\
+             \x20   del l[1]\n\
+             result = (raises(lambda: l[-2]), raises(delete))");
+        assert_eq!(result, (true, true)); // Like a Python list.
+    }
+}
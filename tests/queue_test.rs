@@ -0,0 +1,52 @@
+// queue_test.rs
+// This file contains unit tests for the Queue adapter.
+// It tests FIFO behavior over several linked list backends.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod queue_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::queue::Queue;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+
+    /// Test FIFO order on the default dynamic backend.
+    #[test]
+    fn test_fifo_order() {
+        let mut queue = Queue::new();
+        for value in 1..=3 {
+            queue.enqueue(value).unwrap();
+        }
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(4).unwrap();
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), None); // The queue is empty.
+        assert!(queue.is_empty());
+    }
+
+    /// Test a queue over the doubly linked list backend.
+    #[test]
+    fn test_doubly_backend() {
+        let mut queue = Queue::<String, DoublyLinkedList<String>>::empty();
+        queue.enqueue("a".to_string()).unwrap();
+        queue.enqueue("b".to_string()).unwrap();
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.dequeue().as_deref(), Some("a"));
+        assert_eq!(queue.peek().map(String::as_str), Some("b"));
+    }
+
+    /// Test that a bounded backend reports a full queue.
+    #[test]
+    fn test_static_backend_full() {
+        let mut queue = Queue::<i32, StaticLinkedList<i32, 2>>::empty();
+        queue.enqueue(1).unwrap();
+        queue.enqueue(2).unwrap();
+        assert!(queue.enqueue(3).is_err()); // No free slots left.
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(3).unwrap(); // The freed slot is reused.
+        assert_eq!(queue.len(), 2);
+    }
+}
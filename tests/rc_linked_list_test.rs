@@ -0,0 +1,78 @@
+// rc_linked_list_test.rs
+// This file contains unit tests for the RcLinkedList implementation.
+// It tests shared ownership of the list and mutation through node handles.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod rc_linked_list_tests {
+    use linked_list_impls::rc_linked_list::RcLinkedList;
+
+    /// Test pushing and popping at both ends.
+    #[test]
+    fn test_push_pop_both_ends() {
+        let list = RcLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None); // List is now empty.
+        assert!(list.front().is_none());
+        assert!(list.back().is_none());
+    }
+
+    /// Test that clones are owners of the same list.
+    #[test]
+    fn test_clones_share_the_list() {
+        let list = RcLinkedList::new();
+        let other_owner = list.clone();
+        list.push_back(1);
+        other_owner.push_back(2);
+        assert!(list.ptr_eq(&other_owner));
+        assert_eq!(list.to_vec(), vec![1, 2]);
+        assert_eq!(other_owner.len(), 2);
+    }
+
+    /// Test mutating elements through node handles.
+    #[test]
+    fn test_mutate_through_handles() {
+        let list = RcLinkedList::new();
+        let first = list.push_back(1);
+        list.push_back(2);
+        *first.borrow_mut().unwrap() += 10;
+        let second = first.next().unwrap();
+        assert_eq!(second.replace(20), Ok(2));
+        assert_eq!(list.to_vec(), vec![11, 20]);
+        assert!(second.prev().unwrap().ptr_eq(&first)); // Weak back-link resolves.
+        assert_eq!(list.get(1), Some(20));
+        assert!(list.find(&11));
+    }
+
+    /// Test that handles to removed nodes become detached instead of dangling.
+    #[test]
+    fn test_removed_handles_are_detached() {
+        let list = RcLinkedList::new();
+        let first = list.push_back(1);
+        let second = list.push_back(2);
+        assert_eq!(list.pop_front(), Some(1));
+        assert!(!first.is_linked());
+        assert!(first.borrow().is_none());
+        assert!(first.next().is_none());
+        assert_eq!(first.replace(5), Err(5));
+        assert!(second.prev().is_none()); // The survivor no longer points back.
+        assert!(list.node_at(0).unwrap().ptr_eq(&second));
+    }
+
+    /// Test that dropping a long list does not overflow the stack.
+    #[test]
+    fn test_drop_long_list() {
+        let list = RcLinkedList::new();
+        for value in 0..100_000 {
+            list.push_back(value);
+        }
+        drop(list);
+    }
+}
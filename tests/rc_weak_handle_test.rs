@@ -0,0 +1,54 @@
+// rc_weak_handle_test.rs
+// This file contains unit tests for the weak node handles of RcLinkedList.
+// It tests upgrading while linked and dangling handles after deletion.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod rc_weak_handle_tests {
+    use linked_list_impls::rc_linked_list::RcLinkedList;
+
+    /// Test that a weak handle upgrades to the same node while it is in the list.
+    #[test]
+    fn test_upgrade_while_linked() {
+        let list = RcLinkedList::new();
+        let weak = list.push_back(1).downgrade(); // The strong handle is dropped at once.
+        list.push_back(2);
+        let handle = weak.upgrade().unwrap();
+        *handle.borrow_mut().unwrap() = 10;
+        assert!(handle.ptr_eq(&list.front().unwrap()));
+        assert_eq!(list.to_vec(), vec![10, 2]);
+        assert!(!weak.is_dangling());
+    }
+
+    /// Test that a weak handle dangles once its element is removed.
+    #[test]
+    fn test_dangling_after_delete() {
+        let list = RcLinkedList::new();
+        list.push_back(1);
+        let weak = list.push_back(2).downgrade();
+        let copy = weak.clone();
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(weak.is_dangling()); // Nothing kept the node alive.
+        assert!(weak.upgrade().is_none());
+        assert!(copy.upgrade().is_none());
+        list.push_back(3); // A new node, not the old one.
+        assert!(weak.upgrade().is_none());
+    }
+
+    /// Test that a node kept alive by a strong handle still does not upgrade once removed.
+    #[test]
+    fn test_detached_node_does_not_upgrade() {
+        let list = RcLinkedList::new();
+        let strong = list.push_front(1);
+        let weak = strong.downgrade();
+        assert_eq!(list.pop_front(), Some(1));
+        assert!(!weak.is_dangling()); // `strong` keeps the node alive.
+        assert!(weak.upgrade().is_none()); // But it is detached.
+        drop(strong);
+        assert!(weak.is_dangling());
+        let survivor = list.push_back(2).downgrade();
+        drop(list);
+        assert!(survivor.is_dangling()); // Dropping the list frees its nodes.
+    }
+}
@@ -0,0 +1,55 @@
+// render_ascii_test.rs
+// This file contains unit tests for the ASCII drawings of list structure.
+// It tests the chain of a dynamic list and the slot table of a static list.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod render_ascii_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that an empty list draws as a head pointing at the null link.
+    #[test]
+    fn test_render_empty() {
+        let list: DynamicLinkedList<i32> = DynamicLinkedList::new();
+        assert_eq!(list.render_ascii(), "[head] -> X");
+        let list: StaticLinkedList<i32, 2> = StaticLinkedList::new();
+        assert_eq!(
+            list.render_ascii(),
+            "[head] -> X\n\
+             slot  value  next  note\n\
+             0     -      -     free #1\n\
+             1     -      -     free #2"
+        );
+    }
+
+    /// Test that a dynamic list draws its elements front to back.
+    #[test]
+    fn test_render_dynamic_chain() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(3);
+        list.insert(7);
+        assert_eq!(list.render_ascii(), "[head] -> 3 -> 7 -> X");
+    }
+
+    /// Test that a static list marks the head, the tail and the free slots in its table.
+    #[test]
+    fn test_render_static_slots() {
+        let mut list: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        for value in [5, 3, 7] {
+            list.insert(value);
+        }
+        list.delete_at_index(0).unwrap(); // Slot 0 goes back on the free stack.
+        assert_eq!(
+            list.render_ascii(),
+            "[head] -> 3 -> 7 -> X\n\
+             slot  value  next  note\n\
+             0     -      -     free #1\n\
+             1     3      2     head\n\
+             2     7      X     tail\n\
+             3     -      -     free #2"
+        );
+    }
+}
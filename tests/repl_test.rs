@@ -0,0 +1,75 @@
+// repl_test.rs
+// This file contains tests for the interactive shell in the binary.
+// It feeds commands to the binary on stdin and checks what it prints.
+
+#[cfg(test)]
+mod repl_tests {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    /// Runs the shell on `input` and returns its output lines, without the prompts.
+    fn run(input: &str) -> Vec<String> {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_linked_list_impls"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .split("> ")
+            .skip(1) // The banner
+            .map(|line| line.trim_end().to_string())
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    /// Test editing a dynamic list and printing it.
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri cannot spawn the binary.
+    fn test_edit_and_print() {
+        let lines =
+            run("insert 5\ninsert 7\ninsert_at 1 6\ndelete 5\nset 0 1\nget 1\nfind 7\nprint\n");
+        assert_eq!(lines, vec!["7", "1", "[1, 7] (dynamic, 2 elements)"]);
+    }
+
+    /// Test switching to a static list of a given capacity and back.
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri cannot spawn the binary.
+    fn test_switch_implementation() {
+        let lines = run("insert 1\ninsert 2\nswitch static 2\ninsert 3\nswitch static 1\nswitch dynamic\ninsert 3\nlen\n");
+        assert_eq!(
+            lines,
+            vec![
+                "[1, 2] (static, capacity 2, 2 elements)",
+                "error: the list is full (capacity 2)", // The static list keeps its bound.
+                "error: the elements do not fit: the list is full (capacity 1)",
+                "[1, 2] (dynamic, 2 elements)",
+                "3",
+            ]
+        );
+    }
+
+    /// Test that bad commands are reported without changing the list, and that `quit` stops the shell.
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri cannot spawn the binary.
+    fn test_errors_and_quit() {
+        let lines = run("insert 1 2\nfrobnicate\ndelete_at 3\nget x\nprint\nquit\nprint\n");
+        assert_eq!(
+            lines,
+            vec![
+                "error: unexpected argument `2`",
+                "error: unknown command `frobnicate`; try `help`",
+                "error: index 3 is out of bounds for a list of length 0",
+                "error: invalid index `x`",
+                "[] (dynamic, 0 elements)", // Nothing after `quit` runs.
+            ]
+        );
+    }
+}
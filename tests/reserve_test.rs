@@ -0,0 +1,79 @@
+// reserve_test.rs
+// This file contains unit tests for reserve on DynamicLinkedList and StaticLinkedList.
+// It tests that reserved dynamic nodes are used without allocating and that the static check reports a full list.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod reserve_tests {
+    use std::alloc::Layout;
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+    use std::rc::Rc;
+
+    use linked_list_impls::allocator::{Alloc, Global};
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListError, ListWrite};
+
+    /// An allocator that counts how many blocks it has handed out in total.
+    #[derive(Clone, Default)]
+    struct Counting {
+        allocations: Rc<Cell<usize>>,
+    }
+
+    unsafe impl Alloc for Counting {
+        fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+            self.allocations.set(self.allocations.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            Global.deallocate(ptr, layout);
+        }
+    }
+
+    /// Test that insertions after `reserve` take the reserved nodes.
+    #[test]
+    fn test_dynamic_reserve() {
+        let alloc = Counting::default();
+        let mut list = DynamicLinkedList::new_in(alloc.clone());
+        list.reserve(10);
+        assert_eq!(alloc.allocations.get(), 10); // Paid up front.
+        for value in 0..10 {
+            list.insert(value);
+        }
+        assert_eq!(alloc.allocations.get(), 10);
+        list.insert(10);
+        assert_eq!(alloc.allocations.get(), 11);
+    }
+
+    /// Test that reserving tops up the pool rather than adding to it.
+    #[test]
+    fn test_dynamic_reserve_counts_pooled_nodes() {
+        let alloc = Counting::default();
+        let mut list = DynamicLinkedList::new_in(alloc.clone());
+        for value in 0..4 {
+            list.insert(value);
+        }
+        while list.delete_at_index(0).is_ok() {}
+        list.reserve(3); // Four nodes are already pooled.
+        assert_eq!(alloc.allocations.get(), 4);
+        list.reserve(6);
+        assert_eq!(alloc.allocations.get(), 6);
+        assert_eq!(list.memory_footprint().nodes, 0);
+    }
+
+    /// Test that a static list reports whether the reserved slots are free.
+    #[test]
+    fn test_static_reserve() {
+        let mut list = StaticLinkedList::<i32, 3>::new();
+        assert_eq!(list.reserve(3), Ok(()));
+        list.insert(1);
+        assert_eq!(list.reserve(2), Ok(()));
+        assert_eq!(
+            list.reserve(3),
+            Err(LinkedListError::CapacityExceeded { capacity: 3 })
+        ); // Only two slots are left.
+    }
+}
@@ -0,0 +1,71 @@
+// rope_test.rs
+// This file contains unit tests for the Rope implementation.
+// It tests editing at character offsets, chunk splitting, and the iterators.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod rope_tests {
+    use linked_list_impls::rope::Rope;
+
+    /// Test inserting and removing at character offsets, including multi-byte text.
+    #[test]
+    fn test_insert_and_remove() {
+        let mut rope = Rope::from("héllo world");
+        rope.insert(5, ",").unwrap();
+        rope.insert(0, "¡").unwrap();
+        assert_eq!(rope, "¡héllo, world");
+        rope.remove(7, 13).unwrap();
+        assert_eq!(rope.to_string(), "¡héllo,");
+        assert_eq!(rope.len_chars(), 7);
+        assert_eq!(rope.char_at(2), Some('é'));
+        assert!(rope.insert(8, "x").is_err());
+        assert!(rope.remove(3, 2).is_err());
+    }
+
+    /// Test that large text is split into chunks and edits across chunks work.
+    #[test]
+    fn test_large_text_spans_chunks() {
+        let text = "abcdefghij".repeat(200);
+        let mut rope = Rope::from(text.as_str());
+        assert!(rope.chunk_count() > 1);
+        assert_eq!(rope.len_bytes(), 2000);
+
+        rope.insert(1000, &"-".repeat(1000)).unwrap(); // Splits the chunk.
+        assert_eq!(rope.len_chars(), 3000);
+        rope.remove(500, 2500).unwrap(); // Spans several chunks.
+        let mut expected = text.clone();
+        expected.replace_range(500..1500, "");
+        assert_eq!(String::from(&rope), expected);
+
+        let text = &text[..600];
+        let mut rope = Rope::from(text);
+        rope.remove(10, 520).unwrap(); // Leaves a small first chunk mid-removal.
+        let mut expected = text.to_string();
+        expected.replace_range(10..520, "");
+        assert_eq!(String::from(&rope), expected);
+    }
+
+    /// Test the char and line iterators.
+    #[test]
+    fn test_iterators() {
+        let mut rope = Rope::new();
+        rope.push_str("one\r\ntwo\n");
+        rope.push_str("\nthree");
+        assert_eq!(rope.lines().collect::<Vec<_>>(), vec!["one", "two", "", "three"]);
+        assert_eq!(rope.chars().filter(|c| *c == '\n').count(), 3);
+        assert_eq!(Rope::from("x\n").lines().count(), 1);
+        assert_eq!(Rope::new().lines().count(), 0);
+    }
+
+    /// Test removing everything leaves an empty rope that can be reused.
+    #[test]
+    fn test_remove_all() {
+        let mut rope = Rope::from("a".repeat(1500).as_str());
+        rope.remove(0, 1500).unwrap();
+        assert!(rope.is_empty());
+        assert_eq!(rope.chunk_count(), 0);
+        rope.insert(0, "again").unwrap();
+        assert_eq!(format!("{:?}", rope), "Rope(\"again\")");
+    }
+}
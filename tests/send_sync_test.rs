@@ -0,0 +1,140 @@
+// send_sync_test.rs
+// This file contains compile-time checks of which types are `Send` and `Sync`.
+// A wrong `unsafe impl` or a missing one fails the build rather than a test run.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod send_sync_tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::thread;
+
+    use linked_list_impls::circular_doubly_linked_list::{self, CircularDoublyLinkedList};
+    use linked_list_impls::circular_linked_list::{self, CircularLinkedList};
+    #[cfg(feature = "std")]
+    use linked_list_impls::concurrent_linked_list::ConcurrentLinkedList;
+    use linked_list_impls::counted::Counted;
+    use linked_list_impls::deque::Deque;
+    use linked_list_impls::doubly_linked_list::{self, DoublyLinkedList};
+    use linked_list_impls::dynamic_linked_list::{self, DynamicLinkedList};
+    #[cfg(feature = "std")]
+    use linked_list_impls::hand_over_hand_list::HandOverHandList;
+    #[cfg(feature = "std")]
+    use linked_list_impls::harris_list::HarrisList;
+    #[cfg(feature = "std")]
+    use linked_list_impls::linked_hash_map::{LinkedHashMap, LinkedHashSet};
+    #[cfg(feature = "std")]
+    use linked_list_impls::ms_queue::MsQueue;
+    use linked_list_impls::multilevel_list::MultilevelList;
+    use linked_list_impls::persistent_list::{ArcPointer, PersistentList};
+    use linked_list_impls::priority_list::PriorityList;
+    use linked_list_impls::queue::Queue;
+    use linked_list_impls::rc_linked_list::{NodeHandle, RcLinkedList};
+    use linked_list_impls::rope::Rope;
+    use linked_list_impls::skip_list::{self, IndexedSkipList};
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::small_list::SmallList;
+    use linked_list_impls::sorted_linked_list::SortedLinkedList;
+    use linked_list_impls::stack::{BoundedStack, Stack};
+    use linked_list_impls::static_linked_list::{self, StaticLinkedList};
+    use linked_list_impls::unrolled_linked_list::{self, UnrolledLinkedList};
+    use linked_list_impls::versioned_list::VersionedList;
+    use linked_list_impls::xor_linked_list::{self, XorLinkedList};
+    use linked_list_impls::ListWrite;
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+    // Every single-threaded list is `Send` and `Sync` whenever its elements are.
+    assert_impl_all!(CircularDoublyLinkedList<i32>: Send, Sync);
+    assert_impl_all!(CircularLinkedList<i32>: Send, Sync);
+    assert_impl_all!(Counted<DynamicLinkedList<i32>>: Send, Sync);
+    assert_impl_all!(Deque<i32>: Send, Sync);
+    assert_impl_all!(DoublyLinkedList<i32>: Send, Sync);
+    assert_impl_all!(DynamicLinkedList<i32>: Send, Sync);
+    assert_impl_all!(IndexedSkipList<i32>: Send, Sync);
+    #[cfg(feature = "std")]
+    assert_impl_all!(LinkedHashMap<i32, i32>: Send, Sync);
+    #[cfg(feature = "std")]
+    assert_impl_all!(LinkedHashSet<i32>: Send, Sync);
+    assert_impl_all!(MultilevelList<i32>: Send, Sync);
+    assert_impl_all!(PersistentList<i32, ArcPointer>: Send, Sync);
+    assert_impl_all!(PriorityList<i32>: Send, Sync);
+    assert_impl_all!(Queue<i32>: Send, Sync);
+    assert_impl_all!(Rope: Send, Sync);
+    assert_impl_all!(SlabLinkedList<i32>: Send, Sync);
+    assert_impl_all!(SmallList<i32, 4>: Send, Sync);
+    assert_impl_all!(SortedLinkedList<i32>: Send, Sync);
+    assert_impl_all!(Stack<i32>: Send, Sync);
+    assert_impl_all!(BoundedStack<i32, 4>: Send, Sync);
+    assert_impl_all!(StaticLinkedList<i32, 4>: Send, Sync);
+    assert_impl_all!(UnrolledLinkedList<i32, 4>: Send, Sync);
+    assert_impl_all!(VersionedList<i32>: Send, Sync);
+    assert_impl_all!(XorLinkedList<i32>: Send, Sync);
+
+    // Borrowing iterators can cross threads whenever `&T` can.
+    assert_impl_all!(circular_doubly_linked_list::Iter<'static, i32>: Send, Sync);
+    assert_impl_all!(circular_linked_list::Iter<'static, i32>: Send, Sync);
+    assert_impl_all!(doubly_linked_list::Iter<'static, i32>: Send, Sync);
+    assert_impl_all!(dynamic_linked_list::Iter<'static, i32>: Send, Sync);
+    assert_impl_all!(skip_list::Iter<'static, i32>: Send, Sync);
+    assert_impl_all!(static_linked_list::Iter<'static, i32, 4>: Send, Sync);
+    assert_impl_all!(unrolled_linked_list::Iter<'static, i32, 4>: Send, Sync);
+    assert_impl_all!(xor_linked_list::Iter<'static, i32>: Send, Sync);
+
+    // The raw-pointer lists still respect their element type.
+    assert_not_impl_any!(CircularDoublyLinkedList<Rc<i32>>: Send, Sync);
+    assert_not_impl_any!(XorLinkedList<Rc<i32>>: Send, Sync);
+    assert_impl_all!(XorLinkedList<Cell<i32>>: Send);
+    assert_not_impl_any!(XorLinkedList<Cell<i32>>: Sync);
+    assert_not_impl_any!(xor_linked_list::Iter<'static, Cell<i32>>: Send, Sync);
+
+    // Reference-counted lists with `Rc` never leave their thread.
+    assert_not_impl_any!(RcLinkedList<i32>: Send, Sync);
+    assert_not_impl_any!(NodeHandle<i32>: Send, Sync);
+    assert_not_impl_any!(PersistentList<i32>: Send, Sync);
+
+    // An arena list borrows a `Bump`, which must not be shared, but its iterator can be.
+    #[cfg(feature = "bumpalo")]
+    assert_not_impl_any!(linked_list_impls::arena_linked_list::ArenaLinkedList<'static, i32>: Send, Sync);
+    #[cfg(feature = "bumpalo")]
+    assert_impl_all!(linked_list_impls::arena_linked_list::Iter<'static, i32>: Send, Sync);
+
+    // The concurrent types are shareable; a queue only moves elements, a set shares them.
+    #[cfg(feature = "std")]
+    assert_impl_all!(ConcurrentLinkedList<i32>: Send, Sync);
+    #[cfg(feature = "std")]
+    assert_impl_all!(HandOverHandList<i32>: Send, Sync);
+    #[cfg(feature = "std")]
+    assert_impl_all!(HarrisList<i32>: Send, Sync);
+    #[cfg(feature = "std")]
+    assert_impl_all!(MsQueue<i32>: Send, Sync);
+    #[cfg(feature = "std")]
+    assert_impl_all!(MsQueue<Cell<i32>>: Send, Sync);
+    #[cfg(feature = "std")]
+    assert_impl_all!(HarrisList<Cell<i32>>: Send);
+    #[cfg(feature = "std")]
+    assert_not_impl_any!(HarrisList<Cell<i32>>: Sync);
+    #[cfg(feature = "std")]
+    assert_not_impl_any!(MsQueue<Rc<i32>>: Send, Sync);
+
+    /// Test that raw-pointer lists can be moved to and read from other threads.
+    #[test]
+    fn test_raw_pointer_lists_cross_threads() {
+        let mut xor = XorLinkedList::new();
+        let mut circular = CircularDoublyLinkedList::new();
+        for value in 1..=3 {
+            xor.insert(value);
+            circular.insert(value);
+        }
+        let sum = thread::spawn(move || xor.iter().sum::<i32>())
+            .join()
+            .unwrap();
+        assert_eq!(sum, 6); // Moved into another thread.
+        thread::scope(|scope| {
+            let first = scope.spawn(|| circular.iter().next().copied());
+            let last = scope.spawn(|| circular.iter().next_back().copied());
+            assert_eq!(first.join().unwrap(), Some(1)); // Shared between threads.
+            assert_eq!(last.join().unwrap(), Some(3));
+        });
+    }
+}
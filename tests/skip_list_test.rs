@@ -0,0 +1,61 @@
+// skip_list_test.rs
+// This file contains unit tests for the IndexedSkipList implementation.
+// It tests positional operations on a list large enough to build tall towers.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod skip_list_tests {
+    use linked_list_impls::skip_list::IndexedSkipList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Test positional insertion and access against a `Vec`.
+    #[test]
+    fn test_positional_operations_match_vec() {
+        let mut list = IndexedSkipList::new();
+        let mut model = Vec::new();
+        for value in 0..2000 {
+            let index = (value * 7919) % (model.len() + 1);
+            list.insert_at_index(index, value).unwrap();
+            model.insert(index, value);
+        }
+        for index in (0..model.len()).step_by(37) {
+            assert_eq!(list.get(index), Some(&model[index]));
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), model);
+        assert_eq!(list.get(model.len()), None);
+    }
+
+    /// Test deleting and updating by index.
+    #[test]
+    fn test_delete_and_update_at_index() {
+        let mut list = IndexedSkipList::new();
+        let mut model: Vec<usize> = (0..500).collect();
+        for &value in &model {
+            list.push_back(value);
+        }
+        for step in 0..250 {
+            let index = (step * 31) % model.len();
+            assert_eq!(list.remove(index), Some(model.remove(index)));
+        }
+        list.update_element_at_index(10, 9999).unwrap();
+        model[10] = 9999;
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), model);
+        assert_eq!(list.len(), 250);
+        assert!(list.delete_at_index(250).is_err());
+    }
+
+    /// Test value-based operations.
+    #[test]
+    fn test_value_operations() {
+        let mut list = IndexedSkipList::new();
+        for value in ["a", "b", "c"] {
+            list.insert(value);
+        }
+        assert!(list.find(&"b"));
+        assert!(list.update_element("b", "B"));
+        assert!(list.delete_element("a"));
+        assert!(!list.delete_element("a"));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec!["B", "c"]);
+    }
+}
@@ -0,0 +1,72 @@
+// slab_linked_list_test.rs
+// This file contains unit tests for the SlabLinkedList implementation.
+// It tests key-based access and the detection of stale keys.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod slab_linked_list_tests {
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Collects the list's contents front to back.
+    fn contents(list: &SlabLinkedList<i32>) -> Vec<i32> {
+        list.iter().map(|(_, &value)| value).collect()
+    }
+
+    /// Test that keys stay valid across unrelated insertions and removals.
+    #[test]
+    fn test_keys_are_stable() {
+        let mut list = SlabLinkedList::new();
+        let a = list.push_back(1);
+        let b = list.push_back(2);
+        list.push_front(0);
+        let c = list.insert_after(b, 3).unwrap();
+        assert_eq!(list.remove(a), Some(1));
+        assert_eq!(list.get(b), Some(&2));
+        assert_eq!(list.get(c), Some(&3));
+        assert_eq!(contents(&list), vec![0, 2, 3]);
+    }
+
+    /// Test that keys of removed elements are detected even after slot reuse.
+    #[test]
+    fn test_stale_keys_are_detected() {
+        let mut list = SlabLinkedList::new();
+        let old = list.push_back(1);
+        list.remove(old);
+        let new = list.push_back(2); // Reuses the freed slot.
+        assert_ne!(old, new);
+        assert!(!list.contains_key(old));
+        assert_eq!(list.get(old), None);
+        assert_eq!(list.remove(old), None);
+        assert!(list.insert_after(old, 9).is_err());
+        assert_eq!(list.get(new), Some(&2)); // The stale key did not alias the new element.
+    }
+
+    /// Test mutating an element through its key.
+    #[test]
+    fn test_get_mut() {
+        let mut list = SlabLinkedList::new();
+        let key = list.push_back(5);
+        *list.get_mut(key).unwrap() *= 10;
+        assert_eq!(list.get(key), Some(&50));
+        assert_eq!(list.key_at(0), Some(key));
+    }
+
+    /// Test the positional trait operations.
+    #[test]
+    fn test_trait_operations() {
+        let mut list = SlabLinkedList::new();
+        for value in [1, 3] {
+            list.insert(value);
+        }
+        list.insert_at_index(1, 2).unwrap();
+        assert!(list.insert_at_index(4, 0).is_err());
+        assert!(list.delete_element(&1));
+        list.update_element_at_index(1, 30).unwrap();
+        assert!(list.update_element(&2, 20));
+        list.delete_at_index(1).unwrap();
+        assert_eq!(contents(&list), vec![20]);
+        assert_eq!(ListRead::get(&list, 1), None);
+    }
+}
@@ -0,0 +1,49 @@
+// slice_eq_test.rs
+// This file contains unit tests for comparing the lists with slices, arrays and vectors.
+// It tests equal and unequal contents, lists of different lengths, and mixed element types.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod slice_eq_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test comparing a dynamic list with each kind of sequence.
+    #[test]
+    fn test_equal_contents() {
+        let mut list = DynamicLinkedList::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, &[1, 2, 3][..]);
+        assert!(list == *[1, 2, 3].as_slice()); // An unsized slice.
+        assert_ne!(list, [1, 3, 2]);
+    }
+
+    /// Test that a list only equals a sequence of the same length.
+    #[test]
+    fn test_lengths_differ() {
+        let mut list: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        assert_eq!(list, [0_i32; 0]); // An empty array needs its type spelled out.
+        list.insert(1);
+        list.insert(2);
+        assert_ne!(list, [1]); // A prefix is not equal.
+        assert_ne!(list, [1, 2, 3]);
+        assert_eq!(list, vec![1, 2]);
+    }
+
+    /// Test comparing elements of different types.
+    #[test]
+    fn test_mixed_types() {
+        let mut list = DoublyLinkedList::new();
+        list.insert(String::from("a"));
+        list.insert(String::from("b"));
+        assert_eq!(list, ["a", "b"]); // `String: PartialEq<&str>`.
+        assert_ne!(list, vec!["a", "c"]);
+    }
+}
@@ -0,0 +1,54 @@
+// small_list_test.rs
+// This file contains unit tests for the SmallList implementation.
+// It tests inline storage and the transparent spill to the heap.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod small_list_tests {
+    use linked_list_impls::small_list::SmallList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Test that up to N elements stay inline.
+    #[test]
+    fn test_stays_inline_up_to_capacity() {
+        let mut list: SmallList<i32, 3> = SmallList::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        assert!(list.is_inline());
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.get(2), Some(&3));
+    }
+
+    /// Test that inserting past capacity spills to the heap and keeps the order.
+    #[test]
+    fn test_spills_to_heap() {
+        let mut list: SmallList<i32, 2> = SmallList::new();
+        list.insert(1);
+        list.insert(3);
+        list.insert_at_index(1, 2).unwrap(); // Spills.
+        assert!(!list.is_inline());
+        list.insert(4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.len(), 4);
+    }
+
+    /// Test deletion and updates in both storage modes.
+    #[test]
+    fn test_delete_and_update() {
+        let mut list: SmallList<i32, 2> = SmallList::new();
+        list.insert(1);
+        assert!(list.update_element(&1, 10));
+        assert!(list.delete_element(&10));
+        assert!(list.is_empty());
+        for value in 0..5 {
+            list.insert(value);
+        }
+        list.delete_at_index(0).unwrap();
+        list.update_element_at_index(0, 100).unwrap();
+        assert!(list.find(&100));
+        assert!(list.delete_at_index(4).is_err()); // Only 4 elements remain.
+        assert_eq!(list.len(), 4);
+    }
+}
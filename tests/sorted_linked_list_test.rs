@@ -0,0 +1,64 @@
+// sorted_linked_list_test.rs
+// This file contains unit tests for the SortedLinkedList implementation.
+// It tests ordered insertion, early-exit lookups, and range queries over both backing stores.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod sorted_linked_list_tests {
+    use linked_list_impls::sorted_linked_list::SortedLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+
+    /// Test that insertion keeps the elements in ascending order.
+    #[test]
+    fn test_insert_keeps_order() {
+        let mut list = SortedLinkedList::new();
+        for value in [5, 1, 4, 1, 3] {
+            list.insert(value).unwrap();
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 1, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.get(4), Some(&5));
+    }
+
+    /// Test contains and remove.
+    #[test]
+    fn test_contains_and_remove() {
+        let mut list = SortedLinkedList::new();
+        for value in [10, 30, 20] {
+            list.insert(value).unwrap();
+        }
+        assert!(list.contains(&20));
+        assert!(!list.contains(&25)); // Stops at 30 without reaching the end.
+        assert!(list.remove(&20));
+        assert!(!list.remove(&20));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 30]);
+        assert_eq!(list.len(), 2);
+    }
+
+    /// Test range queries with different bound kinds.
+    #[test]
+    fn test_range() {
+        let mut list = SortedLinkedList::new();
+        for value in (0..10).rev() {
+            list.insert(value).unwrap();
+        }
+        assert_eq!(list.range(3..6).copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(list.range(..=2).copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(list.range(8..).copied().collect::<Vec<_>>(), vec![8, 9]);
+        assert_eq!(list.range(20..).count(), 0);
+    }
+
+    /// Test a sorted list backed by a fixed-capacity static list.
+    #[test]
+    fn test_static_backing() {
+        let mut list = SortedLinkedList::<i32, StaticLinkedList<i32, 3>>::empty();
+        for value in [3, 1, 2] {
+            list.insert(value).unwrap();
+        }
+        assert!(list.insert(0).is_err()); // The backing list is full.
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+    }
+}
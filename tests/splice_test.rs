@@ -0,0 +1,98 @@
+// splice_test.rs
+// This file contains unit tests for splicing DynamicLinkedList and StaticLinkedList.
+// It tests replacing ranges with another list's elements and rejecting invalid ranges.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod splice_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{dynamic_list, static_list, LinkedListError, ListRead};
+    use std::ops::Bound;
+
+    /// Test replacing a middle range, an open range, and an empty range of a dynamic list.
+    #[test]
+    fn test_dynamic_splice() {
+        let mut list = dynamic_list![0, 1, 2, 3, 4];
+        let removed = list.splice(1..3, dynamic_list![7, 8, 9]).unwrap();
+        assert_eq!(removed.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![0, 7, 8, 9, 3, 4]
+        );
+
+        let removed = list.splice(4.., DynamicLinkedList::new()).unwrap();
+        assert_eq!(removed.iter().copied().collect::<Vec<_>>(), vec![3, 4]);
+
+        list.splice(0..0, dynamic_list![-1]).unwrap(); // Pure insertion.
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![-1, 0, 7, 8, 9]
+        );
+    }
+
+    /// Test that invalid ranges leave a dynamic list unchanged.
+    #[test]
+    fn test_dynamic_splice_out_of_bounds() {
+        let mut list = dynamic_list![0, 1, 2];
+        assert!(list.splice(2..5, dynamic_list![9]).is_err()); // Runs past the end.
+        assert!(list.splice(4.., dynamic_list![9]).is_err()); // Starts past the end.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert!(list.splice(3.., dynamic_list![3]).is_ok()); // Appends at the end.
+        assert_eq!(list.get(3), Some(&3));
+    }
+
+    /// Test that bounds at `usize::MAX` are rejected on a dynamic list instead of overflowing.
+    #[test]
+    fn test_dynamic_splice_max_bounds() {
+        let mut list = dynamic_list![0, 1];
+        let overflow = LinkedListError::IndexOutOfBounds {
+            index: usize::MAX,
+            len: 2,
+        };
+        assert_eq!(
+            list.splice(..=usize::MAX, dynamic_list![9]).err(),
+            Some(overflow)
+        );
+        let after_max = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+        assert_eq!(
+            list.splice(after_max, dynamic_list![9]).err(),
+            Some(overflow)
+        );
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]); // Unchanged.
+    }
+
+    /// Test replacing ranges of a static list and rejecting results that would not fit.
+    #[test]
+    fn test_static_splice() {
+        let mut list = static_list![i32; 4 => 0, 1, 2, 3];
+        let removed = list.splice(1..=2, static_list![i32; 4 => 8, 9]).unwrap();
+        assert_eq!(removed.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 8, 9, 3]);
+
+        assert!(list.splice(0..0, static_list![i32; 4 => 5]).is_err()); // Would need a fifth slot.
+        assert!(list.splice(3..5, StaticLinkedList::new()).is_err()); // Runs past the end.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 8, 9, 3]);
+    }
+
+    /// Test that bounds at `usize::MAX` are rejected on a static list instead of overflowing.
+    #[test]
+    fn test_static_splice_max_bounds() {
+        let mut list = static_list![i32; 4 => 0, 1];
+        let overflow = LinkedListError::IndexOutOfBounds {
+            index: usize::MAX,
+            len: 2,
+        };
+        assert_eq!(
+            list.splice(..=usize::MAX, StaticLinkedList::new()).err(),
+            Some(overflow)
+        );
+        let after_max = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+        assert_eq!(
+            list.splice(after_max, StaticLinkedList::new()).err(),
+            Some(overflow)
+        );
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]); // Unchanged.
+    }
+}
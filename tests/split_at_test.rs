@@ -0,0 +1,62 @@
+// split_at_test.rs
+// This file contains unit tests for split_at on the dynamic and static lists.
+// It tests splitting in the middle and at both ends, and an index past the end.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod split_at_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test splitting a dynamic list and appending to both halves.
+    #[test]
+    fn test_dynamic_split() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..5 {
+            list.insert(value);
+        }
+        let (mut front, mut back) = list.split_at(2).unwrap();
+        front.insert(10); // The front's tail is the severed node.
+        back.insert(11);
+        assert_eq!(front.iter().copied().collect::<Vec<_>>(), vec![0, 1, 10]);
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 11]);
+    }
+
+    /// Test splitting a static list, whose back half moves to new slots.
+    #[test]
+    fn test_static_split() {
+        let mut list: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        for value in [1, 2, 3, 4] {
+            list.insert(value);
+        }
+        let (mut front, back) = list.split_at(1).unwrap();
+        assert_eq!(front.iter().copied().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(front.remaining_capacity(), 3); // The moved slots were freed.
+        front.insert(5);
+        assert_eq!(front.iter().copied().collect::<Vec<_>>(), vec![1, 5]);
+    }
+
+    /// Test splitting at both ends and past the end.
+    #[test]
+    fn test_split_bounds() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..3 {
+            list.insert(value);
+        }
+        let (front, list) = list.split_at(0).unwrap();
+        assert!(front.is_empty());
+        let (list, back) = list.split_at(3).unwrap();
+        assert!(back.is_empty());
+        let list = list.split_at(4).unwrap_err(); // Handed back unchanged.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        let mut fixed: StaticLinkedList<i32, 2> = StaticLinkedList::new();
+        fixed.insert(1);
+        let fixed = fixed.split_at(2).unwrap_err();
+        let (front, back) = fixed.split_at(1).unwrap();
+        assert_eq!((front.len(), back.len()), (1, 0));
+    }
+}
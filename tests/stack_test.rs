@@ -0,0 +1,52 @@
+// stack_test.rs
+// This file contains unit tests for the Stack and BoundedStack adapters.
+// It tests LIFO behavior and the typed overflow error of the bounded stack.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod stack_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::stack::{BoundedStack, Stack, StackOverflow};
+
+    /// Test LIFO order on the default dynamic backend.
+    #[test]
+    fn test_lifo_order() {
+        let mut stack = Stack::new();
+        for value in 1..=3 {
+            stack.push(value).unwrap();
+        }
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None); // The stack is empty.
+    }
+
+    /// Test a stack over the doubly linked list backend.
+    #[test]
+    fn test_doubly_backend() {
+        let mut stack = Stack::<char, DoublyLinkedList<char>>::empty();
+        stack.push('a').unwrap();
+        stack.push('b').unwrap();
+        assert_eq!(stack.pop(), Some('b'));
+        assert_eq!(stack.peek(), Some(&'a'));
+    }
+
+    /// Test that a full bounded stack returns the rejected value.
+    #[test]
+    fn test_bounded_overflow() {
+        let mut stack: BoundedStack<i32, 2> = BoundedStack::new();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert!(stack.is_full());
+        let error = stack.push(3).unwrap_err();
+        assert_eq!(error, StackOverflow { data: 3, capacity: 2 });
+        assert_eq!(error.to_string(), "stack overflow: capacity of 2 reached");
+        assert_eq!(stack.pop(), Some(2));
+        stack.push(3).unwrap(); // There is room again.
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.capacity(), 2);
+    }
+}
@@ -0,0 +1,60 @@
+// static_compact_test.rs
+// This file contains unit tests for StaticLinkedList::compact.
+// It tests that compacting keeps the order, packs the slots, and leaves a list that keeps working.
+
+#[cfg(test)]
+mod static_compact_tests {
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Collects the list's values front to back.
+    fn values<const N: usize>(list: &StaticLinkedList<i32, N>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    /// Test that compacting a churned list keeps the elements in order.
+    #[test]
+    fn test_compact_keeps_order() {
+        let mut list = StaticLinkedList::<i32, 6>::new();
+        for value in 0..6 {
+            list.insert(value);
+        }
+        list.delete_at_index(0).unwrap();
+        list.delete_at_index(2).unwrap();
+        list.insert_at_index(0, 10).unwrap(); // Reuses a freed slot out of order.
+        let before = values(&list);
+        list.compact();
+        assert_eq!(values(&list), before);
+        assert_eq!(values(&list), vec![10, 1, 2, 4, 5]);
+    }
+
+    /// Test that the nodes end up in the lowest slots, in list order.
+    #[test]
+    fn test_compact_packs_slots() {
+        let mut list = StaticLinkedList::<i32, 3>::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        list.delete_at_index(0).unwrap(); // Frees slot 0.
+        list.compact();
+        let debug = format!("{:?}", list);
+        assert!(debug.contains(
+            "nodes: [Some(Node { data: 2, next: Some(1) }), Some(Node { data: 3, next: None }), None]"
+        )); // Element `i` sits in slot `i`.
+    }
+
+    /// Test that a compacted list keeps working, including an empty one.
+    #[test]
+    fn test_list_works_after_compact() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.compact(); // Compacting an empty list is a no-op.
+        list.insert(1);
+        list.insert(2);
+        list.delete_at_index(0).unwrap();
+        list.compact();
+        list.insert(3); // Appends after the new tail.
+        list.insert_at_index(0, 0).unwrap();
+        assert_eq!(values(&list), vec![0, 2, 3]);
+        assert_eq!(list.len(), 3);
+    }
+}
@@ -0,0 +1,50 @@
+// static_const_test.rs
+// This file contains unit tests for building a StaticLinkedList at compile time.
+// It tests lists created in `const` and `static` items, and the const accessors.
+
+#[cfg(test)]
+mod static_const_tests {
+    use linked_list_impls::heapless_compat::Vec;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    const EMPTY: StaticLinkedList<u32, 4> = StaticLinkedList::new();
+    static LIST: StaticLinkedList<u32, 8> = StaticLinkedList::new();
+    const CAPACITY: usize = EMPTY.capacity();
+
+    /// Test that a list in a `static` is built at compile time and starts empty.
+    #[test]
+    fn test_list_in_static() {
+        assert!(LIST.is_empty());
+        assert_eq!(LIST.remaining_capacity(), 8);
+        assert_eq!(LIST.iter().count(), 0);
+        assert_eq!(CAPACITY, 4); // Evaluated in a const context.
+    }
+
+    /// Test that each use of a `const` list is a fresh, working list.
+    #[test]
+    fn test_list_from_const() {
+        let mut list = EMPTY;
+        for value in 0..4 {
+            list.insert(value);
+        }
+        assert!(list.is_full());
+        list.delete_at_index(0).unwrap();
+        list.insert(4); // The freed slot is reused.
+        assert_eq!(
+            list.iter().copied().collect::<std::vec::Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert!(EMPTY.is_empty()); // The constant itself is untouched.
+    }
+
+    /// Test that the heapless-style vector can be built in a const context too.
+    #[test]
+    fn test_const_heapless_vec() {
+        const VEC: Vec<&str, 2> = Vec::new();
+        let mut vec = VEC;
+        vec.push("a").unwrap();
+        vec.push("b").unwrap();
+        assert_eq!(vec.push("c"), Err("c"));
+    }
+}
@@ -1,5 +1,3 @@
-use std::mem::MaybeUninit;
-
 /// A static, bounded linked list implementation using a fixed-size array of `Option<T>`.
 /// 
 /// This list is useful when the maximum number of elements (`N`) is known at compile-time.
@@ -16,18 +14,10 @@ impl<T, const N: usize> StaticLinkedList<T, N> {
     /// # Returns
     /// A new instance of the list with all slots initialized to `None`.
     pub fn new() -> Self {
-        let mut nodes: [MaybeUninit<Option<T>>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-    
-        for elem in &mut nodes {
-            elem.write(None);
+        StaticLinkedList {
+            nodes: std::array::from_fn(|_| None),
+            size: 0,
         }
-    
-        let nodes = unsafe {
-            // SAFELY transmute only after all elements have been initialized
-            std::ptr::read(&nodes as *const _ as *const [Option<T>; N])
-        };
-    
-        StaticLinkedList { nodes, size: 0 }
     }
 
     /// Inserts a new element at the end of the list.
@@ -193,3 +183,52 @@ impl<T, const N: usize> Default for StaticLinkedList<T, N> {
         Self::new()
     }
 }
+
+
+#[cfg(test)]
+mod static_linked_list_tests {
+    use super::StaticLinkedList;
+
+    /// Test that a new list starts empty, including for non-`Copy` element types.
+    #[test]
+    fn test_new_is_empty() {
+        let list: StaticLinkedList<String, 4> = StaticLinkedList::new();
+        assert_eq!(list.get(0), None); // No slot should be populated.
+    }
+
+    /// Test inserting until the list reaches its capacity.
+    #[test]
+    fn test_insert_until_full() {
+        let mut list: StaticLinkedList<i32, 2> = StaticLinkedList::new();
+        assert!(list.insert(1).is_ok());
+        assert!(list.insert(2).is_ok());
+        assert!(list.insert(3).is_err()); // Third insert exceeds capacity.
+        assert_eq!(list.get(1), Some(&2));
+    }
+
+    /// Test inserting and deleting in the middle of the list shifts elements correctly.
+    #[test]
+    fn test_insert_and_delete_at_index() {
+        let mut list: StaticLinkedList<String, 4> = StaticLinkedList::default();
+        list.insert("a".to_string()).unwrap();
+        list.insert("c".to_string()).unwrap();
+        list.insert_at_index(1, "b".to_string()).unwrap();
+        assert_eq!(list.get(1).map(String::as_str), Some("b"));
+        list.delete_at_index(0).unwrap();
+        assert_eq!(list.get(0).map(String::as_str), Some("b")); // Elements shifted left.
+        assert!(list.delete_element("c".to_string()));
+        assert_eq!(list.get(1), None);
+    }
+
+    /// Test updating, searching, and out-of-bounds updates.
+    #[test]
+    fn test_update_and_find() {
+        let mut list: StaticLinkedList<i32, 3> = StaticLinkedList::new();
+        list.insert(1).unwrap();
+        assert!(list.update_element(1, 5));
+        assert!(list.find(&5));
+        assert!(!list.find(&1));
+        assert!(list.update_element_at_index(0, 6).is_ok());
+        assert!(list.update_element_at_index(1, 7).is_err()); // Index 1 is not occupied.
+    }
+}
@@ -0,0 +1,64 @@
+// static_no_alloc_test.rs
+// This file contains unit tests for the allocation-free storage of StaticLinkedList.
+// It tests that freed slots are reused, that compacting in place keeps the order after heavy churn, and iter_mut.
+
+#[cfg(test)]
+mod static_no_alloc_tests {
+    use std::mem;
+
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that the list's footprint is just its own size, and that freed slots are reused.
+    #[test]
+    fn test_no_heap_and_slot_reuse() {
+        let mut list = StaticLinkedList::<u32, 4>::new();
+        for round in 0..100 {
+            list.insert(round);
+            list.insert(round + 1);
+            list.delete_at_index(0).unwrap();
+            list.delete_at_index(0).unwrap();
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.remaining_capacity(), 4); // Every freed slot went back on the stack.
+        let size = mem::size_of::<StaticLinkedList<u32, 4>>();
+        assert_eq!(list.memory_footprint().total_bytes, size);
+    }
+
+    /// Test that compacting a thoroughly shuffled list keeps the order and packs the slots.
+    #[test]
+    fn test_compact_after_churn() {
+        let mut list = StaticLinkedList::<u32, 16>::new();
+        let mut model = Vec::new();
+        let mut seed = 7u32;
+        for step in 0..200 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let index = (seed >> 16) as usize % (model.len() + 1);
+            if model.len() < 16 && !seed.is_multiple_of(3) {
+                list.insert_at_index(index, step).unwrap();
+                model.insert(index, step);
+            } else if index < model.len() {
+                list.delete_at_index(index).unwrap();
+                model.remove(index);
+            }
+            if step.is_multiple_of(25) {
+                list.compact();
+                assert_eq!(list.locality().adjacent, model.len().saturating_sub(1));
+            }
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), model);
+        }
+    }
+
+    /// Test that iter_mut visits the elements in list order, not slot order.
+    #[test]
+    fn test_iter_mut_follows_links() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.insert(1);
+        list.insert(2);
+        list.insert_at_index(0, 0).unwrap(); // Slot 2 now holds the first element.
+        for (offset, value) in list.iter_mut().enumerate() {
+            *value += offset as i32 * 10;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 11, 22]);
+    }
+}
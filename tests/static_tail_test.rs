@@ -0,0 +1,63 @@
+// static_tail_test.rs
+// This file contains unit tests for the tail slot of StaticLinkedList.
+// It tests that appends land at the end after deleting, inserting at the end, and cursor edits.
+
+#[cfg(test)]
+mod static_tail_tests {
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Collects the list's values front to back.
+    fn values<const N: usize>(list: &StaticLinkedList<i32, N>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    /// Test that deleting the last element moves the tail back to the element before it.
+    #[test]
+    fn test_append_after_deleting_tail() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        assert!(list.delete_element(&3)); // Deleting the tail by value.
+        list.insert(4);
+        assert_eq!(values(&list), vec![1, 2, 4]);
+        list.delete_at_index(2).unwrap(); // Deleting the tail by index.
+        list.insert(5);
+        assert_eq!(values(&list), vec![1, 2, 5]);
+        while list.pop_front().is_some() {}
+        list.insert(6); // The tail of an emptied list is reset.
+        assert_eq!(values(&list), vec![6]);
+    }
+
+    /// Test that inserting at the end by index moves the tail forward.
+    #[test]
+    fn test_insert_at_end_moves_tail() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.insert_at_index(0, 1).unwrap(); // The first element is also the tail.
+        list.insert_at_index(1, 2).unwrap();
+        list.insert(3);
+        assert_eq!(values(&list), vec![1, 2, 3]);
+    }
+
+    /// Test that cursor edits at the end keep the tail up to date.
+    #[test]
+    fn test_append_after_cursor_edits() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(3)); // Removing the tail with a cursor.
+        list.insert(4);
+        assert_eq!(values(&list), vec![1, 2, 4]);
+
+        let mut cursor = list.cursor_mut();
+        let rest = cursor.split_after(); // The first element becomes the tail.
+        list.insert(5);
+        assert_eq!(values(&list), vec![1, 5]);
+        assert_eq!(values(&rest), vec![2, 4]);
+    }
+}
@@ -0,0 +1,75 @@
+// std_compat_test.rs
+// This file contains unit tests for the std::collections::LinkedList front of DoublyLinkedList.
+// It tests that it behaves like the standard list, splitting and appending, and its trait impls.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod std_compat_tests {
+    use linked_list_impls::std_compat::LinkedList;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    /// Hashes a value with the standard hasher.
+    fn hash_of<H: Hash>(value: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Test the same operations against this list and the standard one.
+    #[test]
+    fn test_matches_std() {
+        let mut ours = LinkedList::new();
+        let mut theirs = std::collections::LinkedList::new();
+        for value in 0..4 {
+            ours.push_back(value);
+            theirs.push_back(value);
+            ours.push_front(-value);
+            theirs.push_front(-value);
+        }
+        *ours.front_mut().unwrap() += 10;
+        *theirs.front_mut().unwrap() += 10;
+        *ours.back_mut().unwrap() *= 2;
+        *theirs.back_mut().unwrap() *= 2;
+        for item in &mut ours {
+            *item += 1;
+        }
+        for item in &mut theirs {
+            *item += 1;
+        }
+        assert!(ours.iter().eq(theirs.iter()));
+        assert_eq!(ours.contains(&7), theirs.contains(&7));
+        assert_eq!(ours.pop_back(), theirs.pop_back());
+        assert_eq!(ours.pop_front(), theirs.pop_front());
+        assert!(ours.into_iter().rev().eq(theirs.into_iter().rev())); // Owned, from the back.
+    }
+
+    /// Test splitting off a tail and appending it back.
+    #[test]
+    fn test_split_off_and_append() {
+        let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+        let mut back = list.split_off(2);
+        assert_eq!(format!("{:?}", list), "[1, 2]");
+        assert_eq!(format!("{:?}", back), "[3, 4, 5]");
+        assert!(list.split_off(2).is_empty());
+        list.append(&mut back);
+        assert!(back.is_empty()); // Appending drains the other list.
+        assert_eq!(list, LinkedList::from([1, 2, 3, 4, 5]));
+        list.clear();
+        assert_eq!(list.len(), 0);
+    }
+
+    /// Test the comparison, hashing, cloning and collecting impls.
+    #[test]
+    fn test_trait_impls() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let mut other = list.clone();
+        assert_eq!(hash_of(&list), hash_of(&other));
+        other.extend(&[4]);
+        assert!(list < other);
+        assert_ne!(hash_of(&list), hash_of(&other));
+        assert_eq!(other.iter().len(), 4);
+        assert_eq!(LinkedList::<i32>::default(), LinkedList::new());
+    }
+}
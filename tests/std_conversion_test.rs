@@ -0,0 +1,55 @@
+// std_conversion_test.rs
+// This file contains unit tests for the conversions between the lists and the std containers.
+// It tests both directions for LinkedList and VecDeque, and the capacity check into StaticLinkedList.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod std_conversion_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+    use std::collections::{LinkedList, VecDeque};
+
+    /// Test moving a dynamic list to and from both std containers.
+    #[test]
+    fn test_dynamic_round_trip() {
+        let deque = VecDeque::from([1, 2, 3]);
+        let mut list = DynamicLinkedList::from(deque);
+        list.insert(4); // Still a working list.
+        let std_list: LinkedList<i32> = list.into();
+        assert_eq!(std_list, LinkedList::from([1, 2, 3, 4]));
+        let list = DynamicLinkedList::from(std_list);
+        let deque: VecDeque<i32> = list.into();
+        assert_eq!(deque, VecDeque::from([1, 2, 3, 4]));
+    }
+
+    /// Test moving a static list to and from both std containers.
+    #[test]
+    fn test_static_round_trip() {
+        let mut list = StaticLinkedList::<String, 3>::try_from(LinkedList::from([
+            "a".to_string(),
+            "b".to_string(),
+        ]))
+        .unwrap();
+        list.insert_at_index(0, "z".to_string()).unwrap(); // Out of slot order.
+        let deque = VecDeque::from(list);
+        assert_eq!(deque, ["z", "a", "b"].map(String::from).to_vec());
+        let list = StaticLinkedList::<String, 3>::try_from(deque).unwrap();
+        assert!(list.is_full());
+        let std_list = LinkedList::from(list);
+        assert_eq!(std_list.front().map(String::as_str), Some("z"));
+    }
+
+    /// Test that a container too long for the capacity is handed back unchanged.
+    #[test]
+    fn test_static_too_long() {
+        let deque = VecDeque::from([1, 2, 3]);
+        let deque = StaticLinkedList::<i32, 2>::try_from(deque).unwrap_err();
+        assert_eq!(deque, [1, 2, 3]);
+        let std_list = LinkedList::from([1, 2, 3]);
+        let std_list = StaticLinkedList::<i32, 2>::try_from(std_list).unwrap_err();
+        assert_eq!(std_list.len(), 3);
+        assert!(StaticLinkedList::<i32, 3>::try_from(std_list).is_ok()); // Exactly `N` fit.
+    }
+}
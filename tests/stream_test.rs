@@ -0,0 +1,65 @@
+// stream_test.rs
+// This file contains unit tests for the async Stream adapters.
+// Run with `cargo test --features futures`.
+
+#![cfg(feature = "futures")]
+
+#[cfg(test)]
+mod stream_tests {
+    use std::thread;
+
+    use futures::executor::block_on;
+    use futures::StreamExt;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::stream;
+    use linked_list_impls::ListWrite;
+
+    /// Test streaming the elements of a list.
+    #[test]
+    fn test_iter_stream() {
+        let mut list = DynamicLinkedList::new();
+        for value in 1..=3 {
+            list.insert(value);
+        }
+        let items: Vec<i32> = block_on(stream::iter(&list).map(|x| *x).collect());
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    /// Test that the queue stream yields pushed elements and ends when producers leave.
+    #[test]
+    fn test_channel_ends_after_producers_drop() {
+        let (producer, mut stream) = stream::channel();
+        producer.push(1);
+        producer.push(2);
+        drop(producer);
+        block_on(async {
+            assert_eq!(stream.next().await, Some(1));
+            assert_eq!(stream.next().await, Some(2));
+            assert_eq!(stream.next().await, None); // No producers left.
+        });
+    }
+
+    /// Test an async consumer waiting on producers running on other threads.
+    #[test]
+    fn test_channel_with_threaded_producers() {
+        let (producer, stream) = stream::channel();
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let producer = producer.clone();
+                thread::spawn(move || {
+                    for i in 0..250 {
+                        producer.push(t * 250 + i);
+                    }
+                })
+            })
+            .collect();
+        drop(producer);
+
+        let mut items: Vec<i32> = block_on(stream.collect());
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        items.sort_unstable();
+        assert_eq!(items, (0..1000).collect::<Vec<_>>()); // Every element arrives once.
+    }
+}
@@ -0,0 +1,59 @@
+// strict_invariants_test.rs
+// This file contains unit tests for the strict-invariants feature.
+// It runs every kind of structural change with the checks on, which panic on corruption.
+#![cfg(all(feature = "strict-invariants", debug_assertions))]
+
+#[cfg(test)]
+mod strict_invariants_tests {
+    use linked_list_impls::slab_linked_list::SlabLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+
+    /// Test that the index-based operations of a static list pass the checks.
+    #[test]
+    fn test_static_list_operations() {
+        let mut list: StaticLinkedList<u32, 6> = StaticLinkedList::new();
+        for value in 0..6 {
+            list.insert_at_index(value as usize / 2, value).unwrap();
+        }
+        list.insert(6); // Rejected by the full list, which stays valid.
+        assert!(list.delete_element(&3));
+        list.delete_at_index(4).unwrap();
+        list.insert_many_at(1, [7, 8]).unwrap();
+        list.compact();
+        assert_eq!(list.len(), 6);
+    }
+
+    /// Test that splicing and cursor edits of a static list pass the checks.
+    #[test]
+    fn test_static_list_splice_and_cursor() {
+        let mut list: StaticLinkedList<u32, 6> = StaticLinkedList::new();
+        let mut other = StaticLinkedList::new();
+        for value in 0..4 {
+            list.insert(value);
+            other.insert(value + 10);
+        }
+        let removed = list.splice(1..3, other).unwrap();
+        assert_eq!(removed.len(), 2);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.remove_current();
+        cursor.insert_before(20).unwrap();
+        let split = cursor.split_after();
+        assert_eq!(list.len() + split.len(), 6);
+    }
+
+    /// Test that keyed and indexed operations of a slab list pass the checks.
+    #[test]
+    fn test_slab_list_operations() {
+        let mut list = SlabLinkedList::new();
+        let first = list.push_back(1);
+        let second = list.insert_after(first, 2).unwrap();
+        list.push_front(0);
+        list.remove(first);
+        list.insert_at_index(1, 5).unwrap();
+        list.remove(second);
+        list.push_back(3); // Reuses a freed slot.
+        assert_eq!(list.len(), 3);
+    }
+}
@@ -0,0 +1,66 @@
+// swap_remove_at_test.rs
+// This file contains unit tests for swap_remove_at on the dynamic and static lists.
+// It tests which element takes the removed one's place and the out-of-bounds error.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod swap_remove_at_tests {
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListError, ListWrite};
+
+    /// Test that the first element moves into the removed element's place.
+    #[test]
+    fn test_dynamic_swap_remove() {
+        let mut list = DynamicLinkedList::new();
+        for value in 0..5 {
+            list.insert(value);
+        }
+        assert_eq!(list.swap_remove_at(3), Ok(3));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 0, 4]);
+        assert_eq!(list.swap_remove_at(0), Ok(1)); // The head itself is just removed.
+        assert_eq!(list.swap_remove_at(2), Ok(4));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 2]);
+        list.insert(5); // The tail is still right.
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 2, 5]);
+    }
+
+    /// Test that a static list frees the head slot and reuses it.
+    #[test]
+    fn test_static_swap_remove() {
+        let mut list: StaticLinkedList<i32, 3> = StaticLinkedList::new();
+        for value in [10, 20, 30] {
+            list.insert(value);
+        }
+        assert_eq!(list.swap_remove_at(2), Ok(30));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![20, 10]);
+        assert_eq!(list.swap_remove_at(1), Ok(10));
+        assert_eq!(list.swap_remove_at(0), Ok(20));
+        assert!(list.is_empty());
+        for value in [1, 2, 3] {
+            list.insert(value); // Every slot was freed.
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    /// Test that an index past the end leaves the list unchanged.
+    #[test]
+    fn test_out_of_bounds() {
+        let mut dynamic = DynamicLinkedList::new();
+        let mut fixed: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        let error = LinkedListError::IndexOutOfBounds { index: 2, len: 2 };
+        for value in [1, 2] {
+            dynamic.insert(value);
+            fixed.insert(value);
+        }
+        assert_eq!(dynamic.swap_remove_at(2), Err(error));
+        assert_eq!(fixed.swap_remove_at(2), Err(error));
+        assert_eq!(
+            DynamicLinkedList::<i32>::new().swap_remove_at(0),
+            Err(LinkedListError::IndexOutOfBounds { index: 0, len: 0 })
+        );
+        assert_eq!(dynamic.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(fixed.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+}
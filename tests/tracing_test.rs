@@ -0,0 +1,146 @@
+// tracing_test.rs
+// This file contains unit tests for the `tracing` instrumentation of the lists.
+// It records the events and spans the lists emit with a small in-memory subscriber.
+#![cfg(feature = "tracing")]
+
+#[cfg(test)]
+mod tracing_tests {
+    use std::fmt::Debug;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::ListWrite;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Formats the fields of an event or span as `name=value`, separated by spaces.
+    #[derive(Default)]
+    struct Fields(String);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if !self.0.is_empty() {
+                self.0.push(' ');
+            }
+            self.0.push_str(&format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    /// A subscriber that keeps one line per event and per entered span.
+    #[derive(Clone, Default)]
+    struct Recorder {
+        lines: Arc<Mutex<Vec<String>>>,
+        spans: Arc<Mutex<Vec<String>>>,
+        next_id: Arc<AtomicU64>,
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields::default();
+            span.record(&mut fields);
+            let line = format!("span {} {}", span.metadata().name(), fields.0);
+            self.spans.lock().unwrap().push(line);
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            let line = format!("{} {}", event.metadata().level(), fields.0);
+            self.lines.lock().unwrap().push(line);
+        }
+
+        fn enter(&self, span: &Id) {
+            let name = self.spans.lock().unwrap()[span.into_u64() as usize - 1].clone();
+            self.lines.lock().unwrap().push(format!("enter {name}"));
+        }
+
+        fn exit(&self, _: &Id) {
+            self.lines.lock().unwrap().push("exit".to_string());
+        }
+    }
+
+    /// Runs `f` with a recorder installed and returns what it recorded.
+    fn record(f: impl FnOnce()) -> Vec<String> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), f);
+        let lines = recorder.lines.lock().unwrap().clone();
+        lines
+    }
+
+    /// Test that the dynamic list reports insertions, deletions and the length of its walks.
+    #[test]
+    fn test_dynamic_list_events() {
+        let mut list = DynamicLinkedList::new();
+        let lines = record(|| {
+            list.insert(1);
+            list.insert_at_index(1, 2).unwrap();
+            list.delete_at_index(0).unwrap();
+        });
+        assert_eq!(
+            lines,
+            vec![
+                "TRACE message=insert",
+                "TRACE message=allocate node",
+                "TRACE message=insert_at_index index=1",
+                "TRACE message=node_at index=0 walked=0",
+                "TRACE message=allocate node",
+                "TRACE message=delete_at_index index=0",
+            ]
+        ); // The freed node goes to the pool, so nothing is deallocated.
+    }
+
+    /// Test that the static list reports the index and length, and a full list.
+    #[test]
+    fn test_static_list_events() {
+        let mut list: StaticLinkedList<i32, 1> = StaticLinkedList::new();
+        let lines = record(|| {
+            list.insert(1);
+            list.insert(2);
+            list.delete_element(&1);
+        });
+        assert_eq!(
+            lines,
+            vec![
+                "TRACE message=push_back index=0 len=0",
+                "TRACE message=push_back index=1 len=1",
+                "DEBUG message=list is full capacity=1",
+                "TRACE message=delete_element len=1",
+            ]
+        );
+    }
+
+    /// Test that compaction and pool reservation run inside spans.
+    #[test]
+    fn test_structural_spans() {
+        let mut list: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        list.insert(1);
+        let mut dynamic: DynamicLinkedList<i32> = DynamicLinkedList::new();
+        let lines = record(|| {
+            list.compact();
+            dynamic.reserve(1);
+            dynamic.shrink_pool();
+        });
+        assert_eq!(
+            lines,
+            vec![
+                "enter span compact len=1 capacity=4",
+                "exit",
+                "enter span reserve additional=1 pooled=0",
+                "exit",
+                "DEBUG message=shrink_pool freed=1",
+            ]
+        );
+    }
+}
@@ -0,0 +1,61 @@
+// trait_bounds_test.rs
+// This file contains unit tests for using the lists with element types that are neither
+// Clone nor Debug. It tests positional operations without PartialEq and value-based ones with it.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod trait_bounds_tests {
+    use std::sync::{Mutex, MutexGuard};
+
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// A resource that implements none of the common derives.
+    struct Resource {
+        id: u32,
+    }
+
+    /// A value that can be compared but not cloned or printed.
+    #[derive(PartialEq)]
+    struct Token(u32);
+
+    /// Test positional operations on a dynamic list of a resource with no derives.
+    #[test]
+    fn test_dynamic_list_without_derives() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(Resource { id: 1 });
+        list.insert_at_index(0, Resource { id: 0 }).unwrap();
+        list.update_element_at_index(1, Resource { id: 2 }).unwrap();
+        assert_eq!(list.get(1).map(|resource| resource.id), Some(2));
+        list.delete_at_index(0).unwrap();
+        assert_eq!(list.get(0).map(|resource| resource.id), Some(2));
+    }
+
+    /// Test that a static list can hold lock guards, which can never be cloned.
+    #[test]
+    fn test_static_list_of_guards() {
+        let locks = [Mutex::new(1), Mutex::new(2)];
+        let mut list: StaticLinkedList<MutexGuard<'_, i32>, 2> = StaticLinkedList::new();
+        for lock in &locks {
+            assert!(list.try_insert(lock.lock().unwrap()).is_ok());
+        }
+        assert!(locks[0].try_lock().is_err()); // Held by the list.
+        **list.cursor_mut().current().unwrap() += 10;
+        list.delete_at_index(0).unwrap(); // Releases the first lock.
+        assert_eq!(*locks[0].lock().unwrap(), 11);
+    }
+
+    /// Test value-based operations with only `PartialEq`.
+    #[test]
+    fn test_value_operations_with_partial_eq_only() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(Token(1));
+        list.insert(Token(2));
+        assert!(list.find(&Token(2)));
+        assert!(list.update_element(&Token(2), Token(3)));
+        assert!(list.delete_element(&Token(1)));
+        assert!(list.get(0) == Some(&Token(3)));
+    }
+}
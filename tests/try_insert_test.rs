@@ -0,0 +1,90 @@
+// try_insert_test.rs
+// This file contains unit tests for ListWrite::try_insert.
+// It tests unbounded lists, a full static list and its capacity, and adapters over bounded lists.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod try_insert_tests {
+    use linked_list_impls::bounded_list::OverflowPolicy;
+    #[cfg(feature = "std")]
+    use linked_list_impls::concurrent_linked_list::ConcurrentLinkedList;
+    use linked_list_impls::counted::Counted;
+    use linked_list_impls::list_builder::ListBuilder;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{dynamic_list, static_list, LinkedListError, ListRead, ListWrite};
+
+    /// Test that an unbounded list always accepts the element.
+    #[test]
+    fn test_unbounded_list_accepts() {
+        let mut list = dynamic_list![1];
+        assert_eq!(list.try_insert(2), Ok(()));
+        assert_eq!(list.get(1), Some(&2));
+    }
+
+    /// Test that a full static list reports its capacity instead of dropping the element.
+    #[test]
+    fn test_static_list_reports_full() {
+        let mut list = static_list![i32; 2 => 1];
+        assert_eq!(list.try_insert(2), Ok(()));
+        assert_eq!(
+            list.try_insert(3),
+            Err(LinkedListError::CapacityExceeded { capacity: 2 })
+        );
+        assert_eq!(list.len(), 2); // Nothing was inserted.
+    }
+
+    /// Test that a static list reports its capacity before an insert would fail.
+    #[test]
+    fn test_static_list_capacity() {
+        let mut list = StaticLinkedList::<i32, 3>::new();
+        assert_eq!(list.capacity(), 3);
+        assert_eq!(list.remaining_capacity(), 3);
+        list.insert(1);
+        list.insert(2);
+        assert_eq!(list.remaining_capacity(), 1);
+        assert!(!list.is_full());
+        list.insert(3);
+        assert!(list.is_full()); // Checked up front instead of after a failed insert.
+        list.delete_at_index(0).unwrap();
+        assert_eq!(list.remaining_capacity(), 1);
+    }
+
+    /// Test that a full static list hands the rejected element back instead of printing.
+    #[test]
+    fn test_static_list_rejects_quietly() {
+        let mut list = StaticLinkedList::<String, 1>::new();
+        assert_eq!(list.push_back("a".to_string()), Ok(()));
+        assert_eq!(list.push_back("b".to_string()), Err("b".to_string())); // Handed back.
+        list.insert("c".to_string()); // Dropped without output.
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.get(0).map(String::as_str), Some("a"));
+    }
+
+    /// Test that adapters forward the error from the list they wrap.
+    #[test]
+    fn test_adapters_forward_errors() {
+        let mut counted = Counted::new(StaticLinkedList::<i32, 1>::new());
+        assert!(counted.try_insert(1).is_ok());
+        assert!(counted.try_insert(2).is_err());
+        assert_eq!(counted.len(), 1); // The rejected element was not counted.
+
+        #[cfg(feature = "std")]
+        {
+            let concurrent = ConcurrentLinkedList::<i32, StaticLinkedList<_, 1>>::empty();
+            assert!(concurrent.try_insert(1).is_ok());
+            assert!(concurrent.try_insert(2).is_err());
+        }
+
+        let mut bounded = ListBuilder::new()
+            .max_len(1)
+            .overflow(OverflowPolicy::Reject)
+            .build()
+            .unwrap();
+        assert!(bounded.try_insert(1).is_ok());
+        assert_eq!(
+            bounded.try_insert(2),
+            Err(LinkedListError::CapacityExceeded { capacity: 1 })
+        );
+    }
+}
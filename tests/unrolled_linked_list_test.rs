@@ -0,0 +1,77 @@
+// unrolled_linked_list_test.rs
+// This file contains unit tests for the UnrolledLinkedList implementation.
+// It tests the trait operations as well as node splitting and merging.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod unrolled_linked_list_tests {
+    use linked_list_impls::unrolled_linked_list::UnrolledLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Collects the list's contents front to back.
+    fn contents<const B: usize>(list: &UnrolledLinkedList<i32, B>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    /// Test that appends fill each node before allocating the next one.
+    #[test]
+    fn test_appends_pack_nodes() {
+        let mut list: UnrolledLinkedList<i32, 4> = UnrolledLinkedList::new();
+        for value in 0..10 {
+            list.insert(value);
+        }
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.node_count(), 3); // 4 + 4 + 2 elements.
+        assert_eq!(contents(&list), (0..10).collect::<Vec<_>>());
+    }
+
+    /// Test that inserting into a full node splits it and keeps order.
+    #[test]
+    fn test_insert_splits_full_node() {
+        let mut list: UnrolledLinkedList<i32, 4> = UnrolledLinkedList::new();
+        for value in [1, 2, 4, 5] {
+            list.insert(value);
+        }
+        list.insert_at_index(2, 3).unwrap();
+        assert_eq!(list.node_count(), 2);
+        list.insert_at_index(5, 6).unwrap(); // Split on the far side of the node.
+        list.insert_at_index(0, 0).unwrap();
+        assert_eq!(contents(&list), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert!(list.insert_at_index(8, 9).is_err());
+    }
+
+    /// Test that deletions merge sparse nodes and drop empty ones.
+    #[test]
+    fn test_delete_merges_nodes() {
+        let mut list: UnrolledLinkedList<i32, 4> = UnrolledLinkedList::new();
+        for value in 0..8 {
+            list.insert(value);
+        }
+        list.delete_at_index(0).unwrap();
+        list.delete_at_index(0).unwrap();
+        list.delete_at_index(0).unwrap(); // First node drops below half and merges.
+        assert_eq!(list.node_count(), 2);
+        assert_eq!(contents(&list), vec![3, 4, 5, 6, 7]);
+        while list.delete_at_index(0).is_ok() {}
+        assert_eq!(list.node_count(), 0); // Empty nodes are unlinked.
+        assert!(list.is_empty());
+    }
+
+    /// Test searching, updating, and getting across node boundaries.
+    #[test]
+    fn test_by_value_operations() {
+        let mut list: UnrolledLinkedList<i32, 2> = UnrolledLinkedList::new();
+        for value in 1..=5 {
+            list.insert(value);
+        }
+        assert!(list.find(&5));
+        assert!(list.update_element(&4, 40));
+        list.update_element_at_index(0, 10).unwrap();
+        assert!(list.delete_element(&3));
+        assert!(!list.delete_element(&3));
+        assert_eq!(list.get(2), Some(&40));
+        assert_eq!(list.get(4), None);
+        assert!(list.update_element_at_index(4, 0).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+// versioned_list_test.rs
+// This file contains unit tests for the VersionedList implementation.
+// It tests the recorded history and undo/redo of each kind of mutation.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod versioned_list_tests {
+    use linked_list_impls::versioned_list::{Edit, VersionedList};
+    use linked_list_impls::{ListRead, ListWrite};
+
+    /// Collects the list's contents front to back.
+    fn contents(list: &VersionedList<i32>) -> Vec<i32> {
+        (0..list.len()).map(|index| *list.get(index).unwrap()).collect()
+    }
+
+    /// Test that every mutation is recorded in the history.
+    #[test]
+    fn test_history_records_edits() {
+        let mut list = VersionedList::new();
+        list.insert(1);
+        list.insert_at_index(0, 0).unwrap();
+        assert!(list.update_element(&1, 10));
+        assert!(list.delete_element(&0));
+        assert!(!list.delete_element(&42)); // Failed edits are not recorded.
+        assert_eq!(
+            list.history(),
+            &[
+                Edit::Inserted { index: 0, data: 1 },
+                Edit::Inserted { index: 0, data: 0 },
+                Edit::Updated { index: 1, old: 1, new: 10 },
+                Edit::Deleted { index: 0, data: 0 },
+            ]
+        );
+    }
+
+    /// Test undoing every edit back to the empty list and redoing them all.
+    #[test]
+    fn test_undo_and_redo() {
+        let mut list = VersionedList::new();
+        list.insert(1);
+        list.insert(2);
+        list.update_element_at_index(0, 5).unwrap();
+        list.delete_at_index(1).unwrap();
+        assert_eq!(contents(&list), vec![5]);
+
+        assert!(list.undo());
+        assert_eq!(contents(&list), vec![5, 2]);
+        while list.undo() {}
+        assert!(list.is_empty());
+
+        assert!(list.redo());
+        assert_eq!(contents(&list), vec![1]);
+        while list.redo() {}
+        assert_eq!(contents(&list), vec![5]);
+        assert!(!list.can_redo());
+    }
+
+    /// Test that a new edit discards the redo stack.
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let mut list = VersionedList::new();
+        list.insert(1);
+        list.insert(2);
+        list.undo();
+        assert!(list.can_redo());
+        list.insert(3);
+        assert!(!list.can_redo());
+        assert!(!list.redo());
+        assert_eq!(contents(&list), vec![1, 3]);
+    }
+}
@@ -0,0 +1,47 @@
+// wasm_test.rs
+// This file contains unit tests for the JavaScript-facing list behind the `wasm` feature.
+// Run with `cargo test --features wasm`; the methods are called natively, without a JavaScript host.
+
+#![cfg(feature = "wasm")]
+
+#[cfg(test)]
+mod wasm_tests {
+    use linked_list_impls::wasm::JsLinkedList;
+
+    /// Test appending, inserting and reading values by index.
+    #[test]
+    fn test_insert_and_get() {
+        let mut list = JsLinkedList::new();
+        list.insert(1.5);
+        list.insert(3.0);
+        list.insert_at(1, 2.25).unwrap();
+        assert_eq!(list.length(), 3);
+        assert_eq!(list.get(1), Some(2.25));
+        assert_eq!(list.get(3), None); // `undefined` in JavaScript.
+        assert_eq!(list.to_array(), vec![1.5, 2.25, 3.0]);
+    }
+
+    /// Test deleting by index and by value.
+    #[test]
+    fn test_delete() {
+        let mut list = JsLinkedList::new();
+        for value in [4.0, 5.0, 6.0, 5.0] {
+            list.insert(value);
+        }
+        list.delete_at(0).unwrap();
+        assert!(list.delete_value(5.0)); // Only the first match goes.
+        assert_eq!(list.index_of(5.0), Some(1));
+        assert!(!list.delete_value(7.0));
+        assert_eq!(list.to_array(), vec![6.0, 5.0]);
+    }
+
+    /// Test that bad indices produce the error message that JavaScript will see.
+    #[test]
+    fn test_errors_are_messages() {
+        let mut list = JsLinkedList::new();
+        let error = list.delete_at(0).unwrap_err();
+        assert_eq!(error, "index 0 is out of bounds for a list of length 0");
+        assert!(list.insert_at(2, 1.0).is_err());
+        assert_eq!(list.length(), 0);
+    }
+}
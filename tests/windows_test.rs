@@ -0,0 +1,56 @@
+// windows_test.rs
+// This file contains unit tests for the windows iterator of LinkedListTrait.
+// It tests overlapping windows, lists shorter than a window, and the size hint.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod windows_tests {
+    use linked_list_impls::doubly_linked_list::DoublyLinkedList;
+    use linked_list_impls::dynamic_linked_list::DynamicLinkedList;
+    use linked_list_impls::static_linked_list::StaticLinkedList;
+    use linked_list_impls::{LinkedListTrait, ListWrite};
+
+    /// Test a moving average over a dynamic list.
+    #[test]
+    fn test_moving_average() {
+        let mut list = DynamicLinkedList::new();
+        for value in [2.0, 4.0, 6.0, 8.0, 10.0] {
+            list.insert(value);
+        }
+        let averages: Vec<f64> = list
+            .windows(3)
+            .map(|window| window.into_iter().sum::<f64>() / 3.0)
+            .collect();
+        assert_eq!(averages, vec![4.0, 6.0, 8.0]);
+        let pairs: Vec<Vec<&f64>> = list.windows(2).take(2).collect();
+        assert_eq!(pairs, vec![vec![&2.0, &4.0], vec![&4.0, &6.0]]); // Overlapping.
+    }
+
+    /// Test that a list shorter than the window has none, and one as long has one.
+    #[test]
+    fn test_short_lists() {
+        let mut list: StaticLinkedList<i32, 4> = StaticLinkedList::new();
+        list.insert(1);
+        list.insert(2);
+        assert_eq!(list.windows(3).next(), None);
+        let mut windows = list.windows(2);
+        assert_eq!(windows.next(), Some(vec![&1, &2]));
+        assert_eq!(windows.next(), None);
+        assert_eq!(windows.next(), None); // Stays exhausted.
+    }
+
+    /// Test that the size hint counts the windows left.
+    #[test]
+    fn test_size_hint() {
+        let mut list = DoublyLinkedList::new(); // Its iterator knows its length.
+        for value in 0..6 {
+            list.insert(value);
+        }
+        let mut windows = list.windows(4);
+        assert_eq!(windows.size_hint(), (3, Some(3)));
+        windows.next();
+        assert_eq!(windows.size_hint(), (2, Some(2)));
+        assert_eq!(windows.count(), 2);
+    }
+}
@@ -0,0 +1,85 @@
+// xor_linked_list_test.rs
+// This file contains unit tests for the XorLinkedList implementation.
+// It tests traversal in both directions, end operations, and node cleanup.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+#[cfg(test)]
+mod xor_linked_list_tests {
+    use std::rc::Rc;
+
+    use linked_list_impls::xor_linked_list::XorLinkedList;
+    use linked_list_impls::{ListRead, ListWrite};
+
+    use crate::common::list_of;
+
+    /// Test pushing and popping at both ends.
+    #[test]
+    fn test_push_pop_both_ends() {
+        let mut list = XorLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None); // List is now empty.
+        assert_eq!(list.back(), None);
+    }
+
+    /// Test iterating forwards, backwards, and from both ends at once.
+    #[test]
+    fn test_iter_both_directions() {
+        let list: XorLinkedList<i32> = list_of(&[1, 2, 3, 4]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.len(), 2);
+    }
+
+    /// Test that middle insertions and deletions keep both directions consistent.
+    #[test]
+    fn test_middle_edits_keep_links_consistent() {
+        let mut list: XorLinkedList<i32> = list_of(&[1, 3, 5]);
+        list.insert_at_index(1, 2).unwrap();
+        list.insert_at_index(3, 4).unwrap(); // Located by walking from the tail.
+        assert!(list.insert_at_index(6, 0).is_err());
+        list.delete_at_index(2).unwrap();
+        assert!(list.delete_element(&5));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 2, 1]);
+    }
+
+    /// Test updating, finding, and getting elements.
+    #[test]
+    fn test_update_and_get() {
+        let mut list: XorLinkedList<i32> = list_of(&[1, 2, 3]);
+        assert!(list.update_element(&2, 20));
+        assert!(!list.update_element(&7, 70));
+        list.update_element_at_index(2, 30).unwrap();
+        assert!(list.find(&20));
+        assert_eq!(list.get(2), Some(&30));
+        assert_eq!(list.get(3), None);
+    }
+
+    /// Test that dropping the list drops every element exactly once.
+    #[test]
+    fn test_drop_releases_elements() {
+        let tracker = Rc::new(());
+        {
+            let mut list = XorLinkedList::new();
+            for _ in 0..4 {
+                list.push_front(Rc::clone(&tracker));
+            }
+            assert_eq!(Rc::strong_count(&tracker), 5);
+        }
+        assert_eq!(Rc::strong_count(&tracker), 1); // All clones were released.
+    }
+}